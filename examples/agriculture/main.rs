@@ -97,11 +97,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 // Create contribution
                 let contribution = kova_core::blockchain::Contribution {
                     sensor_data_hash: hash,
-                    validator_signature: validation_result.signature,
+                    validator_signature: validation_result.signature.clone(),
                     timestamp: chrono::Utc::now(),
                     quality_score: validation_result.quality_score,
                     validator_id: "agriculture_validator".to_string(),
-                    sensor_id: data.sensor_id,
+                    sensor_id: data.sensor_id.clone(),
                 };
                 
                 // Submit contribution
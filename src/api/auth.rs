@@ -0,0 +1,55 @@
+//! API key authentication shared by the REST and WebSocket servers
+
+use axum::http::HeaderMap;
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// How long a WebSocket client has to authenticate after the handshake
+pub const AUTH_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// API key guard
+///
+/// Holds the set of accepted keys and checks credentials presented either as
+/// a `Bearer` token in the `Authorization` header or in an `X-API-Key`
+/// header. Both servers share one guard so keys are configured in one place.
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuth {
+    keys: HashSet<String>,
+}
+
+impl ApiKeyAuth {
+    /// Create a guard accepting the given keys
+    pub fn new<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Check a bare API key
+    pub fn is_authorized(&self, key: &str) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Check the credentials carried in a set of HTTP headers
+    ///
+    /// Accepts `Authorization: Bearer <key>` or `X-API-Key: <key>`.
+    pub fn check_headers(&self, headers: &HeaderMap) -> bool {
+        if let Some(value) = headers.get("authorization").and_then(|v| v.to_str().ok()) {
+            if let Some(key) = value.strip_prefix("Bearer ") {
+                if self.is_authorized(key.trim()) {
+                    return true;
+                }
+            }
+        }
+        if let Some(key) = headers.get("x-api-key").and_then(|v| v.to_str().ok()) {
+            if self.is_authorized(key.trim()) {
+                return true;
+            }
+        }
+        false
+    }
+}
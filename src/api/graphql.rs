@@ -1,8 +1,11 @@
 //! GraphQL API implementation
 
 use crate::core::Error;
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Schema};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
 
 /// GraphQL query structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,16 +38,23 @@ pub struct GraphQLErrorLocation {
 }
 
 /// GraphQL schema types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, async_graphql::SimpleObject)]
 pub struct SensorData {
     pub id: String,
     pub sensor_type: String,
     pub timestamp: String,
     pub data: String,
-    pub metadata: HashMap<String, String>,
+    pub metadata: Vec<KeyValuePair>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Key/value metadata entry
+#[derive(Debug, Clone, Serialize, Deserialize, async_graphql::SimpleObject)]
+pub struct KeyValuePair {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, async_graphql::SimpleObject)]
 pub struct ValidationResult {
     pub id: String,
     pub sensor_data_id: String,
@@ -53,7 +63,7 @@ pub struct ValidationResult {
     pub timestamp: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, async_graphql::SimpleObject)]
 pub struct Contribution {
     pub id: String,
     pub sensor_data_hash: String,
@@ -63,16 +73,126 @@ pub struct Contribution {
     pub reward: f64,
 }
 
+/// Shared data sources backing the GraphQL resolvers
+#[derive(Clone, Default)]
+pub struct QueryContext {
+    /// Sensor data store
+    pub sensor_store: Arc<RwLock<Vec<SensorData>>>,
+    /// Validation result store
+    pub validation_store: Arc<RwLock<Vec<ValidationResult>>>,
+    /// Contribution store
+    pub contribution_store: Arc<RwLock<Vec<Contribution>>>,
+}
+
+/// Root query resolver
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Look up a sensor data record by id
+    async fn sensor_data(&self, ctx: &Context<'_>, id: String) -> Option<SensorData> {
+        let context = ctx.data_unchecked::<QueryContext>();
+        let store = context.sensor_store.read().await;
+        store.iter().find(|entry| entry.id == id).cloned()
+    }
+
+    /// List sensor data records
+    async fn sensor_data_list(
+        &self,
+        ctx: &Context<'_>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Vec<SensorData> {
+        let context = ctx.data_unchecked::<QueryContext>();
+        let store = context.sensor_store.read().await;
+        store
+            .iter()
+            .skip(offset.unwrap_or(0))
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+
+    /// Look up a validation result by id
+    async fn validation_result(&self, ctx: &Context<'_>, id: String) -> Option<ValidationResult> {
+        let context = ctx.data_unchecked::<QueryContext>();
+        let store = context.validation_store.read().await;
+        store.iter().find(|entry| entry.id == id).cloned()
+    }
+
+    /// List validation results
+    async fn validation_result_list(
+        &self,
+        ctx: &Context<'_>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Vec<ValidationResult> {
+        let context = ctx.data_unchecked::<QueryContext>();
+        let store = context.validation_store.read().await;
+        store
+            .iter()
+            .skip(offset.unwrap_or(0))
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+
+    /// Look up a contribution by id
+    async fn contribution(&self, ctx: &Context<'_>, id: String) -> Option<Contribution> {
+        let context = ctx.data_unchecked::<QueryContext>();
+        let store = context.contribution_store.read().await;
+        store.iter().find(|entry| entry.id == id).cloned()
+    }
+
+    /// List contributions
+    async fn contribution_list(
+        &self,
+        ctx: &Context<'_>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Vec<Contribution> {
+        let context = ctx.data_unchecked::<QueryContext>();
+        let store = context.contribution_store.read().await;
+        store
+            .iter()
+            .skip(offset.unwrap_or(0))
+            .take(limit.unwrap_or(usize::MAX))
+            .cloned()
+            .collect()
+    }
+}
+
 /// GraphQL API server
 pub struct GraphQLServer {
     port: u16,
     host: String,
+    schema: Schema<QueryRoot, EmptyMutation, EmptySubscription>,
+    context: QueryContext,
 }
 
 impl GraphQLServer {
-    /// Create a new GraphQL server
+    /// Create a new GraphQL server with empty in-memory stores
     pub fn new(host: String, port: u16) -> Self {
-        Self { host, port }
+        Self::with_context(host, port, QueryContext::default())
+    }
+
+    /// Create a new GraphQL server backed by the given data sources
+    pub fn with_context(host: String, port: u16, context: QueryContext) -> Self {
+        let schema = Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+            .data(context.clone())
+            .finish();
+
+        Self {
+            host,
+            port,
+            schema,
+            context,
+        }
+    }
+
+    /// Get the data sources backing the resolvers
+    pub fn context(&self) -> &QueryContext {
+        &self.context
     }
 
     /// Start the GraphQL server
@@ -82,13 +202,54 @@ impl GraphQLServer {
         Ok(())
     }
 
-    /// Execute a GraphQL query
+    /// Execute a GraphQL query against the in-memory resolvers
     pub async fn execute_query<T>(&self, query: GraphQLQuery) -> Result<GraphQLResponse<T>, Error>
     where
         T: serde::de::DeserializeOwned,
     {
-        // Implementation would go here
-        Err(Error::network("GraphQL execution not implemented"))
+        let mut request = async_graphql::Request::new(query.query);
+        if let Some(variables) = query.variables {
+            let value = serde_json::to_value(variables)?;
+            request = request.variables(async_graphql::Variables::from_json(value));
+        }
+        if let Some(operation_name) = query.operation_name {
+            request = request.operation_name(operation_name);
+        }
+
+        let response = self.schema.execute(request).await;
+
+        let errors = if response.errors.is_empty() {
+            None
+        } else {
+            Some(
+                response
+                    .errors
+                    .iter()
+                    .map(|e| GraphQLError {
+                        message: e.message.clone(),
+                        locations: Some(
+                            e.locations
+                                .iter()
+                                .map(|loc| GraphQLErrorLocation {
+                                    line: loc.line as u32,
+                                    column: loc.column as u32,
+                                })
+                                .collect(),
+                        ),
+                        path: None,
+                    })
+                    .collect(),
+            )
+        };
+
+        let data = if response.errors.is_empty() {
+            let json = response.data.into_json()?;
+            Some(serde_json::from_value(json)?)
+        } else {
+            None
+        };
+
+        Ok(GraphQLResponse { data, errors })
     }
 }
 
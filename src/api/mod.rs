@@ -1,5 +1,6 @@
 //! API modules for Kova Core
 
+pub mod auth;
 pub mod rest;
 pub mod graphql;
 pub mod websocket;
@@ -1,12 +1,46 @@
 //! REST API for Kova Core
 
+use crate::api::auth::ApiKeyAuth;
+use crate::blockchain::{BlockchainManager, Contribution};
+use crate::core::validation::{DataValidator, ValidationResult};
 use crate::core::Error;
+use axum::extract::{Path, State};
+use axum::http::{Request, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Shared state for REST handlers
+#[derive(Clone)]
+pub struct ApiState {
+    /// Data validator for submitted sensor bytes
+    pub validator: Arc<DataValidator>,
+    /// Blockchain manager for contribution lookups
+    pub blockchain: Arc<BlockchainManager>,
+}
+
+impl Default for ApiState {
+    fn default() -> Self {
+        Self {
+            validator: Arc::new(DataValidator::new()),
+            blockchain: Arc::new(BlockchainManager::new()),
+        }
+    }
+}
 
 /// REST API server
 pub struct RestApiServer {
     port: u16,
     host: String,
+    state: ApiState,
+    local_addr: RwLock<Option<SocketAddr>>,
+    auth: Option<Arc<ApiKeyAuth>>,
 }
 
 /// API response
@@ -41,15 +75,264 @@ impl<T> ApiResponse<T> {
 }
 
 impl RestApiServer {
-    /// Create a new REST API server
+    /// Create a new REST API server with default state
     pub fn new(host: String, port: u16) -> Self {
-        Self { host, port }
+        Self::with_state(host, port, ApiState::default())
+    }
+
+    /// Create a new REST API server sharing the given validator and manager
+    pub fn with_state(host: String, port: u16, state: ApiState) -> Self {
+        Self {
+            host,
+            port,
+            state,
+            local_addr: RwLock::new(None),
+            auth: None,
+        }
+    }
+
+    /// Require a valid API key on every request
+    pub fn set_auth(&mut self, auth: ApiKeyAuth) {
+        self.auth = Some(Arc::new(auth));
+    }
+
+    /// Build the axum router for the API
+    pub fn router(&self) -> Router {
+        let router = Router::new()
+            .route("/health", get(health))
+            .route("/sensor-data", post(submit_sensor_data))
+            .route("/contributions/:id", get(get_contribution))
+            .with_state(self.state.clone());
+
+        match &self.auth {
+            Some(auth) => {
+                router.layer(middleware::from_fn_with_state(auth.clone(), require_api_key))
+            }
+            None => router,
+        }
+    }
+
+    /// Get the bound address, if the server has started
+    pub async fn local_addr(&self) -> Option<SocketAddr> {
+        *self.local_addr.read().await
     }
 
     /// Start the server
+    ///
+    /// Binds to the configured host/port and serves the API in a background
+    /// task, returning once the listener is bound.
     pub async fn start(&self) -> Result<(), Error> {
-        tracing::info!("Starting REST API server on {}:{}", self.host, self.port);
-        // Implementation would go here
+        let addr = format!("{}:{}", self.host, self.port)
+            .parse::<SocketAddr>()
+            .map_err(|e| Error::network(format!("Invalid REST API address: {}", e)))?;
+
+        let server = axum::Server::try_bind(&addr)
+            .map_err(|e| Error::network(format!("Failed to bind REST API server: {}", e)))?
+            .serve(self.router().into_make_service());
+
+        let local_addr = server.local_addr();
+        *self.local_addr.write().await = Some(local_addr);
+        tracing::info!("REST API server listening on {}", local_addr);
+
+        tokio::spawn(async move {
+            if let Err(e) = server.await {
+                tracing::error!("REST API server error: {}", e);
+            }
+        });
+
         Ok(())
     }
 }
+
+/// OpenAPI 3.0 description of the REST API
+///
+/// Kept next to the router so route changes and the published contract
+/// are reviewed together.
+pub fn openapi_spec() -> serde_json::Value {
+    let api_response = |data_schema: serde_json::Value| {
+        serde_json::json!({
+            "type": "object",
+            "required": ["success", "timestamp"],
+            "properties": {
+                "success": { "type": "boolean" },
+                "data": data_schema,
+                "error": { "type": "string", "nullable": true },
+                "timestamp": { "type": "string", "format": "date-time" }
+            }
+        })
+    };
+
+    serde_json::json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Kova Core REST API",
+            "version": env!("CARGO_PKG_VERSION")
+        },
+        "paths": {
+            "/health": {
+                "get": {
+                    "summary": "Health check",
+                    "responses": {
+                        "200": {
+                            "description": "Service is up",
+                            "content": {
+                                "application/json": {
+                                    "schema": api_response(serde_json::json!({ "type": "string" }))
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "/sensor-data": {
+                "post": {
+                    "summary": "Submit sensor bytes for validation",
+                    "requestBody": {
+                        "required": true,
+                        "content": {
+                            "application/octet-stream": {
+                                "schema": { "type": "string", "format": "binary" }
+                            }
+                        }
+                    },
+                    "responses": {
+                        "200": {
+                            "description": "Validation result",
+                            "content": {
+                                "application/json": {
+                                    "schema": api_response(serde_json::json!({
+                                        "$ref": "#/components/schemas/ValidationResult"
+                                    }))
+                                }
+                            }
+                        },
+                        "422": { "description": "Validation failed" }
+                    }
+                }
+            },
+            "/contributions/{id}": {
+                "get": {
+                    "summary": "Look up a contribution by its stored hash",
+                    "parameters": [{
+                        "name": "id",
+                        "in": "path",
+                        "required": true,
+                        "schema": { "type": "string" }
+                    }],
+                    "responses": {
+                        "200": {
+                            "description": "Stored contribution",
+                            "content": {
+                                "application/json": {
+                                    "schema": api_response(serde_json::json!({
+                                        "$ref": "#/components/schemas/Contribution"
+                                    }))
+                                }
+                            }
+                        },
+                        "404": { "description": "Unknown contribution hash" }
+                    }
+                }
+            }
+        },
+        "components": {
+            "schemas": {
+                "ValidationResult": {
+                    "type": "object",
+                    "required": ["quality_score", "timestamp", "metrics", "signature", "is_valid"],
+                    "properties": {
+                        "quality_score": { "type": "number", "format": "double" },
+                        "timestamp": { "type": "string", "format": "date-time" },
+                        "metrics": { "$ref": "#/components/schemas/QualityMetrics" },
+                        "signature": { "type": "string" },
+                        "is_valid": { "type": "boolean" }
+                    }
+                },
+                "QualityMetrics": {
+                    "type": "object",
+                    "required": ["noise_level", "completeness", "consistency", "accuracy", "anomaly_score"],
+                    "properties": {
+                        "noise_level": { "type": "number", "format": "double" },
+                        "completeness": { "type": "number", "format": "double" },
+                        "consistency": { "type": "number", "format": "double" },
+                        "accuracy": { "type": "number", "format": "double" },
+                        "anomaly_score": { "type": "number", "format": "double" }
+                    }
+                },
+                "Contribution": {
+                    "type": "object",
+                    "required": ["sensor_data_hash", "validator_signature", "quality_score", "timestamp", "sensor_type", "robot_id"],
+                    "properties": {
+                        "sensor_data_hash": { "type": "string" },
+                        "validator_signature": { "type": "string" },
+                        "quality_score": { "type": "number", "format": "double" },
+                        "timestamp": { "type": "string", "format": "date-time" },
+                        "sensor_type": { "type": "string" },
+                        "robot_id": { "type": "string" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Reject requests that do not carry an accepted API key
+async fn require_api_key<B>(
+    State(auth): State<Arc<ApiKeyAuth>>,
+    request: Request<B>,
+    next: Next<B>,
+) -> Response {
+    if auth.check_headers(request.headers()) {
+        next.run(request).await
+    } else {
+        (
+            StatusCode::UNAUTHORIZED,
+            Json(ApiResponse::<()>::error(
+                "Missing or invalid API key".to_string(),
+            )),
+        )
+            .into_response()
+    }
+}
+
+/// Health check endpoint
+async fn health() -> Json<ApiResponse<String>> {
+    Json(ApiResponse::success("ok".to_string()))
+}
+
+/// Submit sensor bytes for validation
+async fn submit_sensor_data(
+    State(state): State<ApiState>,
+    body: axum::body::Bytes,
+) -> (StatusCode, Json<ApiResponse<ValidationResult>>) {
+    match state.validator.validate(&body, &HashMap::new()).await {
+        Ok(result) => (StatusCode::OK, Json(ApiResponse::success(result))),
+        Err(e) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiResponse::error(e.to_string())),
+        ),
+    }
+}
+
+/// Look up a contribution by its stored hash
+async fn get_contribution(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+) -> (StatusCode, Json<ApiResponse<Contribution>>) {
+    match state.blockchain.retrieve_data(&id).await {
+        Ok(data) => match serde_json::from_slice::<Contribution>(&data) {
+            Ok(contribution) => (StatusCode::OK, Json(ApiResponse::success(contribution))),
+            Err(e) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!(
+                    "Stored data is not a contribution: {}",
+                    e
+                ))),
+            ),
+        },
+        Err(e) => (
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(e.to_string())),
+        ),
+    }
+}
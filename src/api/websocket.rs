@@ -1,9 +1,67 @@
 //! WebSocket API implementation
 
+use crate::api::auth::{ApiKeyAuth, AUTH_HANDSHAKE_TIMEOUT};
 use crate::core::Error;
+use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
-use tokio::sync::broadcast;
 use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::{broadcast, RwLock};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Protocol version spoken by this server
+///
+/// Bump whenever the wire format of [`WebSocketMessage`] changes in a way
+/// old clients cannot parse.
+pub const PROTOCOL_VERSION: u8 = 1;
+
+fn current_version() -> u8 {
+    PROTOCOL_VERSION
+}
+
+/// Versioned wire envelope for WebSocket messages
+///
+/// Serializes as the message itself plus a `v` field, so the tagged enum
+/// layout is unchanged. Messages without a `v` field are treated as the
+/// current version for backward compatibility.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Envelope {
+    /// Protocol version of the enclosed message
+    #[serde(default = "current_version")]
+    pub v: u8,
+    /// The enclosed message
+    #[serde(flatten)]
+    pub msg: WebSocketMessage,
+}
+
+impl Envelope {
+    /// Wrap a message in a current-version envelope
+    pub fn new(msg: WebSocketMessage) -> Self {
+        Self {
+            v: PROTOCOL_VERSION,
+            msg,
+        }
+    }
+
+    /// Serialize the envelope to JSON
+    pub fn encode(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Parse an envelope and unwrap the message, rejecting unsupported versions
+    pub fn decode(text: &str) -> Result<WebSocketMessage, Error> {
+        let envelope: Envelope = serde_json::from_str(text)?;
+        if envelope.v != PROTOCOL_VERSION {
+            return Err(Error::network(format!(
+                "Unsupported protocol version {} (server speaks {})",
+                envelope.v, PROTOCOL_VERSION
+            )));
+        }
+        Ok(envelope.msg)
+    }
+}
 
 /// WebSocket message types
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,17 +90,122 @@ pub enum WebSocketMessage {
         reward: f64,
         timestamp: String,
     },
+    /// Authenticate the connection with an API key
+    Auth { api_key: String },
+    /// Subscribe to a topic
+    Subscribe { topic: String },
+    /// Unsubscribe from a topic
+    Unsubscribe { topic: String },
     /// Error message
-    Error {
-        message: String,
-        code: String,
-    },
+    Error { message: String, code: String },
     /// Ping message
     Ping,
     /// Pong message
     Pong,
 }
 
+impl WebSocketMessage {
+    /// Encode sensor data as a binary WebSocket frame
+    ///
+    /// Layout: a little-endian `u32` header length, the versioned JSON header
+    /// (the message with an empty `data` field), then the raw sensor bytes.
+    /// This avoids the ~4x blowup of encoding image payloads as JSON number
+    /// arrays. Only [`WebSocketMessage::SensorData`] has a binary encoding.
+    pub fn encode_binary(&self) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::SensorData {
+                sensor_id,
+                sensor_type,
+                data,
+                timestamp,
+                metadata,
+            } => {
+                let header = Envelope::new(Self::SensorData {
+                    sensor_id: sensor_id.clone(),
+                    sensor_type: sensor_type.clone(),
+                    data: Vec::new(),
+                    timestamp: timestamp.clone(),
+                    metadata: metadata.clone(),
+                });
+                let header = serde_json::to_vec(&header)?;
+
+                let mut frame = Vec::with_capacity(4 + header.len() + data.len());
+                frame.extend_from_slice(&(header.len() as u32).to_le_bytes());
+                frame.extend_from_slice(&header);
+                frame.extend_from_slice(data);
+                Ok(frame)
+            }
+            _ => Err(Error::network(
+                "Only sensor data messages have a binary encoding",
+            )),
+        }
+    }
+
+    /// Decode a binary WebSocket frame produced by [`WebSocketMessage::encode_binary`]
+    pub fn decode_binary(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 4 {
+            return Err(Error::network("Binary frame too short for header length"));
+        }
+        let header_len = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
+        let payload_start = 4 + header_len;
+        if bytes.len() < payload_start {
+            return Err(Error::network("Binary frame truncated before payload"));
+        }
+
+        let header = std::str::from_utf8(&bytes[4..payload_start])
+            .map_err(|e| Error::network(format!("Binary frame header is not UTF-8: {}", e)))?;
+        match Envelope::decode(header)? {
+            Self::SensorData {
+                sensor_id,
+                sensor_type,
+                timestamp,
+                metadata,
+                ..
+            } => Ok(Self::SensorData {
+                sensor_id,
+                sensor_type,
+                data: bytes[payload_start..].to_vec(),
+                timestamp,
+                metadata,
+            }),
+            _ => Err(Error::network("Binary frame header is not sensor data")),
+        }
+    }
+
+    /// Get the topic this message belongs to, if it carries one
+    ///
+    /// Sensor data messages are scoped by sensor id; validation results and
+    /// contributions by the data they reference.
+    pub fn topic(&self) -> Option<&str> {
+        match self {
+            Self::SensorData { sensor_id, .. } => Some(sensor_id),
+            Self::ValidationResult { sensor_data_id, .. } => Some(sensor_data_id),
+            Self::Contribution {
+                sensor_data_hash, ..
+            } => Some(sensor_data_hash),
+            _ => None,
+        }
+    }
+}
+
+/// Connection quotas enforced by the WebSocket server
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectionLimits {
+    /// Maximum number of simultaneously registered connections
+    pub max_connections: usize,
+    /// Maximum client messages per connection per second
+    pub max_messages_per_second: u32,
+}
+
+impl Default for ConnectionLimits {
+    fn default() -> Self {
+        Self {
+            max_connections: 1024,
+            max_messages_per_second: 100,
+        }
+    }
+}
+
 /// WebSocket client connection
 pub struct WebSocketConnection {
     pub id: String,
@@ -50,68 +213,168 @@ pub struct WebSocketConnection {
     pub subscriptions: Vec<String>,
 }
 
+/// Shared connection registry
+type ConnectionMap = Arc<RwLock<HashMap<String, WebSocketConnection>>>;
+
 /// WebSocket server
 pub struct WebSocketServer {
     port: u16,
     host: String,
-    connections: HashMap<String, WebSocketConnection>,
+    connections: ConnectionMap,
     message_sender: broadcast::Sender<WebSocketMessage>,
+    local_addr: RwLock<Option<SocketAddr>>,
+    limits: ConnectionLimits,
+    auth: Option<Arc<ApiKeyAuth>>,
 }
 
 impl WebSocketServer {
-    /// Create a new WebSocket server
+    /// Create a new WebSocket server with default connection limits
     pub fn new(host: String, port: u16) -> Self {
+        Self::with_limits(host, port, ConnectionLimits::default())
+    }
+
+    /// Create a new WebSocket server with explicit connection limits
+    pub fn with_limits(host: String, port: u16, limits: ConnectionLimits) -> Self {
         let (message_sender, _) = broadcast::channel(1000);
-        
+
         Self {
             host,
             port,
-            connections: HashMap::new(),
+            connections: Arc::new(RwLock::new(HashMap::new())),
             message_sender,
+            local_addr: RwLock::new(None),
+            limits,
+            auth: None,
         }
     }
 
+    /// Require connections to authenticate with an API key before anything else
+    pub fn set_auth(&mut self, auth: ApiKeyAuth) {
+        self.auth = Some(Arc::new(auth));
+    }
+
     /// Start the WebSocket server
+    ///
+    /// Binds to the configured host/port, then spawns an accept loop that
+    /// registers each incoming connection and forwards broadcast messages to
+    /// its subscribers. Returns once the listener is bound, so an ephemeral
+    /// port (`0`) can be resolved via [`WebSocketServer::local_addr`].
     pub async fn start(&self) -> Result<(), Error> {
-        tracing::info!("Starting WebSocket server on {}:{}", self.host, self.port);
-        // Implementation would go here
+        let listener = TcpListener::bind((self.host.as_str(), self.port))
+            .await
+            .map_err(|e| Error::network(format!("Failed to bind WebSocket server: {}", e)))?;
+
+        let addr = listener
+            .local_addr()
+            .map_err(|e| Error::network(format!("Failed to get local address: {}", e)))?;
+        *self.local_addr.write().await = Some(addr);
+        tracing::info!("WebSocket server listening on {}", addr);
+
+        let connections = Arc::clone(&self.connections);
+        let message_sender = self.message_sender.clone();
+        let limits = self.limits;
+        let auth = self.auth.clone();
+
+        tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        let connections = Arc::clone(&connections);
+                        let message_sender = message_sender.clone();
+                        let auth = auth.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = handle_connection(
+                                stream,
+                                peer,
+                                connections,
+                                message_sender,
+                                limits,
+                                auth,
+                            )
+                            .await
+                            {
+                                tracing::warn!("WebSocket connection {} closed: {}", peer, e);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to accept WebSocket connection: {}", e);
+                    }
+                }
+            }
+        });
+
         Ok(())
     }
 
+    /// Get the bound address, if the server has started
+    pub async fn local_addr(&self) -> Option<SocketAddr> {
+        *self.local_addr.read().await
+    }
+
     /// Add a new connection
-    pub fn add_connection(&mut self, id: String, sender: broadcast::Sender<WebSocketMessage>) {
+    pub async fn add_connection(&self, id: String, sender: broadcast::Sender<WebSocketMessage>) {
         let connection = WebSocketConnection {
             id: id.clone(),
             sender,
             subscriptions: Vec::new(),
         };
-        self.connections.insert(id, connection);
+        self.connections.write().await.insert(id, connection);
     }
 
     /// Remove a connection
-    pub fn remove_connection(&mut self, id: &str) {
-        self.connections.remove(id);
+    pub async fn remove_connection(&self, id: &str) {
+        self.connections.write().await.remove(id);
     }
 
     /// Broadcast message to all connections
     pub async fn broadcast(&self, message: WebSocketMessage) -> Result<(), Error> {
-        self.message_sender.send(message)
+        self.message_sender
+            .send(message)
             .map_err(|_| Error::network("Failed to broadcast message"))?;
         Ok(())
     }
 
+    /// Broadcast a message only to connections subscribed to a topic
+    pub async fn broadcast_to_topic(
+        &self,
+        topic: &str,
+        message: WebSocketMessage,
+    ) -> Result<(), Error> {
+        let connections = self.connections.read().await;
+        for connection in connections.values() {
+            if connection.subscriptions.contains(&topic.to_string()) {
+                if connection.sender.send(message.clone()).is_err() {
+                    tracing::warn!(
+                        "Failed to deliver topic message to connection {}",
+                        connection.id
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Send message to specific connection
-    pub async fn send_to_connection(&self, connection_id: &str, message: WebSocketMessage) -> Result<(), Error> {
-        if let Some(connection) = self.connections.get(connection_id) {
-            connection.sender.send(message)
+    pub async fn send_to_connection(
+        &self,
+        connection_id: &str,
+        message: WebSocketMessage,
+    ) -> Result<(), Error> {
+        let connections = self.connections.read().await;
+        if let Some(connection) = connections.get(connection_id) {
+            connection
+                .sender
+                .send(message)
                 .map_err(|_| Error::network("Failed to send message to connection"))?;
         }
         Ok(())
     }
 
     /// Subscribe connection to a topic
-    pub fn subscribe(&mut self, connection_id: &str, topic: String) -> Result<(), Error> {
-        if let Some(connection) = self.connections.get_mut(connection_id) {
+    pub async fn subscribe(&self, connection_id: &str, topic: String) -> Result<(), Error> {
+        let mut connections = self.connections.write().await;
+        if let Some(connection) = connections.get_mut(connection_id) {
             if !connection.subscriptions.contains(&topic) {
                 connection.subscriptions.push(topic);
             }
@@ -120,28 +383,239 @@ impl WebSocketServer {
     }
 
     /// Unsubscribe connection from a topic
-    pub fn unsubscribe(&mut self, connection_id: &str, topic: &str) -> Result<(), Error> {
-        if let Some(connection) = self.connections.get_mut(connection_id) {
+    pub async fn unsubscribe(&self, connection_id: &str, topic: &str) -> Result<(), Error> {
+        let mut connections = self.connections.write().await;
+        if let Some(connection) = connections.get_mut(connection_id) {
             connection.subscriptions.retain(|t| t != topic);
         }
         Ok(())
     }
 
     /// Get connection count
-    pub fn connection_count(&self) -> usize {
-        self.connections.len()
+    pub async fn connection_count(&self) -> usize {
+        self.connections.read().await.len()
     }
 
-    /// Get connections by subscription
-    pub fn get_connections_by_subscription(&self, topic: &str) -> Vec<&str> {
+    /// Get connection ids subscribed to a topic
+    pub async fn get_connections_by_subscription(&self, topic: &str) -> Vec<String> {
         self.connections
+            .read()
+            .await
             .values()
             .filter(|conn| conn.subscriptions.contains(&topic.to_string()))
-            .map(|conn| conn.id.as_str())
+            .map(|conn| conn.id.clone())
             .collect()
     }
 }
 
+/// Handle a single accepted WebSocket connection
+async fn handle_connection(
+    stream: tokio::net::TcpStream,
+    peer: SocketAddr,
+    connections: ConnectionMap,
+    message_sender: broadcast::Sender<WebSocketMessage>,
+    limits: ConnectionLimits,
+    auth: Option<Arc<ApiKeyAuth>>,
+) -> Result<(), Error> {
+    let ws_stream = tokio_tungstenite::accept_async(stream)
+        .await
+        .map_err(|e| Error::network(format!("WebSocket handshake failed: {}", e)))?;
+    let (mut write, mut read) = ws_stream.split();
+
+    // When auth is configured, the first message must be a valid Auth within
+    // the handshake window; anything else closes the connection.
+    if let Some(auth) = &auth {
+        let authorized = match tokio::time::timeout(AUTH_HANDSHAKE_TIMEOUT, read.next()).await {
+            Ok(Some(Ok(Message::Text(text)))) => matches!(
+                Envelope::decode(&text),
+                Ok(WebSocketMessage::Auth { api_key }) if auth.is_authorized(&api_key)
+            ),
+            _ => false,
+        };
+        if !authorized {
+            tracing::warn!("Closing unauthenticated WebSocket connection from {}", peer);
+            let error = WebSocketMessage::Error {
+                message: "Authentication required".to_string(),
+                code: "unauthorized".to_string(),
+            };
+            let _ = send_message(&mut write, &error).await;
+            let _ = write.send(Message::Close(None)).await;
+            return Ok(());
+        }
+    }
+
+    let connection_id = uuid::Uuid::new_v4().to_string();
+    let (sender, _) = broadcast::channel(100);
+    {
+        // Check capacity and register under one write lock so concurrent
+        // connects cannot both observe spare capacity and exceed the limit
+        let mut conns = connections.write().await;
+        if conns.len() >= limits.max_connections {
+            drop(conns);
+            tracing::warn!("Rejecting WebSocket connection from {}: at capacity", peer);
+            let error = WebSocketMessage::Error {
+                message: format!(
+                    "Server is at its connection limit of {}",
+                    limits.max_connections
+                ),
+                code: "connection_limit".to_string(),
+            };
+            let _ = send_message(&mut write, &error).await;
+            let _ = write.send(Message::Close(None)).await;
+            return Ok(());
+        }
+        conns.insert(
+            connection_id.clone(),
+            WebSocketConnection {
+                id: connection_id.clone(),
+                sender: sender.clone(),
+                subscriptions: Vec::new(),
+            },
+        );
+    }
+    tracing::info!("WebSocket connection {} registered from {}", connection_id, peer);
+
+    let mut direct_receiver = sender.subscribe();
+    let mut broadcast_receiver = message_sender.subscribe();
+
+    // Fixed one-second window for the per-connection message rate limit
+    let mut window_start = tokio::time::Instant::now();
+    let mut window_count = 0u32;
+
+    let result = loop {
+        tokio::select! {
+            // Messages addressed to this connection
+            Ok(message) = direct_receiver.recv() => {
+                if send_message(&mut write, &message).await.is_err() {
+                    break Ok(());
+                }
+            }
+            // Server-wide broadcasts
+            Ok(message) = broadcast_receiver.recv() => {
+                if send_message(&mut write, &message).await.is_err() {
+                    break Ok(());
+                }
+            }
+            // Incoming client messages
+            incoming = read.next() => {
+                if matches!(
+                    incoming,
+                    Some(Ok(Message::Text(_))) | Some(Ok(Message::Binary(_)))
+                ) {
+                    let now = tokio::time::Instant::now();
+                    if now.duration_since(window_start) >= std::time::Duration::from_secs(1) {
+                        window_start = now;
+                        window_count = 0;
+                    }
+                    window_count += 1;
+                    if window_count > limits.max_messages_per_second {
+                        tracing::warn!(
+                            "Closing WebSocket connection {}: message rate exceeded",
+                            connection_id
+                        );
+                        let error = WebSocketMessage::Error {
+                            message: format!(
+                                "Message rate limit of {}/s exceeded",
+                                limits.max_messages_per_second
+                            ),
+                            code: "rate_limited".to_string(),
+                        };
+                        let _ = send_message(&mut write, &error).await;
+                        let _ = write.send(Message::Close(None)).await;
+                        break Ok(());
+                    }
+                }
+                match incoming {
+                    Some(Ok(Message::Text(text))) => {
+                        match serde_json::from_str::<Envelope>(&text) {
+                            Ok(envelope) if envelope.v != PROTOCOL_VERSION => {
+                                let error = WebSocketMessage::Error {
+                                    message: format!(
+                                        "Unsupported protocol version {} (server speaks {})",
+                                        envelope.v, PROTOCOL_VERSION
+                                    ),
+                                    code: "version_mismatch".to_string(),
+                                };
+                                let _ = send_message(&mut write, &error).await;
+                            }
+                            Ok(Envelope {
+                                msg: WebSocketMessage::Subscribe { topic },
+                                ..
+                            }) => {
+                                let mut conns = connections.write().await;
+                                if let Some(conn) = conns.get_mut(&connection_id) {
+                                    if !conn.subscriptions.contains(&topic) {
+                                        conn.subscriptions.push(topic);
+                                    }
+                                }
+                            }
+                            Ok(Envelope {
+                                msg: WebSocketMessage::Unsubscribe { topic },
+                                ..
+                            }) => {
+                                let mut conns = connections.write().await;
+                                if let Some(conn) = conns.get_mut(&connection_id) {
+                                    conn.subscriptions.retain(|t| t != &topic);
+                                }
+                            }
+                            Ok(Envelope {
+                                msg: WebSocketMessage::Ping,
+                                ..
+                            }) => {
+                                let _ = send_message(&mut write, &WebSocketMessage::Pong).await;
+                            }
+                            Ok(_) => {}
+                            Err(e) => {
+                                let error = WebSocketMessage::Error {
+                                    message: format!("Invalid message: {}", e),
+                                    code: "invalid_message".to_string(),
+                                };
+                                let _ = send_message(&mut write, &error).await;
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Binary(bytes))) => {
+                        if let Err(e) = WebSocketMessage::decode_binary(&bytes) {
+                            let error = WebSocketMessage::Error {
+                                message: format!("Invalid binary frame: {}", e),
+                                code: "invalid_message".to_string(),
+                            };
+                            let _ = send_message(&mut write, &error).await;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => break Ok(()),
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => {
+                        break Err(Error::network(format!("WebSocket read error: {}", e)));
+                    }
+                }
+            }
+        }
+    };
+
+    connections.write().await.remove(&connection_id);
+    tracing::info!("WebSocket connection {} removed", connection_id);
+    result
+}
+
+/// Serialize and send a message over the socket, wrapped in a versioned envelope
+///
+/// Sensor data goes out as a binary frame to keep large payloads compact;
+/// everything else is JSON text.
+async fn send_message<S>(write: &mut S, message: &WebSocketMessage) -> Result<(), Error>
+where
+    S: SinkExt<Message> + Unpin,
+{
+    let frame = match message {
+        WebSocketMessage::SensorData { .. } => Message::Binary(message.encode_binary()?),
+        _ => Message::Text(Envelope::new(message.clone()).encode()?),
+    };
+    write
+        .send(frame)
+        .await
+        .map_err(|_| Error::network("Failed to send WebSocket message"))
+}
+
 /// WebSocket client
 pub struct WebSocketClient {
     url: String,
@@ -174,7 +648,9 @@ impl WebSocketClient {
     /// Send message
     pub async fn send(&self, message: WebSocketMessage) -> Result<(), Error> {
         if let Some(connection) = &self.connection {
-            connection.sender.send(message)
+            connection
+                .sender
+                .send(message)
                 .map_err(|_| Error::network("Failed to send message"))?;
         }
         Ok(())
@@ -182,13 +658,11 @@ impl WebSocketClient {
 
     /// Subscribe to topic
     pub async fn subscribe(&mut self, topic: String) -> Result<(), Error> {
-        let message = WebSocketMessage::Ping; // Simplified
-        self.send(message).await
+        self.send(WebSocketMessage::Subscribe { topic }).await
     }
 
     /// Unsubscribe from topic
     pub async fn unsubscribe(&mut self, topic: String) -> Result<(), Error> {
-        let message = WebSocketMessage::Ping; // Simplified
-        self.send(message).await
+        self.send(WebSocketMessage::Unsubscribe { topic }).await
     }
 }
@@ -1,8 +1,13 @@
 //! Arweave blockchain integration
 
 use crate::core::Error;
-use crate::blockchain::{BlockchainClient, Contribution};
+use crate::core::error::BlockchainError;
+use crate::blockchain::BlockchainClient;
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use serde::{Deserialize, Serialize};
+use sha2::Digest;
 
 /// Arweave client configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,7 +66,7 @@ impl ArweaveClient {
             tags: tags.into_iter().map(|(name, value)| ArweaveTag { name, value }).collect(),
             target: "".to_string(),
             quantity: "0".to_string(),
-            data: base64::encode(data),
+            data: STANDARD.encode(data),
             reward: reward.to_string(),
         };
 
@@ -77,7 +82,7 @@ impl ArweaveClient {
             .json(transaction)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to submit transaction: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport("Failed to submit transaction", &e))?;
 
         if response.status().is_success() {
             Ok(transaction.id.clone())
@@ -95,12 +100,16 @@ impl ArweaveClient {
             .get(&url)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to get data: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport("Failed to get data", &e))?;
+
+        if !response.status().is_success() {
+            return Err(BlockchainError::from_status("Failed to get data", response.status()).into());
+        }
 
         let data = response
             .bytes()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to read data: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to read data: {}", e)))?;
 
         Ok(data.to_vec())
     }
@@ -113,12 +122,12 @@ impl ArweaveClient {
             .get(&url)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to get transaction info: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport("Failed to get transaction info", &e))?;
 
         let info: ArweaveTransactionInfo = response
             .json()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to parse transaction info: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to parse transaction info: {}", e)))?;
 
         Ok(info)
     }
@@ -126,7 +135,7 @@ impl ArweaveClient {
     /// Generate transaction ID
     fn generate_transaction_id(&self, data: &[u8]) -> String {
         let hash = sha2::Sha256::digest(data);
-        base64::encode(hash)
+        STANDARD.encode(hash)
     }
 
     /// Calculate transaction reward
@@ -136,6 +145,85 @@ impl ArweaveClient {
         data_size * 1000 // 1000 winston per byte
     }
 
+    /// Estimate the upload cost in winston for a payload of `data_len` bytes
+    ///
+    /// Queries the gateway's `/price/{bytes}` endpoint for the live price;
+    /// if the gateway is unreachable the local flat-rate estimate is used
+    /// instead, and the fallback is logged so callers can tell which one
+    /// they got.
+    pub async fn estimate_cost(&self, data_len: usize) -> Result<u64, Error> {
+        let url = format!("{}/price/{}", self.config.gateway_url, data_len);
+
+        match self.client.get(&url).send().await {
+            Ok(response) if response.status().is_success() => {
+                let body = response
+                    .text()
+                    .await
+                    .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to read price: {}", e)))?;
+                let price = body
+                    .trim()
+                    .parse::<u64>()
+                    .map_err(|_| BlockchainError::MalformedResponse(format!("Invalid price: {}", body)))?;
+                tracing::debug!("Network price for {} bytes: {} winston", data_len, price);
+                Ok(price)
+            }
+            Ok(response) => {
+                Err(BlockchainError::from_status("Failed to get price", response.status()).into())
+            }
+            Err(e) => {
+                let fallback = self.calculate_reward(data_len as u64);
+                tracing::warn!(
+                    "Price endpoint unreachable ({}), using local estimate of {} winston",
+                    e,
+                    fallback
+                );
+                Ok(fallback)
+            }
+        }
+    }
+
+    /// Find transaction ids matching all of the given tags
+    ///
+    /// Issues a GraphQL query against the gateway's `/graphql` endpoint,
+    /// e.g. to find all `App-Name: Kova` uploads for a given sensor.
+    pub async fn query_by_tags(&self, tags: &[(String, String)]) -> Result<Vec<String>, Error> {
+        let tag_filters: Vec<serde_json::Value> = tags
+            .iter()
+            .map(|(name, value)| serde_json::json!({ "name": name, "values": [value] }))
+            .collect();
+
+        let query = serde_json::json!({
+            "query": "query($tags: [TagFilter!]) { transactions(tags: $tags) { edges { node { id } } } }",
+            "variables": { "tags": tag_filters }
+        });
+
+        let url = format!("{}/graphql", self.config.gateway_url);
+        let response = self.client
+            .post(&url)
+            .json(&query)
+            .send()
+            .await
+            .map_err(|e| BlockchainError::from_transport("Failed to query tags", &e))?;
+
+        if !response.status().is_success() {
+            return Err(BlockchainError::from_status("Failed to query tags", response.status()).into());
+        }
+
+        let response_json: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to parse query response: {}", e)))?;
+
+        let edges = response_json["data"]["transactions"]["edges"]
+            .as_array()
+            .ok_or_else(|| BlockchainError::MalformedResponse("Missing transactions in query response".to_string()))?;
+
+        Ok(edges
+            .iter()
+            .filter_map(|edge| edge["node"]["id"].as_str().map(str::to_string))
+            .collect())
+    }
+
     /// Check if Arweave node is available
     pub async fn check_availability(&self) -> Result<bool, Error> {
         let url = format!("{}/info", self.config.gateway_url);
@@ -187,6 +275,19 @@ impl Default for ArweaveConfig {
     }
 }
 
+impl From<crate::core::config::ArweaveConfig> for ArweaveConfig {
+    fn from(config: crate::core::config::ArweaveConfig) -> Self {
+        Self {
+            gateway_url: config.gateway_url,
+            timeout_seconds: config.timeout_seconds,
+            retry_attempts: config.retry_attempts,
+            // Signing keys are never carried in the top-level config
+            ..Self::default()
+        }
+    }
+}
+
+#[async_trait]
 impl BlockchainClient for ArweaveClient {
     fn name(&self) -> &str {
         "Arweave"
@@ -1,7 +1,10 @@
 //! IPFS blockchain integration
 
 use crate::core::Error;
-use crate::blockchain::{BlockchainClient, Contribution};
+use crate::core::error::BlockchainError;
+use crate::core::storage::StorageManager;
+use crate::blockchain::BlockchainClient;
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 /// IPFS client configuration
@@ -17,6 +20,9 @@ pub struct IPFSConfig {
     pub retry_attempts: u32,
     /// Pin content on add
     pub pin_on_add: bool,
+    /// Re-fetch and compare content after every add
+    #[serde(default)]
+    pub verify_after_add: bool,
 }
 
 /// IPFS client implementation
@@ -46,12 +52,12 @@ impl IPFSClient {
             .multipart(form)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to add data to IPFS: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport("Failed to add data to IPFS", &e))?;
 
         let response_text = response
             .text()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to read response: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to read response: {}", e)))?;
 
         // Parse IPFS response
         let lines: Vec<&str> = response_text.trim().split('\n').collect();
@@ -59,7 +65,7 @@ impl IPFSClient {
             .ok_or_else(|| Error::blockchain("Empty IPFS response"))?;
 
         let response_json: serde_json::Value = serde_json::from_str(last_line)
-            .map_err(|e| Error::blockchain(format!("Failed to parse IPFS response: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to parse IPFS response: {}", e)))?;
 
         let hash = response_json["Hash"]
             .as_str()
@@ -70,27 +76,148 @@ impl IPFSClient {
             self.pin(hash).await?;
         }
 
+        // Optionally confirm the node serves back exactly what we sent
+        if self.config.verify_after_add {
+            let fetched = self.get_data(hash).await?;
+            if fetched != data {
+                return Err(Error::blockchain(format!(
+                    "Verification failed for {}: fetched content differs from upload",
+                    hash
+                )));
+            }
+        }
+
         Ok(hash.to_string())
     }
 
+    /// Add several files to IPFS as one wrapped directory
+    ///
+    /// Uses `wrap-with-directory=true` so the files share a root object;
+    /// the returned result carries the root CID plus each file's CID.
+    pub async fn add_directory(&self, files: &[(String, Vec<u8>)]) -> Result<IpfsDirResult, Error> {
+        if files.is_empty() {
+            return Err(Error::blockchain("No files to add"));
+        }
+
+        let mut form = reqwest::multipart::Form::new();
+        for (name, data) in files {
+            form = form.part(
+                "file",
+                reqwest::multipart::Part::bytes(data.clone()).file_name(name.clone()),
+            );
+        }
+
+        let response = self.client
+            .post(&format!(
+                "{}/api/v0/add?wrap-with-directory=true",
+                self.config.api_url
+            ))
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| BlockchainError::from_transport("Failed to add directory to IPFS", &e))?;
+
+        let response_text = response
+            .text()
+            .await
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to read response: {}", e)))?;
+
+        // One JSON object per line; the unnamed entry is the wrapping directory
+        let mut root = None;
+        let mut entries = Vec::new();
+        for line in response_text.trim().lines() {
+            let entry: serde_json::Value = serde_json::from_str(line)
+                .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to parse add output: {}", e)))?;
+            let name = entry["Name"].as_str().unwrap_or("").to_string();
+            let hash = entry["Hash"]
+                .as_str()
+                .ok_or_else(|| Error::blockchain("No hash in IPFS add output"))?
+                .to_string();
+
+            if name.is_empty() {
+                root = Some(hash);
+            } else {
+                entries.push(IpfsDirEntry { name, hash });
+            }
+        }
+
+        let root = root.ok_or_else(|| Error::blockchain("No directory root in IPFS add output"))?;
+
+        if self.config.pin_on_add {
+            self.pin(&root).await?;
+        }
+
+        Ok(IpfsDirResult { root, entries })
+    }
+
     /// Get data from IPFS
+    ///
+    /// Tries the local node's API first; if the block is missing or the
+    /// node is unreachable, falls back to fetching through the configured
+    /// public gateway.
     pub async fn get_data(&self, hash: &str) -> Result<Vec<u8>, Error> {
+        match self.get_data_from_api(hash).await {
+            Ok(data) => Ok(data),
+            Err(e) if Self::should_try_gateway(&e) => {
+                tracing::warn!("API retrieval of {} failed ({}), trying gateway", hash, e);
+                self.get_data_from_gateway(hash).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Fetch content through the local node's cat API
+    async fn get_data_from_api(&self, hash: &str) -> Result<Vec<u8>, Error> {
         let url = format!("{}/api/v0/cat?arg={}", self.config.api_url, hash);
         
         let response = self.client
             .post(&url)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to get data from IPFS: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport("Failed to get data from IPFS", &e))?;
+
+        if !response.status().is_success() {
+            return Err(BlockchainError::from_status("Failed to get data from IPFS", response.status()).into());
+        }
+
+        let data = response
+            .bytes()
+            .await
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to read data: {}", e)))?;
+
+        Ok(data.to_vec())
+    }
+
+    /// Fetch content through the configured public gateway
+    async fn get_data_from_gateway(&self, hash: &str) -> Result<Vec<u8>, Error> {
+        let url = format!("{}/ipfs/{}", self.config.gateway_url, hash);
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| BlockchainError::from_transport("Failed to get data from gateway", &e))?;
+
+        if !response.status().is_success() {
+            return Err(BlockchainError::from_status("Failed to get data from gateway", response.status()).into());
+        }
 
         let data = response
             .bytes()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to read data: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to read data: {}", e)))?;
 
         Ok(data.to_vec())
     }
 
+    /// Whether an API failure is worth retrying through the gateway
+    fn should_try_gateway(error: &Error) -> bool {
+        matches!(
+            error,
+            Error::Blockchain(BlockchainError::NotFound(_))
+        ) || error.is_retryable()
+    }
+
     /// Pin content in IPFS
     pub async fn pin(&self, hash: &str) -> Result<(), Error> {
         let url = format!("{}/api/v0/pin/add?arg={}", self.config.api_url, hash);
@@ -99,7 +226,7 @@ impl IPFSClient {
             .post(&url)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to pin content: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport("Failed to pin content", &e))?;
 
         Ok(())
     }
@@ -112,7 +239,7 @@ impl IPFSClient {
             .post(&url)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to unpin content: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport("Failed to unpin content", &e))?;
 
         Ok(())
     }
@@ -125,15 +252,15 @@ impl IPFSClient {
             .post(&url)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to list pins: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport("Failed to list pins", &e))?;
 
         let response_text = response
             .text()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to read response: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to read response: {}", e)))?;
 
         let response_json: serde_json::Value = serde_json::from_str(&response_text)
-            .map_err(|e| Error::blockchain(format!("Failed to parse response: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to parse response: {}", e)))?;
 
         let pins = response_json["Keys"]
             .as_object()
@@ -155,15 +282,15 @@ impl IPFSClient {
             .post(&url)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to get stats: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport("Failed to get stats", &e))?;
 
         let response_text = response
             .text()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to read response: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to read response: {}", e)))?;
 
         let stats: IPFSStats = serde_json::from_str(&response_text)
-            .map_err(|e| Error::blockchain(format!("Failed to parse stats: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to parse stats: {}", e)))?;
 
         Ok(stats)
     }
@@ -179,6 +306,159 @@ impl IPFSClient {
     }
 }
 
+/// Progress record for a resumable IPFS upload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadProgress {
+    /// Session identifier
+    pub session_id: String,
+    /// Chunk size in bytes
+    pub chunk_size: usize,
+    /// Total payload length in bytes
+    pub total_len: usize,
+    /// Per-chunk hashes; `None` for chunks not yet uploaded
+    pub chunk_hashes: Vec<Option<String>>,
+}
+
+/// Resumable multipart upload session for IPFS
+///
+/// Chunks a payload, uploads each chunk as its own IPFS object, and persists
+/// progress via [`StorageManager`] so an interrupted upload resumes from the
+/// last successful chunk. Completed chunks are linked into a single manifest
+/// object whose CID is returned as the final handle.
+pub struct IPFSUploadSession {
+    progress: UploadProgress,
+}
+
+impl IPFSUploadSession {
+    /// Create a new upload session for a payload of the given length
+    pub fn new(session_id: String, total_len: usize, chunk_size: usize) -> Result<Self, Error> {
+        if chunk_size == 0 {
+            return Err(Error::blockchain("Chunk size must be greater than zero"));
+        }
+
+        let chunk_count = (total_len + chunk_size - 1) / chunk_size;
+        Ok(Self {
+            progress: UploadProgress {
+                session_id,
+                chunk_size,
+                total_len,
+                chunk_hashes: vec![None; chunk_count],
+            },
+        })
+    }
+
+    /// Resume a previously persisted upload session
+    pub async fn resume(storage: &StorageManager, session_id: &str) -> Result<Self, Error> {
+        let data = storage.retrieve(&Self::storage_key(session_id)).await?;
+        let progress: UploadProgress = serde_json::from_slice(&data)?;
+        Ok(Self { progress })
+    }
+
+    /// Storage key for a session's progress record
+    fn storage_key(session_id: &str) -> String {
+        format!("ipfs_sessions/{}.json", session_id)
+    }
+
+    /// Get the session identifier
+    pub fn session_id(&self) -> &str {
+        &self.progress.session_id
+    }
+
+    /// Get the indices of chunks not yet uploaded
+    pub fn pending_chunks(&self) -> Vec<usize> {
+        self.progress
+            .chunk_hashes
+            .iter()
+            .enumerate()
+            .filter(|(_, hash)| hash.is_none())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Check whether every chunk has been uploaded
+    pub fn is_complete(&self) -> bool {
+        self.progress.chunk_hashes.iter().all(|hash| hash.is_some())
+    }
+
+    /// Record a completed chunk upload
+    pub fn mark_chunk_complete(&mut self, index: usize, hash: String) -> Result<(), Error> {
+        let slot = self
+            .progress
+            .chunk_hashes
+            .get_mut(index)
+            .ok_or_else(|| Error::blockchain(format!("Chunk index {} out of range", index)))?;
+        *slot = Some(hash);
+        Ok(())
+    }
+
+    /// Persist the current progress via the storage manager
+    pub async fn persist(&self, storage: &StorageManager) -> Result<(), Error> {
+        let data = serde_json::to_vec(&self.progress)?;
+        storage
+            .store(&Self::storage_key(&self.progress.session_id), &data)
+            .await
+    }
+
+    /// Upload all pending chunks, persisting progress after each, then link
+    /// the chunks into a single manifest object and return its CID
+    pub async fn upload(
+        &mut self,
+        client: &IPFSClient,
+        storage: &StorageManager,
+        data: &[u8],
+    ) -> Result<String, Error> {
+        if data.len() != self.progress.total_len {
+            return Err(Error::blockchain(format!(
+                "Payload length {} does not match session length {}",
+                data.len(),
+                self.progress.total_len
+            )));
+        }
+
+        for index in self.pending_chunks() {
+            let start = index * self.progress.chunk_size;
+            let end = (start + self.progress.chunk_size).min(data.len());
+            let hash = client.add_data(&data[start..end]).await?;
+            self.mark_chunk_complete(index, hash)?;
+            self.persist(storage).await?;
+        }
+
+        // Link the chunks into one manifest object
+        let manifest = serde_json::json!({
+            "session_id": self.progress.session_id,
+            "total_len": self.progress.total_len,
+            "chunk_size": self.progress.chunk_size,
+            "chunks": self.progress.chunk_hashes,
+        });
+        let root = client.add_data(&serde_json::to_vec(&manifest)?).await?;
+
+        // The session is complete; drop the progress record
+        storage
+            .delete(&Self::storage_key(&self.progress.session_id))
+            .await?;
+
+        Ok(root)
+    }
+}
+
+/// One file within a wrapped directory add
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpfsDirEntry {
+    /// File name within the directory
+    pub name: String,
+    /// File CID
+    pub hash: String,
+}
+
+/// Result of a wrapped directory add
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpfsDirResult {
+    /// CID of the wrapping directory
+    pub root: String,
+    /// Per-file CIDs
+    pub entries: Vec<IpfsDirEntry>,
+}
+
 /// IPFS statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IPFSStats {
@@ -198,10 +478,24 @@ impl Default for IPFSConfig {
             timeout_seconds: 60,
             retry_attempts: 3,
             pin_on_add: true,
+            verify_after_add: false,
+        }
+    }
+}
+
+impl From<crate::core::config::IPFSConfig> for IPFSConfig {
+    fn from(config: crate::core::config::IPFSConfig) -> Self {
+        Self {
+            api_url: config.api_url,
+            gateway_url: config.gateway_url,
+            timeout_seconds: config.timeout_seconds,
+            pin_on_add: config.pin_on_add,
+            ..Self::default()
         }
     }
 }
 
+#[async_trait]
 impl BlockchainClient for IPFSClient {
     fn name(&self) -> &str {
         "IPFS"
@@ -2,79 +2,623 @@
 
 use crate::core::Error;
 use crate::blockchain::{BlockchainClient, Contribution};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::collections::HashMap;
 use tokio::sync::RwLock;
 
+/// Magic/version header prefixed to compressed blobs ("KVZ" + version 1)
+const COMPRESSED_MAGIC: &[u8] = b"KVZ\x01";
+
+/// zstd compression level used for stored blobs
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Manifest describing a payload stored as ordered chunks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, serde::Deserialize)]
+pub struct ChunkManifest {
+    /// Hashes of the stored chunks, in payload order
+    pub chunk_hashes: Vec<String>,
+    /// Chunk size used when splitting
+    pub chunk_size: usize,
+    /// Total payload length in bytes
+    pub total_length: usize,
+}
+
+/// Wire encoding used for on-chain contribution payloads
+///
+/// The chosen format is recorded in a one-byte prefix so retrieval can
+/// pick the right decoder regardless of the manager's current setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SerializationFormat {
+    /// Human-readable JSON
+    #[default]
+    Json,
+    /// Compact CBOR
+    Cbor,
+    /// Compact MessagePack
+    MessagePack,
+}
+
+impl SerializationFormat {
+    fn prefix(&self) -> u8 {
+        match self {
+            Self::Json => 0,
+            Self::Cbor => 1,
+            Self::MessagePack => 2,
+        }
+    }
+
+    fn from_prefix(prefix: u8) -> Result<Self, Error> {
+        match prefix {
+            0 => Ok(Self::Json),
+            1 => Ok(Self::Cbor),
+            2 => Ok(Self::MessagePack),
+            other => Err(Error::blockchain(format!(
+                "Unknown serialization format prefix: {}",
+                other
+            ))),
+        }
+    }
+
+    /// Encode a value with a one-byte format prefix
+    pub fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Error> {
+        let mut bytes = vec![self.prefix()];
+        match self {
+            Self::Json => serde_json::to_writer(&mut bytes, value)?,
+            Self::Cbor => serde_cbor::to_writer(&mut bytes, value)
+                .map_err(|e| Error::blockchain(format!("CBOR encoding failed: {}", e)))?,
+            Self::MessagePack => {
+                let payload = rmp_serde::to_vec_named(value)
+                    .map_err(|e| Error::blockchain(format!("MessagePack encoding failed: {}", e)))?;
+                bytes.extend_from_slice(&payload);
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// Decode a prefixed payload, dispatching on the recorded format
+    pub fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error> {
+        let (prefix, payload) = bytes
+            .split_first()
+            .ok_or_else(|| Error::blockchain("Empty contribution payload"))?;
+
+        match Self::from_prefix(*prefix)? {
+            Self::Json => Ok(serde_json::from_slice(payload)?),
+            Self::Cbor => serde_cbor::from_slice(payload)
+                .map_err(|e| Error::blockchain(format!("CBOR decoding failed: {}", e))),
+            Self::MessagePack => rmp_serde::from_slice(payload)
+                .map_err(|e| Error::blockchain(format!("MessagePack decoding failed: {}", e))),
+        }
+    }
+}
+
+/// Priority assigned to clients registered without an explicit one
+const DEFAULT_PRIORITY: u32 = 100;
+
+/// Consecutive failures before a client's breaker opens
+const BREAKER_FAILURE_THRESHOLD: u32 = 3;
+
+/// How long an open breaker skips its client before allowing a trial request
+const BREAKER_COOLDOWN: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Observable state of a client's circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    /// Requests flow normally
+    Closed,
+    /// The client is skipped until the cooldown elapses
+    Open,
+    /// The cooldown elapsed; the next request is a trial
+    HalfOpen,
+}
+
+/// Per-client circuit breaker
+///
+/// Opens after a run of consecutive failures so a persistently-down backend
+/// is not retried on every call; after the cooldown a single trial request
+/// is allowed through, closing the breaker again on success.
+struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: std::time::Duration,
+    inner: std::sync::Mutex<BreakerInner>,
+}
+
+struct BreakerInner {
+    consecutive_failures: u32,
+    opened_at: Option<std::time::Instant>,
+}
+
+impl CircuitBreaker {
+    fn new(failure_threshold: u32, cooldown: std::time::Duration) -> Self {
+        Self {
+            failure_threshold,
+            cooldown,
+            inner: std::sync::Mutex::new(BreakerInner {
+                consecutive_failures: 0,
+                opened_at: None,
+            }),
+        }
+    }
+
+    fn state(&self) -> BreakerState {
+        let inner = self.inner.lock().unwrap();
+        match inner.opened_at {
+            None => BreakerState::Closed,
+            Some(at) if at.elapsed() >= self.cooldown => BreakerState::HalfOpen,
+            Some(_) => BreakerState::Open,
+        }
+    }
+
+    fn allows_request(&self) -> bool {
+        self.state() != BreakerState::Open
+    }
+
+    fn record_success(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+    }
+
+    fn record_failure(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.consecutive_failures += 1;
+        if inner.consecutive_failures >= self.failure_threshold {
+            inner.opened_at = Some(std::time::Instant::now());
+        }
+    }
+}
+
+/// A registered client together with its failover priority and breaker
+struct ClientEntry {
+    name: String,
+    priority: u32,
+    client: Box<dyn BlockchainClient>,
+    breaker: CircuitBreaker,
+}
+
+/// Default storage cost estimate, in currency units per gigabyte
+const DEFAULT_COST_PER_GB: f64 = 0.05;
+
+/// Per-client usage accounting
+#[derive(Debug, Clone, Default, PartialEq, Serialize, serde::Deserialize)]
+pub struct UsageStats {
+    /// Requests issued to the client, successful or not
+    pub requests: u64,
+    /// Bytes successfully stored through the client
+    pub bytes_stored: u64,
+    /// Accumulated storage cost estimate
+    pub estimated_cost: f64,
+}
+
 /// Blockchain manager for handling multiple blockchain clients
 pub struct BlockchainManager {
-    clients: RwLock<HashMap<String, Box<dyn BlockchainClient>>>,
+    clients: RwLock<Vec<ClientEntry>>,
+    batch_pack: bool,
+    format: SerializationFormat,
+    breaker_threshold: u32,
+    breaker_cooldown: std::time::Duration,
+    dead_letter_dir: Option<std::path::PathBuf>,
+    usage: RwLock<HashMap<String, UsageStats>>,
+    cost_per_gb: f64,
 }
 
 impl BlockchainManager {
     /// Create a new blockchain manager
     pub fn new() -> Self {
         Self {
-            clients: RwLock::new(HashMap::new()),
+            clients: RwLock::new(Vec::new()),
+            batch_pack: false,
+            format: SerializationFormat::default(),
+            breaker_threshold: BREAKER_FAILURE_THRESHOLD,
+            breaker_cooldown: BREAKER_COOLDOWN,
+            dead_letter_dir: None,
+            usage: RwLock::new(HashMap::new()),
+            cost_per_gb: DEFAULT_COST_PER_GB,
+        }
+    }
+
+    /// Set the per-gigabyte rate used for cost estimates
+    pub fn set_cost_per_gb(&mut self, cost_per_gb: f64) {
+        self.cost_per_gb = cost_per_gb;
+    }
+
+    /// Usage accounting per client since creation or the last reset
+    pub async fn usage_report(&self) -> HashMap<String, UsageStats> {
+        self.usage.read().await.clone()
+    }
+
+    /// Clear the usage accounting
+    pub async fn reset_usage(&self) {
+        self.usage.write().await.clear();
+    }
+
+    /// Count a request against a client, with bytes on a successful store
+    async fn record_usage(&self, client_name: &str, stored_bytes: Option<usize>) {
+        let mut usage = self.usage.write().await;
+        let stats = usage.entry(client_name.to_string()).or_default();
+        stats.requests += 1;
+        if let Some(bytes) = stored_bytes {
+            stats.bytes_stored += bytes as u64;
+            stats.estimated_cost += bytes as f64 / 1e9 * self.cost_per_gb;
         }
     }
 
-    /// Add a blockchain client
+    /// Persist contributions that fail against every backend to a directory
+    ///
+    /// Dead-lettered contributions can be re-attempted later with
+    /// [`BlockchainManager::retry_dead_letters`].
+    pub fn set_dead_letter_queue(&mut self, dir: std::path::PathBuf) {
+        self.dead_letter_dir = Some(dir);
+    }
+
+    /// Configure the circuit breaker applied to clients registered afterwards
+    pub fn set_breaker_policy(&mut self, failure_threshold: u32, cooldown: std::time::Duration) {
+        self.breaker_threshold = failure_threshold;
+        self.breaker_cooldown = cooldown;
+    }
+
+    /// Pack batched contributions into a single blob instead of storing each
+    /// one individually
+    pub fn set_batch_pack(&mut self, batch_pack: bool) {
+        self.batch_pack = batch_pack;
+    }
+
+    /// Choose the wire encoding for contribution payloads
+    pub fn set_serialization_format(&mut self, format: SerializationFormat) {
+        self.format = format;
+    }
+
+    /// Add a blockchain client with the default priority
     pub async fn add_client(&self, name: String, client: Box<dyn BlockchainClient>) {
+        self.add_client_with_priority(name, client, DEFAULT_PRIORITY)
+            .await;
+    }
+
+    /// Add a blockchain client with an explicit failover priority
+    ///
+    /// Lower priorities are tried first; clients sharing a priority keep
+    /// their registration order. Registering a name again replaces the
+    /// previous client.
+    pub async fn add_client_with_priority(
+        &self,
+        name: String,
+        client: Box<dyn BlockchainClient>,
+        priority: u32,
+    ) {
         let mut clients = self.clients.write().await;
-        clients.insert(name, client);
+        clients.retain(|entry| entry.name != name);
+        clients.push(ClientEntry {
+            name,
+            priority,
+            client,
+            breaker: CircuitBreaker::new(self.breaker_threshold, self.breaker_cooldown),
+        });
+        clients.sort_by_key(|entry| entry.priority);
+    }
+
+    /// Get the circuit-breaker state of every registered client
+    pub async fn breaker_states(&self) -> HashMap<String, BreakerState> {
+        let clients = self.clients.read().await;
+        clients
+            .iter()
+            .map(|entry| (entry.name.clone(), entry.breaker.state()))
+            .collect()
+    }
+
+    /// Get the names of all registered clients, in failover order
+    pub async fn client_names(&self) -> Vec<String> {
+        let clients = self.clients.read().await;
+        clients.iter().map(|entry| entry.name.clone()).collect()
+    }
+
+    /// Check the availability of every registered client concurrently
+    pub async fn availability(&self) -> HashMap<String, bool> {
+        let clients = self.clients.read().await;
+
+        let checks = clients.iter().map(|entry| async move {
+            (entry.name.clone(), entry.client.is_available().await)
+        });
+
+        futures_util::future::join_all(checks).await.into_iter().collect()
     }
 
-    /// Store data using the first available client
+    /// Store data using the first available client, in priority order
     pub async fn store_data(&self, data: &[u8]) -> Result<String, Error> {
         let clients = self.clients.read().await;
-        
-        for (name, client) in clients.iter() {
-            if client.is_available().await {
-                match client.store_data(data).await {
+
+        for entry in clients.iter() {
+            if !entry.breaker.allows_request() {
+                tracing::debug!("Skipping {}: circuit breaker open", entry.name);
+                continue;
+            }
+            if entry.client.is_available().await {
+                match entry.client.store_data(data).await {
                     Ok(hash) => {
-                        tracing::info!("Data stored using {}: {}", name, hash);
+                        entry.breaker.record_success();
+                        self.record_usage(&entry.name, Some(data.len())).await;
+                        tracing::info!("Data stored using {}: {}", entry.name, hash);
                         return Ok(hash);
                     }
+                    Err(e) if e.is_retryable() => {
+                        entry.breaker.record_failure();
+                        self.record_usage(&entry.name, None).await;
+                        tracing::warn!("Failed to store data using {}: {}", entry.name, e);
+                    }
                     Err(e) => {
-                        tracing::warn!("Failed to store data using {}: {}", name, e);
+                        // Non-retryable: trying another client won't help
+                        entry.breaker.record_failure();
+                        self.record_usage(&entry.name, None).await;
+                        return Err(e);
                     }
                 }
             }
         }
-        
+
         Err(Error::blockchain("No available blockchain clients"))
     }
 
-    /// Retrieve data using the first available client
+    /// Store a captured frame, correlating log records by its frame id
+    pub async fn store_frame(&self, frame: &crate::sensors::SensorData) -> Result<String, Error> {
+        use tracing::Instrument;
+
+        let span = tracing::info_span!("store", frame_id = %frame.frame_id);
+        async {
+            tracing::info!(frame_id = %frame.frame_id, "Storing frame");
+            self.store_data(&frame.data).await
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Store data split into fixed-size chunks
+    ///
+    /// Each chunk is stored separately; the returned manifest lists the
+    /// chunk hashes in order so the payload can be reassembled later.
+    pub async fn store_chunked(
+        &self,
+        data: &[u8],
+        chunk_size: usize,
+    ) -> Result<ChunkManifest, Error> {
+        if chunk_size == 0 {
+            return Err(Error::blockchain("Chunk size must be non-zero"));
+        }
+
+        let mut chunk_hashes = Vec::with_capacity(data.len().div_ceil(chunk_size));
+        for chunk in data.chunks(chunk_size) {
+            chunk_hashes.push(self.store_data(chunk).await?);
+        }
+
+        Ok(ChunkManifest {
+            chunk_hashes,
+            chunk_size,
+            total_length: data.len(),
+        })
+    }
+
+    /// Reassemble a payload stored via `store_chunked`
+    pub async fn retrieve_chunked(&self, manifest: &ChunkManifest) -> Result<Vec<u8>, Error> {
+        let mut data = Vec::with_capacity(manifest.total_length);
+        for hash in &manifest.chunk_hashes {
+            data.extend_from_slice(&self.retrieve_data(hash).await?);
+        }
+
+        if data.len() != manifest.total_length {
+            return Err(Error::blockchain(format!(
+                "Reassembled {} bytes but manifest expects {}",
+                data.len(),
+                manifest.total_length
+            )));
+        }
+
+        Ok(data)
+    }
+
+    /// Store data zstd-compressed with a small magic/version header
+    pub async fn store_compressed(&self, data: &[u8]) -> Result<String, Error> {
+        let compressed = zstd::encode_all(data, COMPRESSION_LEVEL)
+            .map_err(|e| Error::blockchain(format!("Compression failed: {}", e)))?;
+
+        let mut blob = Vec::with_capacity(COMPRESSED_MAGIC.len() + compressed.len());
+        blob.extend_from_slice(COMPRESSED_MAGIC);
+        blob.extend_from_slice(&compressed);
+
+        self.store_data(&blob).await
+    }
+
+    /// Retrieve and decompress data stored via `store_compressed`
+    pub async fn retrieve_decompressed(&self, hash: &str) -> Result<Vec<u8>, Error> {
+        let blob = self.retrieve_data(hash).await?;
+
+        let payload = blob
+            .strip_prefix(COMPRESSED_MAGIC)
+            .ok_or_else(|| Error::blockchain("Data is not a compressed blob"))?;
+
+        zstd::decode_all(payload)
+            .map_err(|e| Error::blockchain(format!("Decompression failed: {}", e)))
+    }
+
+    /// Retrieve data using the first available client, in priority order
     pub async fn retrieve_data(&self, hash: &str) -> Result<Vec<u8>, Error> {
         let clients = self.clients.read().await;
-        
-        for (name, client) in clients.iter() {
-            if client.is_available().await {
-                match client.retrieve_data(hash).await {
+
+        for entry in clients.iter() {
+            if !entry.breaker.allows_request() {
+                tracing::debug!("Skipping {}: circuit breaker open", entry.name);
+                continue;
+            }
+            if entry.client.is_available().await {
+                match entry.client.retrieve_data(hash).await {
                     Ok(data) => {
-                        tracing::info!("Data retrieved using {}: {} bytes", name, data.len());
+                        entry.breaker.record_success();
+                        self.record_usage(&entry.name, None).await;
+                        tracing::info!("Data retrieved using {}: {} bytes", entry.name, data.len());
                         return Ok(data);
                     }
+                    Err(e) if e.is_retryable() => {
+                        entry.breaker.record_failure();
+                        self.record_usage(&entry.name, None).await;
+                        tracing::warn!("Failed to retrieve data using {}: {}", entry.name, e);
+                    }
                     Err(e) => {
-                        tracing::warn!("Failed to retrieve data using {}: {}", name, e);
+                        // Non-retryable: trying another client won't help
+                        entry.breaker.record_failure();
+                        self.record_usage(&entry.name, None).await;
+                        return Err(e);
                     }
                 }
             }
         }
-        
+
         Err(Error::blockchain("No available blockchain clients"))
     }
 
+    /// Retrieve data and verify its SHA-256 digest
+    ///
+    /// Guards against a gateway returning corrupted or substituted bytes:
+    /// the fetched payload is hashed and compared against the expected
+    /// digest, erroring on mismatch.
+    pub async fn retrieve_verified(
+        &self,
+        hash: &str,
+        expected_sha256: &[u8; 32],
+    ) -> Result<Vec<u8>, Error> {
+        use sha2::{Digest, Sha256};
+
+        let data = self.retrieve_data(hash).await?;
+        let actual = Sha256::digest(&data);
+
+        if actual.as_slice() != expected_sha256 {
+            return Err(Error::blockchain(format!(
+                "Checksum mismatch for {}: expected {}, got {}",
+                hash,
+                hex::encode(expected_sha256),
+                hex::encode(actual)
+            )));
+        }
+        Ok(data)
+    }
+
     /// Submit a contribution
+    ///
+    /// If every backend fails and a dead-letter queue is configured, the
+    /// contribution is persisted there before the error is returned.
     pub async fn submit_contribution(&self, contribution: &Contribution) -> Result<String, Error> {
-        // Serialize contribution
-        let data = serde_json::to_vec(contribution)?;
-        
+        // Serialize contribution with a format-prefixed encoding
+        let data = self.format.encode(contribution)?;
+
         // Store on blockchain
-        let hash = self.store_data(&data).await?;
-        
-        tracing::info!("Contribution submitted: {}", hash);
-        Ok(hash)
+        match self.store_data(&data).await {
+            Ok(hash) => {
+                tracing::info!("Contribution submitted: {}", hash);
+                Ok(hash)
+            }
+            Err(e) => {
+                if let Some(dir) = &self.dead_letter_dir {
+                    let path = dir.join(format!("{}.json", uuid::Uuid::new_v4()));
+                    std::fs::create_dir_all(dir)?;
+                    std::fs::write(&path, serde_json::to_vec_pretty(contribution)?)?;
+                    tracing::warn!(
+                        "Contribution dead-lettered to {} after: {}",
+                        path.display(),
+                        e
+                    );
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Count the contributions currently in the dead-letter queue
+    pub fn dead_letter_count(&self) -> Result<usize, Error> {
+        let Some(dir) = &self.dead_letter_dir else {
+            return Ok(0);
+        };
+        if !dir.exists() {
+            return Ok(0);
+        }
+        Ok(std::fs::read_dir(dir)?.filter_map(|e| e.ok()).count())
+    }
+
+    /// Re-attempt every dead-lettered contribution
+    ///
+    /// Successfully resubmitted contributions are removed from the queue and
+    /// their hashes returned; the rest stay queued for the next retry.
+    pub async fn retry_dead_letters(&self) -> Result<Vec<String>, Error> {
+        let Some(dir) = &self.dead_letter_dir else {
+            return Ok(Vec::new());
+        };
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut hashes = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let contribution: Contribution = serde_json::from_slice(&std::fs::read(&path)?)?;
+
+            // Bypass submit_contribution so a repeated failure does not
+            // dead-letter the same contribution twice.
+            let data = self.format.encode(&contribution)?;
+            match self.store_data(&data).await {
+                Ok(hash) => {
+                    std::fs::remove_file(&path)?;
+                    tracing::info!("Dead-lettered contribution resubmitted: {}", hash);
+                    hashes.push(hash);
+                }
+                Err(e) => {
+                    tracing::warn!("Dead-letter retry failed for {}: {}", path.display(), e);
+                }
+            }
+        }
+        Ok(hashes)
+    }
+
+    /// Retrieve and decode a contribution stored by [`submit_contribution`]
+    ///
+    /// [`submit_contribution`]: Self::submit_contribution
+    pub async fn retrieve_contribution(&self, hash: &str) -> Result<Contribution, Error> {
+        let data = self.retrieve_data(hash).await?;
+        SerializationFormat::decode(&data)
+    }
+
+    /// Submit a batch of contributions, returning per-item hashes
+    ///
+    /// When batch packing is enabled the whole batch is stored as a single
+    /// blob and one hash is returned. Otherwise each contribution is stored
+    /// individually; partial failures are collected into the returned error.
+    pub async fn submit_contributions(
+        &self,
+        contributions: &[Contribution],
+    ) -> Result<Vec<String>, Error> {
+        if self.batch_pack {
+            let data = self.format.encode(&contributions)?;
+            let hash = self.store_data(&data).await?;
+            tracing::info!("Packed {} contributions into {}", contributions.len(), hash);
+            return Ok(vec![hash]);
+        }
+
+        let mut hashes = Vec::new();
+        let mut failures = Vec::new();
+
+        for (index, contribution) in contributions.iter().enumerate() {
+            match self.submit_contribution(contribution).await {
+                Ok(hash) => hashes.push(hash),
+                Err(e) => failures.push(format!("contribution {}: {}", index, e)),
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(hashes)
+        } else {
+            Err(Error::blockchain(format!(
+                "{} of {} contributions failed: {}",
+                failures.len(),
+                contributions.len(),
+                failures.join("; ")
+            )))
+        }
     }
 }
@@ -0,0 +1,80 @@
+//! In-memory mock blockchain client for tests and local development
+
+use crate::blockchain::BlockchainClient;
+use crate::core::Error;
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// In-memory blockchain client keyed by SHA-256 content hashes
+///
+/// Useful for exercising `BlockchainManager` round-trips without a
+/// network. Storage is process-local and lost on drop.
+pub struct MockBlockchainClient {
+    storage: Mutex<HashMap<String, Vec<u8>>>,
+    available: bool,
+}
+
+impl MockBlockchainClient {
+    /// Create an empty, available mock client
+    pub fn new() -> Self {
+        Self {
+            storage: Mutex::new(HashMap::new()),
+            available: true,
+        }
+    }
+
+    /// Create a mock client that reports itself unavailable
+    pub fn unavailable() -> Self {
+        Self {
+            storage: Mutex::new(HashMap::new()),
+            available: false,
+        }
+    }
+
+    /// Number of stored blobs
+    pub fn len(&self) -> usize {
+        self.storage.lock().unwrap().len()
+    }
+
+    /// Whether nothing has been stored yet
+    pub fn is_empty(&self) -> bool {
+        self.storage.lock().unwrap().is_empty()
+    }
+}
+
+impl Default for MockBlockchainClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl BlockchainClient for MockBlockchainClient {
+    fn name(&self) -> &str {
+        "Mock"
+    }
+
+    async fn is_available(&self) -> bool {
+        self.available
+    }
+
+    async fn store_data(&self, data: &[u8]) -> Result<String, Error> {
+        let hash = hex::encode(Sha256::digest(data));
+        self.storage
+            .lock()
+            .unwrap()
+            .insert(hash.clone(), data.to_vec());
+        Ok(hash)
+    }
+
+    async fn retrieve_data(&self, hash: &str) -> Result<Vec<u8>, Error> {
+        self.storage
+            .lock()
+            .unwrap()
+            .get(hash)
+            .cloned()
+            .ok_or_else(|| Error::blockchain(format!("Hash not found: {}", hash)))
+    }
+}
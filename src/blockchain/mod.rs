@@ -4,10 +4,20 @@ pub mod arweave;
 pub mod ipfs;
 pub mod solana;
 pub mod manager;
+#[cfg(feature = "test-utils")]
+pub mod mock;
+pub mod store;
 
-pub use manager::BlockchainManager;
+pub use arweave::{ArweaveClient, ArweaveConfig};
+pub use ipfs::{IPFSClient, IPFSConfig};
+pub use manager::{BlockchainManager, BreakerState, ChunkManifest, SerializationFormat, UsageStats};
+#[cfg(feature = "test-utils")]
+pub use mock::MockBlockchainClient;
+pub use solana::{SolanaClient, SolanaConfig};
+pub use store::{ContributionFilter, ContributionStore};
 
 /// Blockchain client trait
+#[async_trait::async_trait]
 pub trait BlockchainClient: Send + Sync {
     /// Get client name
     fn name(&self) -> &str;
@@ -37,4 +47,60 @@ pub struct Contribution {
     pub validator_id: String,
     /// Sensor ID
     pub sensor_id: String,
+}
+
+impl Contribution {
+    /// Build a contribution from a validation result
+    ///
+    /// Copies the quality score and timestamp, and chains the validation
+    /// signature over the contribution's own fields so that tampering
+    /// with any of them is detectable via [`verify`](Self::verify).
+    pub fn from_validation(
+        sensor_data_hash: String,
+        sensor_id: String,
+        validator_id: String,
+        result: &crate::core::validation::ValidationResult,
+    ) -> Self {
+        let mut contribution = Self {
+            sensor_data_hash,
+            validator_signature: String::new(),
+            timestamp: result.timestamp,
+            quality_score: result.quality_score,
+            validator_id,
+            sensor_id,
+        };
+        contribution.validator_signature = contribution.chained_signature(&result.signature);
+        contribution
+    }
+
+    /// Check the signature against the referenced sensor data
+    ///
+    /// Recomputes the validation signature over `data` and the
+    /// contribution timestamp — the same digest
+    /// `DataValidator::generate_signature` produces — then re-chains it
+    /// over the contribution fields. Any change to the data, timestamp,
+    /// data hash, quality score or ids makes this return `false`.
+    pub fn verify(&self, data: &[u8]) -> bool {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        hasher.update(data);
+        hasher.update(self.timestamp.to_rfc3339().as_bytes());
+        let base = hex::encode(hasher.finalize());
+
+        self.validator_signature == self.chained_signature(&base)
+    }
+
+    /// Digest binding a validation signature to this contribution's fields
+    fn chained_signature(&self, validation_signature: &str) -> String {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        hasher.update(validation_signature.as_bytes());
+        hasher.update(self.sensor_data_hash.as_bytes());
+        hasher.update(self.quality_score.to_le_bytes());
+        hasher.update(self.validator_id.as_bytes());
+        hasher.update(self.sensor_id.as_bytes());
+        hex::encode(hasher.finalize())
+    }
 }
\ No newline at end of file
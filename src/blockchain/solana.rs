@@ -1,8 +1,13 @@
 //! Solana blockchain integration
 
 use crate::core::Error;
+use crate::core::error::BlockchainError;
 use crate::blockchain::{BlockchainClient, Contribution};
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
 use serde::{Deserialize, Serialize};
+use sha2::Digest;
 
 /// Solana client configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,26 +41,93 @@ impl SolanaClient {
         Ok(Self { config, client })
     }
 
-    /// Get account balance
-    pub async fn get_balance(&self, address: &str) -> Result<f64, Error> {
+    /// Issue a JSON-RPC call, retrying transient failures with backoff
+    ///
+    /// Transport errors and retryable JSON-RPC errors (blockhash not
+    /// found, node behind) are retried up to `retry_attempts` times with
+    /// exponential backoff; malformed-request errors fail immediately.
+    async fn rpc_call(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, Error> {
         let request_body = serde_json::json!({
             "jsonrpc": "2.0",
             "id": 1,
-            "method": "getBalance",
-            "params": [address]
+            "method": method,
+            "params": params
         });
 
+        let attempts = self.config.retry_attempts.max(1);
+        let mut backoff = std::time::Duration::from_millis(100);
+
+        for attempt in 1..=attempts {
+            match self.rpc_call_once(method, &request_body).await {
+                Ok(response) => return Ok(response),
+                Err(e) if e.is_retryable() && attempt < attempts => {
+                    tracing::warn!(
+                        "{} attempt {}/{} failed, retrying in {:?}: {}",
+                        method,
+                        attempt,
+                        attempts,
+                        backoff,
+                        e
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        unreachable!("rpc_call loop always returns")
+    }
+
+    async fn rpc_call_once(
+        &self,
+        method: &str,
+        request_body: &serde_json::Value,
+    ) -> Result<serde_json::Value, Error> {
         let response = self.client
             .post(&self.config.rpc_url)
-            .json(&request_body)
+            .json(request_body)
             .send()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to send request: {}", e)))?;
+            .map_err(|e| BlockchainError::from_transport(format!("{} request failed", method), &e))?;
 
         let response_json: serde_json::Value = response
             .json()
             .await
-            .map_err(|e| Error::blockchain(format!("Failed to parse response: {}", e)))?;
+            .map_err(|e| BlockchainError::MalformedResponse(format!("Failed to parse {} response: {}", method, e)))?;
+
+        if let Some(error) = response_json.get("error").filter(|e| !e.is_null()) {
+            let code = error["code"].as_i64().unwrap_or(0);
+            let message = error["message"].as_str().unwrap_or("unknown error");
+            let description = format!("{} RPC error {}: {}", method, code, message);
+            return Err(if Self::is_retryable_rpc_error(code, message) {
+                BlockchainError::Other(description).into()
+            } else {
+                BlockchainError::MalformedResponse(description).into()
+            });
+        }
+
+        Ok(response_json)
+    }
+
+    /// Whether a JSON-RPC error is worth retrying
+    fn is_retryable_rpc_error(code: i64, message: &str) -> bool {
+        // -32002 transaction simulation failures (stale blockhash),
+        // -32004 block not available, -32005 node behind
+        matches!(code, -32002 | -32004 | -32005)
+            || message.contains("Blockhash not found")
+            || message.contains("node is behind")
+    }
+
+    /// Get account balance
+    pub async fn get_balance(&self, address: &str) -> Result<f64, Error> {
+        let response_json = self
+            .rpc_call("getBalance", serde_json::json!([address]))
+            .await?;
 
         let balance = response_json["result"]["value"]
             .as_u64()
@@ -67,24 +139,9 @@ impl SolanaClient {
 
     /// Submit a transaction
     pub async fn submit_transaction(&self, transaction: &str) -> Result<String, Error> {
-        let request_body = serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "sendTransaction",
-            "params": [transaction]
-        });
-
-        let response = self.client
-            .post(&self.config.rpc_url)
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| Error::blockchain(format!("Failed to send transaction: {}", e)))?;
-
-        let response_json: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| Error::blockchain(format!("Failed to parse response: {}", e)))?;
+        let response_json = self
+            .rpc_call("sendTransaction", serde_json::json!([transaction]))
+            .await?;
 
         let signature = response_json["result"]
             .as_str()
@@ -95,24 +152,9 @@ impl SolanaClient {
 
     /// Get transaction status
     pub async fn get_transaction_status(&self, signature: &str) -> Result<bool, Error> {
-        let request_body = serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "getSignatureStatuses",
-            "params": [[signature]]
-        });
-
-        let response = self.client
-            .post(&self.config.rpc_url)
-            .json(&request_body)
-            .send()
-            .await
-            .map_err(|e| Error::blockchain(format!("Failed to get transaction status: {}", e)))?;
-
-        let response_json: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| Error::blockchain(format!("Failed to parse response: {}", e)))?;
+        let response_json = self
+            .rpc_call("getSignatureStatuses", serde_json::json!([[signature]]))
+            .await?;
 
         let status = response_json["result"]["value"][0]["confirmationStatus"]
             .as_str()
@@ -121,6 +163,30 @@ impl SolanaClient {
         Ok(status == "confirmed" || status == "finalized")
     }
 
+    /// Poll a transaction signature until it is confirmed or the timeout
+    /// elapses
+    ///
+    /// Returns `Ok(false)` if the transaction was never seen as confirmed
+    /// or finalized within the timeout.
+    pub async fn confirm_transaction(
+        &self,
+        signature: &str,
+        timeout: std::time::Duration,
+    ) -> Result<bool, Error> {
+        let deadline = std::time::Instant::now() + timeout;
+        let poll_interval = std::time::Duration::from_millis(500);
+
+        loop {
+            if self.get_transaction_status(signature).await? {
+                return Ok(true);
+            }
+            if std::time::Instant::now() + poll_interval > deadline {
+                return Ok(false);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
     /// Create a contribution transaction
     pub async fn create_contribution_transaction(&self, contribution: &Contribution) -> Result<String, Error> {
         // Serialize contribution data
@@ -128,7 +194,7 @@ impl SolanaClient {
             .map_err(|e| Error::blockchain(format!("Failed to serialize contribution: {}", e)))?;
 
         // Create transaction (simplified)
-        let transaction = base64::encode(&contribution_data);
+        let transaction = STANDARD.encode(&contribution_data);
         Ok(transaction)
     }
 }
@@ -145,6 +211,20 @@ impl Default for SolanaConfig {
     }
 }
 
+impl From<crate::core::config::SolanaConfig> for SolanaConfig {
+    fn from(config: crate::core::config::SolanaConfig) -> Self {
+        Self {
+            rpc_url: config.rpc_url,
+            commitment: config.commitment,
+            timeout_seconds: config.timeout_seconds,
+            retry_attempts: config.retry_attempts,
+            // Signing keys are never carried in the top-level config
+            ..Self::default()
+        }
+    }
+}
+
+#[async_trait]
 impl BlockchainClient for SolanaClient {
     fn name(&self) -> &str {
         "Solana"
@@ -172,7 +252,7 @@ impl BlockchainClient for SolanaClient {
         // For Solana, we would retrieve data from a program account
         // This is a simplified implementation
         if hash.starts_with("solana:") {
-            let actual_hash = &hash[7..];
+            let _actual_hash = &hash[7..];
             // In a real implementation, this would query the Solana program
             // to retrieve the stored data
             Err(Error::blockchain("Data retrieval not implemented"))
@@ -0,0 +1,89 @@
+//! In-memory contribution store with filtered queries
+
+use crate::blockchain::Contribution;
+use tokio::sync::RwLock;
+
+/// Filter for contribution queries
+#[derive(Debug, Clone, Default)]
+pub struct ContributionFilter {
+    /// Only return contributions from this validator
+    pub validator_id: Option<String>,
+    /// Only return contributions at or above this quality score
+    pub min_quality_score: Option<f64>,
+    /// Maximum number of results to return
+    pub limit: Option<usize>,
+    /// Number of matching results to skip
+    pub offset: usize,
+}
+
+/// Store backing the REST/GraphQL contribution listings
+///
+/// Results are stably ordered by timestamp descending (newest first), so
+/// offset/limit paging is consistent across calls.
+pub struct ContributionStore {
+    contributions: RwLock<Vec<Contribution>>,
+}
+
+impl ContributionStore {
+    /// Create a new empty store
+    pub fn new() -> Self {
+        Self {
+            contributions: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Add a contribution to the store
+    pub async fn add(&self, contribution: Contribution) {
+        self.contributions.write().await.push(contribution);
+    }
+
+    /// Get the number of stored contributions
+    pub async fn len(&self) -> usize {
+        self.contributions.read().await.len()
+    }
+
+    /// Check whether the store is empty
+    pub async fn is_empty(&self) -> bool {
+        self.contributions.read().await.is_empty()
+    }
+
+    /// Query contributions matching the filter
+    pub async fn query(&self, filter: ContributionFilter) -> Vec<Contribution> {
+        let contributions = self.contributions.read().await;
+
+        let mut matches: Vec<Contribution> = contributions
+            .iter()
+            .filter(|c| {
+                filter
+                    .validator_id
+                    .as_ref()
+                    .map_or(true, |id| &c.validator_id == id)
+            })
+            .filter(|c| {
+                filter
+                    .min_quality_score
+                    .map_or(true, |min| c.quality_score >= min)
+            })
+            .cloned()
+            .collect();
+
+        // Newest first; tie-break on sensor data hash for a stable order
+        matches.sort_by(|a, b| {
+            b.timestamp
+                .cmp(&a.timestamp)
+                .then_with(|| a.sensor_data_hash.cmp(&b.sensor_data_hash))
+        });
+
+        matches
+            .into_iter()
+            .skip(filter.offset)
+            .take(filter.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+impl Default for ContributionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,56 @@
+//! Pluggable time source for deterministic timestamp handling
+
+use chrono::{DateTime, Utc};
+
+/// Source of the current UTC time
+pub trait Clock: Send + Sync {
+    /// Current UTC time
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock backed by the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Manually driven clock for deterministic tests
+///
+/// Share it as an `Arc` so tests keep a handle for advancing time after
+/// handing the clock to a component.
+#[cfg(feature = "test-utils")]
+pub struct MockClock {
+    now: std::sync::Mutex<DateTime<Utc>>,
+}
+
+#[cfg(feature = "test-utils")]
+impl MockClock {
+    /// Create a clock frozen at the given instant
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            now: std::sync::Mutex::new(start),
+        }
+    }
+
+    /// Move the clock forward
+    pub fn advance(&self, duration: chrono::Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now += duration;
+    }
+
+    /// Jump the clock to an absolute instant
+    pub fn set(&self, instant: DateTime<Utc>) {
+        *self.now.lock().unwrap() = instant;
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
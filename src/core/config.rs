@@ -1,7 +1,9 @@
 //! Configuration management for Kova Core
 
+use crate::core::Error;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
+use std::str::FromStr;
 
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +34,9 @@ pub struct SensorConfig {
     pub cache_size_mb: usize,
     /// Enable sensor calibration
     pub enable_calibration: bool,
+    /// Canonical unit system for normalized readings
+    #[serde(default)]
+    pub unit_system: crate::sensors::units::UnitSystem,
 }
 
 /// Blockchain configuration
@@ -121,6 +126,126 @@ pub struct StorageConfig {
     pub max_file_size_mb: usize,
 }
 
+/// Partial configuration for layered overrides
+///
+/// Every field is optional; present fields win over the base configuration
+/// when merged via [`Config::merge`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartialConfig {
+    /// Sensor configuration overrides
+    pub sensors: Option<PartialSensorConfig>,
+    /// Blockchain configuration overrides
+    pub blockchain: Option<PartialBlockchainConfig>,
+    /// Network configuration overrides
+    pub network: Option<PartialNetworkConfig>,
+    /// Validation configuration overrides
+    pub validation: Option<PartialValidationConfig>,
+    /// Storage configuration overrides
+    pub storage: Option<PartialStorageConfig>,
+}
+
+/// Sensor configuration overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartialSensorConfig {
+    /// Default sensor timeout in seconds
+    pub timeout_seconds: Option<u64>,
+    /// Enable sensor data caching
+    pub enable_caching: Option<bool>,
+    /// Cache size limit in MB
+    pub cache_size_mb: Option<usize>,
+    /// Enable sensor calibration
+    pub enable_calibration: Option<bool>,
+    /// Canonical unit system for normalized readings
+    pub unit_system: Option<crate::sensors::units::UnitSystem>,
+}
+
+/// Blockchain configuration overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartialBlockchainConfig {
+    /// Solana configuration overrides
+    pub solana: Option<PartialSolanaConfig>,
+    /// IPFS configuration overrides
+    pub ipfs: Option<PartialIPFSConfig>,
+    /// Arweave configuration overrides
+    pub arweave: Option<PartialArweaveConfig>,
+}
+
+/// Solana configuration overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartialSolanaConfig {
+    /// RPC endpoint URL
+    pub rpc_url: Option<String>,
+    /// Commitment level
+    pub commitment: Option<String>,
+    /// Request timeout in seconds
+    pub timeout_seconds: Option<u64>,
+    /// Number of retry attempts
+    pub retry_attempts: Option<u32>,
+}
+
+/// IPFS configuration overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartialIPFSConfig {
+    /// API endpoint URL
+    pub api_url: Option<String>,
+    /// Gateway URL
+    pub gateway_url: Option<String>,
+    /// Pin content on add
+    pub pin_on_add: Option<bool>,
+    /// Request timeout in seconds
+    pub timeout_seconds: Option<u64>,
+}
+
+/// Arweave configuration overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartialArweaveConfig {
+    /// Gateway URL
+    pub gateway_url: Option<String>,
+    /// Request timeout in seconds
+    pub timeout_seconds: Option<u64>,
+    /// Number of retry attempts
+    pub retry_attempts: Option<u32>,
+}
+
+/// Network configuration overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartialNetworkConfig {
+    /// Maximum concurrent connections
+    pub max_connections: Option<usize>,
+    /// Connection timeout in seconds
+    pub connection_timeout_seconds: Option<u64>,
+    /// Enable connection pooling
+    pub enable_pooling: Option<bool>,
+    /// Pool size
+    pub pool_size: Option<usize>,
+}
+
+/// Validation configuration overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartialValidationConfig {
+    /// Minimum quality score threshold
+    pub min_quality_score: Option<f64>,
+    /// Enable anomaly detection
+    pub enable_anomaly_detection: Option<bool>,
+    /// Enable temporal consistency checks
+    pub enable_temporal_consistency: Option<bool>,
+    /// Maximum noise threshold
+    pub max_noise_threshold: Option<f64>,
+}
+
+/// Storage configuration overrides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PartialStorageConfig {
+    /// Local storage path
+    pub local_path: Option<String>,
+    /// Enable compression
+    pub enable_compression: Option<bool>,
+    /// Compression level (1-9)
+    pub compression_level: Option<u32>,
+    /// Maximum file size in MB
+    pub max_file_size_mb: Option<usize>,
+}
+
 /// ROS2 configuration
 #[cfg(feature = "ros2")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -154,6 +279,7 @@ impl Default for SensorConfig {
             enable_caching: true,
             cache_size_mb: 100,
             enable_calibration: true,
+            unit_system: crate::sensors::units::UnitSystem::default(),
         }
     }
 }
@@ -258,4 +384,245 @@ impl Config {
         std::fs::write(path, content)?;
         Ok(())
     }
+
+    /// Merge a partial configuration into this one, field by field
+    pub fn merge(&mut self, other: PartialConfig) {
+        if let Some(sensors) = other.sensors {
+            if let Some(v) = sensors.timeout_seconds {
+                self.sensors.timeout_seconds = v;
+            }
+            if let Some(v) = sensors.enable_caching {
+                self.sensors.enable_caching = v;
+            }
+            if let Some(v) = sensors.cache_size_mb {
+                self.sensors.cache_size_mb = v;
+            }
+            if let Some(v) = sensors.enable_calibration {
+                self.sensors.enable_calibration = v;
+            }
+            if let Some(v) = sensors.unit_system {
+                self.sensors.unit_system = v;
+            }
+        }
+        if let Some(blockchain) = other.blockchain {
+            if let Some(solana) = blockchain.solana {
+                if let Some(v) = solana.rpc_url {
+                    self.blockchain.solana.rpc_url = v;
+                }
+                if let Some(v) = solana.commitment {
+                    self.blockchain.solana.commitment = v;
+                }
+                if let Some(v) = solana.timeout_seconds {
+                    self.blockchain.solana.timeout_seconds = v;
+                }
+                if let Some(v) = solana.retry_attempts {
+                    self.blockchain.solana.retry_attempts = v;
+                }
+            }
+            if let Some(ipfs) = blockchain.ipfs {
+                if let Some(v) = ipfs.api_url {
+                    self.blockchain.ipfs.api_url = v;
+                }
+                if let Some(v) = ipfs.gateway_url {
+                    self.blockchain.ipfs.gateway_url = v;
+                }
+                if let Some(v) = ipfs.pin_on_add {
+                    self.blockchain.ipfs.pin_on_add = v;
+                }
+                if let Some(v) = ipfs.timeout_seconds {
+                    self.blockchain.ipfs.timeout_seconds = v;
+                }
+            }
+            if let Some(arweave) = blockchain.arweave {
+                if let Some(v) = arweave.gateway_url {
+                    self.blockchain.arweave.gateway_url = v;
+                }
+                if let Some(v) = arweave.timeout_seconds {
+                    self.blockchain.arweave.timeout_seconds = v;
+                }
+                if let Some(v) = arweave.retry_attempts {
+                    self.blockchain.arweave.retry_attempts = v;
+                }
+            }
+        }
+        if let Some(network) = other.network {
+            if let Some(v) = network.max_connections {
+                self.network.max_connections = v;
+            }
+            if let Some(v) = network.connection_timeout_seconds {
+                self.network.connection_timeout_seconds = v;
+            }
+            if let Some(v) = network.enable_pooling {
+                self.network.enable_pooling = v;
+            }
+            if let Some(v) = network.pool_size {
+                self.network.pool_size = v;
+            }
+        }
+        if let Some(validation) = other.validation {
+            if let Some(v) = validation.min_quality_score {
+                self.validation.min_quality_score = v;
+            }
+            if let Some(v) = validation.enable_anomaly_detection {
+                self.validation.enable_anomaly_detection = v;
+            }
+            if let Some(v) = validation.enable_temporal_consistency {
+                self.validation.enable_temporal_consistency = v;
+            }
+            if let Some(v) = validation.max_noise_threshold {
+                self.validation.max_noise_threshold = v;
+            }
+        }
+        if let Some(storage) = other.storage {
+            if let Some(v) = storage.local_path {
+                self.storage.local_path = v;
+            }
+            if let Some(v) = storage.enable_compression {
+                self.storage.enable_compression = v;
+            }
+            if let Some(v) = storage.compression_level {
+                self.storage.compression_level = v;
+            }
+            if let Some(v) = storage.max_file_size_mb {
+                self.storage.max_file_size_mb = v;
+            }
+        }
+    }
+
+    /// Load a base configuration then apply override files in order
+    ///
+    /// The first path is parsed as a complete configuration; the remaining
+    /// paths are parsed as [`PartialConfig`] and merged, later files winning
+    /// field by field.
+    pub fn from_layered<P: AsRef<Path>>(paths: &[P]) -> Result<Self, Error> {
+        let (base, overrides) = paths
+            .split_first()
+            .ok_or_else(|| Error::config("No configuration files provided"))?;
+
+        let content = std::fs::read_to_string(base)?;
+        let mut config: Config = toml::from_str(&content)
+            .map_err(|e| Error::config(format!("Failed to parse base config: {}", e)))?;
+
+        for path in overrides {
+            let content = std::fs::read_to_string(path)?;
+            let partial: PartialConfig = toml::from_str(&content)
+                .map_err(|e| Error::config(format!("Failed to parse override config: {}", e)))?;
+            config.merge(partial);
+        }
+
+        Ok(config)
+    }
+
+    /// Load configuration from file, then overlay `KOVA_*` environment variables
+    pub fn from_file_with_env<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let content = std::fs::read_to_string(path)?;
+        let mut config: Config = toml::from_str(&content)
+            .map_err(|e| Error::config(format!("Failed to parse config file: {}", e)))?;
+        config.apply_env_overrides()?;
+        Ok(config)
+    }
+
+    /// Overlay environment variable overrides onto this configuration
+    ///
+    /// Recognized variables follow the pattern `KOVA_<SECTION>_<FIELD>`, e.g.
+    /// `KOVA_SOLANA_RPC_URL` or `KOVA_VALIDATION_MIN_QUALITY_SCORE`.
+    pub fn apply_env_overrides(&mut self) -> Result<(), Error> {
+        if let Some(value) = env_override::<String>("KOVA_SOLANA_RPC_URL")? {
+            self.blockchain.solana.rpc_url = value;
+        }
+        if let Some(value) = env_override::<String>("KOVA_SOLANA_COMMITMENT")? {
+            self.blockchain.solana.commitment = value;
+        }
+        if let Some(value) = env_override::<u64>("KOVA_SOLANA_TIMEOUT_SECONDS")? {
+            self.blockchain.solana.timeout_seconds = value;
+        }
+        if let Some(value) = env_override::<u32>("KOVA_SOLANA_RETRY_ATTEMPTS")? {
+            self.blockchain.solana.retry_attempts = value;
+        }
+        if let Some(value) = env_override::<String>("KOVA_IPFS_API_URL")? {
+            self.blockchain.ipfs.api_url = value;
+        }
+        if let Some(value) = env_override::<String>("KOVA_IPFS_GATEWAY_URL")? {
+            self.blockchain.ipfs.gateway_url = value;
+        }
+        if let Some(value) = env_override::<bool>("KOVA_IPFS_PIN_ON_ADD")? {
+            self.blockchain.ipfs.pin_on_add = value;
+        }
+        if let Some(value) = env_override::<u64>("KOVA_IPFS_TIMEOUT_SECONDS")? {
+            self.blockchain.ipfs.timeout_seconds = value;
+        }
+        if let Some(value) = env_override::<String>("KOVA_ARWEAVE_GATEWAY_URL")? {
+            self.blockchain.arweave.gateway_url = value;
+        }
+        if let Some(value) = env_override::<u64>("KOVA_ARWEAVE_TIMEOUT_SECONDS")? {
+            self.blockchain.arweave.timeout_seconds = value;
+        }
+        if let Some(value) = env_override::<u32>("KOVA_ARWEAVE_RETRY_ATTEMPTS")? {
+            self.blockchain.arweave.retry_attempts = value;
+        }
+        if let Some(value) = env_override::<u64>("KOVA_SENSORS_TIMEOUT_SECONDS")? {
+            self.sensors.timeout_seconds = value;
+        }
+        if let Some(value) = env_override::<bool>("KOVA_SENSORS_ENABLE_CACHING")? {
+            self.sensors.enable_caching = value;
+        }
+        if let Some(value) = env_override::<usize>("KOVA_SENSORS_CACHE_SIZE_MB")? {
+            self.sensors.cache_size_mb = value;
+        }
+        if let Some(value) = env_override::<bool>("KOVA_SENSORS_ENABLE_CALIBRATION")? {
+            self.sensors.enable_calibration = value;
+        }
+        if let Some(value) = env_override::<usize>("KOVA_NETWORK_MAX_CONNECTIONS")? {
+            self.network.max_connections = value;
+        }
+        if let Some(value) = env_override::<u64>("KOVA_NETWORK_CONNECTION_TIMEOUT_SECONDS")? {
+            self.network.connection_timeout_seconds = value;
+        }
+        if let Some(value) = env_override::<bool>("KOVA_NETWORK_ENABLE_POOLING")? {
+            self.network.enable_pooling = value;
+        }
+        if let Some(value) = env_override::<usize>("KOVA_NETWORK_POOL_SIZE")? {
+            self.network.pool_size = value;
+        }
+        if let Some(value) = env_override::<f64>("KOVA_VALIDATION_MIN_QUALITY_SCORE")? {
+            self.validation.min_quality_score = value;
+        }
+        if let Some(value) = env_override::<bool>("KOVA_VALIDATION_ENABLE_ANOMALY_DETECTION")? {
+            self.validation.enable_anomaly_detection = value;
+        }
+        if let Some(value) = env_override::<bool>("KOVA_VALIDATION_ENABLE_TEMPORAL_CONSISTENCY")? {
+            self.validation.enable_temporal_consistency = value;
+        }
+        if let Some(value) = env_override::<f64>("KOVA_VALIDATION_MAX_NOISE_THRESHOLD")? {
+            self.validation.max_noise_threshold = value;
+        }
+        if let Some(value) = env_override::<String>("KOVA_STORAGE_LOCAL_PATH")? {
+            self.storage.local_path = value;
+        }
+        if let Some(value) = env_override::<bool>("KOVA_STORAGE_ENABLE_COMPRESSION")? {
+            self.storage.enable_compression = value;
+        }
+        if let Some(value) = env_override::<u32>("KOVA_STORAGE_COMPRESSION_LEVEL")? {
+            self.storage.compression_level = value;
+        }
+        if let Some(value) = env_override::<usize>("KOVA_STORAGE_MAX_FILE_SIZE_MB")? {
+            self.storage.max_file_size_mb = value;
+        }
+        Ok(())
+    }
+}
+
+/// Read and parse a single environment variable override
+fn env_override<T: FromStr>(key: &str) -> Result<Option<T>, Error>
+where
+    T::Err: std::fmt::Display,
+{
+    match std::env::var(key) {
+        Ok(value) => value
+            .parse::<T>()
+            .map(Some)
+            .map_err(|e| Error::config(format!("Invalid value for {}: {}", key, e))),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(e) => Err(Error::config(format!("Failed to read {}: {}", key, e))),
+    }
 }
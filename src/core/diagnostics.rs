@@ -0,0 +1,101 @@
+//! System health diagnostics
+//!
+//! One call that gathers what a field tech asks for first: which sensors
+//! are up, which blockchain backends are reachable, and what version is
+//! running — as a JSON-serializable report.
+
+use crate::blockchain::BlockchainManager;
+use crate::sensors::manager::SensorManager;
+use serde::{Deserialize, Serialize};
+
+/// Snapshot of overall system health
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticsReport {
+    /// Crate version the report was produced by
+    pub version: String,
+    /// When the report was generated
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    /// Per-sensor health, sorted by sensor id
+    pub sensors: Vec<SensorDiagnostics>,
+    /// Per-blockchain-client health, sorted by client name
+    pub blockchain: Vec<ClientDiagnostics>,
+}
+
+/// Health of a single sensor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensorDiagnostics {
+    /// Sensor id
+    pub id: String,
+    /// Whether the sensor reports itself available
+    pub available: bool,
+    /// Lifecycle state, rendered as text
+    pub state: String,
+    /// Capture failures since the last success
+    pub consecutive_failures: u32,
+    /// Whether no capture succeeded within the stale timeout
+    pub stale: bool,
+}
+
+/// Health of a single blockchain client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientDiagnostics {
+    /// Client name
+    pub name: String,
+    /// Whether the client reports itself reachable
+    pub available: bool,
+    /// Circuit breaker state, rendered as text
+    pub breaker: String,
+}
+
+/// Gather a health report over the registered sensors and clients
+pub async fn diagnostics(
+    sensor_manager: &SensorManager,
+    blockchain_manager: &BlockchainManager,
+) -> DiagnosticsReport {
+    let availability = sensor_manager.availability().await;
+    let states = sensor_manager.sensor_states().await;
+    let metrics = sensor_manager.metrics().await;
+
+    let mut sensors: Vec<SensorDiagnostics> = availability
+        .into_iter()
+        .map(|(id, available)| {
+            let state = states
+                .get(&id)
+                .map_or_else(|| "Unknown".to_string(), |s| format!("{:?}", s));
+            let sensor_metrics = metrics.get(&id);
+            SensorDiagnostics {
+                available,
+                state,
+                consecutive_failures: sensor_metrics.map_or(0, |m| m.consecutive_failures),
+                stale: sensor_metrics.is_some_and(|m| m.stale),
+                id,
+            }
+        })
+        .collect();
+    sensors.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let client_availability = blockchain_manager.availability().await;
+    let breakers = blockchain_manager.breaker_states().await;
+
+    let mut blockchain: Vec<ClientDiagnostics> = client_availability
+        .into_iter()
+        .map(|(name, available)| {
+            let breaker = breakers
+                .get(&name)
+                .map_or_else(|| "Unknown".to_string(), |s| format!("{:?}", s));
+            ClientDiagnostics {
+                available,
+                breaker,
+                name,
+            }
+        })
+        .collect();
+    blockchain.sort_by(|a, b| a.name.cmp(&b.name));
+
+    DiagnosticsReport {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        generated_at: chrono::Utc::now(),
+        sensors,
+        blockchain,
+    }
+}
@@ -14,7 +14,7 @@ pub enum Error {
 
     /// Blockchain-related errors
     #[error("Blockchain error: {0}")]
-    Blockchain(String),
+    Blockchain(#[from] BlockchainError),
 
     /// Validation errors
     #[error("Validation error: {0}")]
@@ -50,6 +50,69 @@ pub enum Error {
     Other(#[from] anyhow::Error),
 }
 
+/// Structured blockchain error
+///
+/// Lets callers programmatically distinguish transient transport failures
+/// from protocol-level problems instead of matching on message strings.
+#[derive(Error, Debug)]
+pub enum BlockchainError {
+    /// The node or gateway could not be reached
+    #[error("Node unreachable: {0}")]
+    Unreachable(String),
+
+    /// The request timed out
+    #[error("Request timed out: {0}")]
+    Timeout(String),
+
+    /// The node returned a response that could not be parsed
+    #[error("Malformed response: {0}")]
+    MalformedResponse(String),
+
+    /// The account lacks funds for the operation
+    #[error("Insufficient funds: {0}")]
+    InsufficientFunds(String),
+
+    /// The requested resource does not exist
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    /// Other blockchain errors
+    #[error("{0}")]
+    Other(String),
+}
+
+impl BlockchainError {
+    /// Classify a reqwest transport error into the matching variant
+    pub fn from_transport(context: impl Into<String>, error: &reqwest::Error) -> Self {
+        let message = format!("{}: {}", context.into(), error);
+        if error.is_timeout() {
+            Self::Timeout(message)
+        } else if error.is_connect() {
+            Self::Unreachable(message)
+        } else if error.is_decode() {
+            Self::MalformedResponse(message)
+        } else {
+            Self::Other(message)
+        }
+    }
+
+    /// Classify an HTTP status code into the matching variant
+    pub fn from_status(context: impl Into<String>, status: reqwest::StatusCode) -> Self {
+        let message = format!("{}: HTTP {}", context.into(), status);
+        if status == reqwest::StatusCode::NOT_FOUND {
+            Self::NotFound(message)
+        } else if status == reqwest::StatusCode::REQUEST_TIMEOUT
+            || status == reqwest::StatusCode::GATEWAY_TIMEOUT
+        {
+            Self::Timeout(message)
+        } else if status == reqwest::StatusCode::PAYMENT_REQUIRED {
+            Self::InsufficientFunds(message)
+        } else {
+            Self::Other(message)
+        }
+    }
+}
+
 impl Error {
     /// Create a new sensor error
     pub fn sensor(msg: impl Into<String>) -> Self {
@@ -58,7 +121,7 @@ impl Error {
 
     /// Create a new blockchain error
     pub fn blockchain(msg: impl Into<String>) -> Self {
-        Self::Blockchain(msg.into())
+        Self::Blockchain(BlockchainError::Other(msg.into()))
     }
 
     /// Create a new validation error
@@ -86,4 +149,30 @@ impl Error {
     pub fn ros2(msg: impl Into<String>) -> Self {
         Self::ROS2(msg.into())
     }
+
+    /// Check whether retrying the failed operation might succeed
+    ///
+    /// Transient transport failures (unreachable nodes, timeouts, network
+    /// errors) are retryable; validation, configuration, and malformed-data
+    /// errors are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Blockchain(e) => matches!(
+                e,
+                BlockchainError::Unreachable(_)
+                    | BlockchainError::Timeout(_)
+                    | BlockchainError::Other(_)
+            ),
+            Self::Network(_) => true,
+            Self::Io(e) => matches!(
+                e.kind(),
+                std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::ConnectionRefused
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::Interrupted
+            ),
+            _ => false,
+        }
+    }
 }
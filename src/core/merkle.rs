@@ -0,0 +1,102 @@
+//! Merkle tree for batched sensor data proofs
+//!
+//! Commits a batch of frames to a single SHA-256 root so any frame's
+//! inclusion can later be proven on-chain.
+
+use sha2::{Digest, Sha256};
+
+fn hash_leaf(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// SHA-256 Merkle tree over a batch of byte leaves
+///
+/// Odd levels are handled by pairing the unpaired node with itself, so
+/// every proof carries exactly one sibling per level.
+pub struct MerkleTree {
+    /// Node hashes per level, leaves first
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Build a tree over the given leaves
+    ///
+    /// Returns `None` for an empty batch.
+    pub fn from_leaves(leaves: &[Vec<u8>]) -> Option<Self> {
+        if leaves.is_empty() {
+            return None;
+        }
+
+        let mut levels = vec![leaves.iter().map(|leaf| hash_leaf(leaf)).collect::<Vec<_>>()];
+
+        while levels.last().unwrap().len() > 1 {
+            let previous = levels.last().unwrap();
+            let next = previous
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [unpaired] => hash_pair(unpaired, unpaired),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Some(Self { levels })
+    }
+
+    /// Root hash committing to the whole batch
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Number of leaves in the tree
+    pub fn leaf_count(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Inclusion proof for the leaf at `index`
+    ///
+    /// The proof lists one sibling hash per level, from the leaf level
+    /// upward; an unpaired node's sibling is the node itself.
+    pub fn proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.leaf_count() {
+            return None;
+        }
+
+        let mut proof = Vec::new();
+        let mut position = index;
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            proof.push(level[sibling.min(level.len() - 1)]);
+            position /= 2;
+        }
+
+        Some(proof)
+    }
+}
+
+/// Verify an inclusion proof produced by [`MerkleTree::proof`]
+pub fn verify(root: [u8; 32], leaf: &[u8], proof: &[[u8; 32]], index: usize) -> bool {
+    let mut hash = hash_leaf(leaf);
+    let mut position = index;
+
+    for sibling in proof {
+        hash = if position % 2 == 0 {
+            hash_pair(&hash, sibling)
+        } else {
+            hash_pair(sibling, &hash)
+        };
+        position /= 2;
+    }
+
+    hash == root
+}
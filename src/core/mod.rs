@@ -1,12 +1,21 @@
 //! Core functionality for the Kova system
 
+pub mod clock;
 pub mod config;
+pub mod diagnostics;
 pub mod error;
+pub mod merkle;
 pub mod network;
+pub mod pipeline;
 pub mod protocol;
 pub mod rewards;
 pub mod storage;
 pub mod validation;
 
+#[cfg(feature = "test-utils")]
+pub use clock::MockClock;
+pub use clock::{Clock, SystemClock};
 pub use config::Config;
-pub use error::{Error, Result};
+pub use diagnostics::{ClientDiagnostics, DiagnosticsReport, SensorDiagnostics};
+pub use error::{BlockchainError, Error, Result};
+pub use pipeline::{FailAction, FrameOutcome, FrameResult, Pipeline, ValidationPolicy};
@@ -0,0 +1,207 @@
+//! Capture → validate → store pipeline
+//!
+//! Collapses the loop every example repeats by hand: capture from all
+//! sensors, validate each frame, store the valid ones and submit a
+//! contribution, reporting what happened to every frame.
+
+use crate::blockchain::{BlockchainManager, Contribution};
+use crate::core::validation::DataValidator;
+use crate::core::Error;
+use crate::sensors::manager::SensorManager;
+use crate::sensors::redaction::{RedactionPolicy, RegionProvider};
+use crate::sensors::{SensorData, SensorType};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// What to do with a frame that fails validation
+#[derive(Debug, Clone, Default)]
+pub enum FailAction {
+    /// Discard the frame and report it as rejected
+    #[default]
+    Drop,
+    /// Store the frame and submit a contribution despite the failure
+    StoreAnyway,
+    /// Write the rejected frame as JSON into a directory for inspection
+    Quarantine(PathBuf),
+}
+
+/// Policy applied by the pipeline when validation fails
+#[derive(Debug, Clone, Default)]
+pub struct ValidationPolicy {
+    /// Action taken on frames that fail validation
+    pub on_fail: FailAction,
+}
+
+/// What happened to a single frame in the pipeline
+#[derive(Debug, Clone)]
+pub enum FrameOutcome {
+    /// The frame passed validation, was stored, and a contribution was submitted
+    Stored {
+        /// Hash of the stored sensor data
+        data_hash: String,
+        /// Transaction hash of the submitted contribution
+        contribution_tx: String,
+        /// Validation quality score
+        quality_score: f64,
+    },
+    /// The frame failed validation and was not stored
+    Rejected {
+        /// Why the frame was rejected
+        reason: String,
+        /// Validation quality score
+        quality_score: f64,
+    },
+    /// Validation or storage itself failed
+    Failed {
+        /// The underlying error, rendered as a message
+        error: String,
+    },
+}
+
+/// Per-frame pipeline result
+#[derive(Debug, Clone)]
+pub struct FrameResult {
+    /// Frame id correlating with capture/validation/storage log records
+    pub frame_id: uuid::Uuid,
+    /// The sensor the frame came from
+    pub sensor_id: String,
+    /// What happened to the frame
+    pub outcome: FrameOutcome,
+}
+
+/// Capture-validate-store pipeline
+pub struct Pipeline {
+    validator: Arc<DataValidator>,
+    blockchain: Arc<BlockchainManager>,
+    validator_id: String,
+    policy: ValidationPolicy,
+    redaction: Option<(RedactionPolicy, Box<dyn RegionProvider>)>,
+}
+
+impl Pipeline {
+    /// Create a new pipeline with the default (drop-on-fail) policy
+    pub fn new(
+        validator: Arc<DataValidator>,
+        blockchain: Arc<BlockchainManager>,
+        validator_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            validator,
+            blockchain,
+            validator_id: validator_id.into(),
+            policy: ValidationPolicy::default(),
+            redaction: None,
+        }
+    }
+
+    /// Set the policy applied to frames that fail validation
+    pub fn set_policy(&mut self, policy: ValidationPolicy) {
+        self.policy = policy;
+    }
+
+    /// Redact camera frames before storage
+    ///
+    /// The provider supplies the regions (e.g. from a face or plate
+    /// detector) and the policy blurs them; non-camera frames pass
+    /// through untouched.
+    pub fn set_redaction(&mut self, policy: RedactionPolicy, provider: Box<dyn RegionProvider>) {
+        self.redaction = Some((policy, provider));
+    }
+
+    /// Run one capture pass over all sensors and process every frame
+    pub async fn run(&self, manager: &SensorManager) -> Result<Vec<FrameResult>, Error> {
+        let frames = manager.capture_all().await?;
+        let mut results = Vec::with_capacity(frames.len());
+        for frame in &frames {
+            results.push(self.process_frame(frame).await);
+        }
+        Ok(results)
+    }
+
+    /// Validate one frame and, if it passes, store it and submit a contribution
+    pub async fn process_frame(&self, frame: &SensorData) -> FrameResult {
+        let outcome = match self.process_inner(frame).await {
+            Ok(outcome) => outcome,
+            Err(e) => FrameOutcome::Failed {
+                error: e.to_string(),
+            },
+        };
+        FrameResult {
+            frame_id: frame.frame_id,
+            sensor_id: frame.sensor_id.clone(),
+            outcome,
+        }
+    }
+
+    async fn process_inner(&self, frame: &SensorData) -> Result<FrameOutcome, Error> {
+        let validation = self.validator.validate_frame(frame).await?;
+
+        if !validation.is_valid {
+            match &self.policy.on_fail {
+                FailAction::Drop => {
+                    return Ok(FrameOutcome::Rejected {
+                        reason: format!(
+                            "Validation failed with quality score {:.2}",
+                            validation.quality_score
+                        ),
+                        quality_score: validation.quality_score,
+                    });
+                }
+                FailAction::StoreAnyway => {
+                    tracing::warn!(
+                        frame_id = %frame.frame_id,
+                        "Storing frame despite failed validation per policy"
+                    );
+                }
+                FailAction::Quarantine(dir) => {
+                    let path = self.quarantine(frame, dir)?;
+                    return Ok(FrameOutcome::Rejected {
+                        reason: format!("Validation failed; frame quarantined to {}", path.display()),
+                        quality_score: validation.quality_score,
+                    });
+                }
+            }
+        }
+
+        // Redact before anything leaves the process
+        let redacted;
+        let stored_frame = match &self.redaction {
+            Some((policy, provider)) if frame.sensor_type == SensorType::Camera => {
+                let boxes = provider.regions(frame);
+                if boxes.is_empty() {
+                    frame
+                } else {
+                    let mut copy = frame.clone();
+                    policy.apply(&mut copy, &boxes)?;
+                    redacted = copy;
+                    &redacted
+                }
+            }
+            _ => frame,
+        };
+
+        let data_hash = self.blockchain.store_frame(stored_frame).await?;
+        let contribution = Contribution::from_validation(
+            data_hash.clone(),
+            frame.sensor_id.clone(),
+            self.validator_id.clone(),
+            &validation,
+        );
+        let contribution_tx = self.blockchain.submit_contribution(&contribution).await?;
+
+        Ok(FrameOutcome::Stored {
+            data_hash,
+            contribution_tx,
+            quality_score: validation.quality_score,
+        })
+    }
+
+    /// Write a rejected frame as JSON into the quarantine directory
+    fn quarantine(&self, frame: &SensorData, dir: &Path) -> Result<PathBuf, Error> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{}.json", frame.frame_id));
+        let json = serde_json::to_vec_pretty(frame)?;
+        std::fs::write(&path, json)?;
+        Ok(path)
+    }
+}
@@ -1,6 +1,7 @@
 //! Protocol management for Kova Core
 
 use crate::core::Error;
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 /// Protocol manager for handling communication protocols
@@ -9,6 +10,7 @@ pub struct ProtocolManager {
 }
 
 /// Protocol trait
+#[async_trait]
 pub trait Protocol: Send + Sync {
     /// Get protocol name
     fn name(&self) -> &str;
@@ -1,6 +1,5 @@
 //! Reward management for Kova Core
 
-use crate::core::Error;
 use serde::{Deserialize, Serialize};
 
 /// Reward manager for handling rewards and incentives
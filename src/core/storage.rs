@@ -40,4 +40,59 @@ impl StorageManager {
         std::fs::remove_file(&path)?;
         Ok(())
     }
+
+    /// Delete stored files older than the given age
+    ///
+    /// Walks the storage directory recursively and removes every file whose
+    /// modification time is older than `age`, returning how many were
+    /// deleted. A missing storage directory counts as nothing to prune.
+    pub async fn prune_older_than(&self, age: std::time::Duration) -> Result<usize, Error> {
+        let base = Path::new(&self.base_path);
+        if !base.exists() {
+            return Ok(0);
+        }
+        let cutoff = std::time::SystemTime::now() - age;
+        let mut removed = 0;
+        prune_dir(base, cutoff, &mut removed)?;
+        if removed > 0 {
+            tracing::info!("Pruned {} stale files from {}", removed, self.base_path);
+        }
+        Ok(removed)
+    }
+
+    /// Spawn a background task pruning stale files on an interval
+    ///
+    /// The task runs until the returned handle is aborted or the runtime
+    /// shuts down; prune failures are logged and do not stop the task.
+    pub fn spawn_prune_task(
+        self: &std::sync::Arc<Self>,
+        age: std::time::Duration,
+        interval: std::time::Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let manager = std::sync::Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = manager.prune_older_than(age).await {
+                    tracing::warn!("Storage prune failed: {}", e);
+                }
+            }
+        })
+    }
+}
+
+/// Recursively delete files under `dir` modified before `cutoff`
+fn prune_dir(dir: &Path, cutoff: std::time::SystemTime, removed: &mut usize) -> Result<(), Error> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            prune_dir(&path, cutoff, removed)?;
+        } else if entry.metadata()?.modified()? < cutoff {
+            std::fs::remove_file(&path)?;
+            *removed += 1;
+        }
+    }
+    Ok(())
 }
@@ -1,12 +1,87 @@
 //! Data validation and quality assessment
 
+use crate::core::clock::{Clock, SystemClock};
 use crate::core::Error;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Accepted distance between a frame timestamp and the validator clock
+const MAX_FRAME_AGE_SECONDS: i64 = 300;
+
+/// Identical repeats after which a sensor counts as frozen
+const DEFAULT_FROZEN_FRAME_THRESHOLD: u32 = 3;
+
+/// Factor applied to the quality score of a frozen frame
+const FROZEN_SCORE_PENALTY: f64 = 0.5;
+
+/// zstd level used for the compression quality metric
+const COMPRESSION_METRIC_LEVEL: i32 = 3;
 
 /// Data validator for sensor data
 pub struct DataValidator {
     config: ValidationConfig,
+    anomaly_detector: Box<dyn AnomalyDetector>,
+    clock: Arc<dyn Clock>,
+    smoothing_factor: Option<f64>,
+    smoothed_scores: tokio::sync::RwLock<HashMap<String, f64>>,
+    frozen_threshold: u32,
+    frame_hashes: tokio::sync::RwLock<HashMap<String, (u64, u32)>>,
+}
+
+/// Pluggable anomaly scoring over raw sensor bytes
+///
+/// Scores are in `0.0..=1.0`, where higher means more anomalous.
+pub trait AnomalyDetector: Send + Sync {
+    /// Score the given data for anomalies
+    fn score(&self, data: &[u8]) -> f64;
+}
+
+/// Default detector counting samples beyond two standard deviations
+pub struct ZScoreAnomalyDetector;
+
+impl AnomalyDetector for ZScoreAnomalyDetector {
+    fn score(&self, data: &[u8]) -> f64 {
+        if data.len() < 10 {
+            return 0.0;
+        }
+
+        let mean = data.iter().map(|&x| x as f64).sum::<f64>() / data.len() as f64;
+        let std_dev = (data.iter()
+            .map(|&x| (x as f64 - mean).powi(2))
+            .sum::<f64>() / data.len() as f64).sqrt();
+
+        let outliers = data.iter()
+            .filter(|&&x| (x as f64 - mean).abs() > 2.0 * std_dev)
+            .count();
+
+        outliers as f64 / data.len() as f64
+    }
+}
+
+/// Detector counting samples outside the 1.5×IQR whiskers
+pub struct IqrAnomalyDetector;
+
+impl AnomalyDetector for IqrAnomalyDetector {
+    fn score(&self, data: &[u8]) -> f64 {
+        if data.len() < 10 {
+            return 0.0;
+        }
+
+        let mut sorted: Vec<u8> = data.to_vec();
+        sorted.sort_unstable();
+        let q1 = sorted[sorted.len() / 4] as f64;
+        let q3 = sorted[(sorted.len() * 3) / 4] as f64;
+        let iqr = q3 - q1;
+
+        let lower = q1 - 1.5 * iqr;
+        let upper = q3 + 1.5 * iqr;
+        let outliers = data.iter()
+            .filter(|&&x| (x as f64) < lower || (x as f64) > upper)
+            .count();
+
+        outliers as f64 / data.len() as f64
+    }
 }
 
 /// Validation configuration
@@ -18,8 +93,16 @@ pub struct ValidationConfig {
     pub enable_anomaly_detection: bool,
     /// Enable temporal consistency checks
     pub enable_temporal_consistency: bool,
+    /// Enable per-sensor metadata schema checks
+    pub enable_metadata_validation: bool,
+    /// Fold data compressibility into the quality score
+    #[serde(default)]
+    pub enable_compression_metric: bool,
     /// Maximum noise threshold
     pub max_noise_threshold: f64,
+    /// Per-sensor-type overrides of the minimum quality score
+    #[serde(default)]
+    pub per_type_thresholds: HashMap<crate::sensors::SensorType, f64>,
 }
 
 /// Validation result
@@ -35,6 +118,20 @@ pub struct ValidationResult {
     pub signature: String,
     /// Is valid
     pub is_valid: bool,
+    /// Frame content repeated beyond the frozen-frame threshold
+    #[serde(default)]
+    pub frozen: bool,
+}
+
+/// Validation result extended with a per-sensor smoothed quality score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmoothedValidationResult {
+    /// The underlying validation result
+    pub result: ValidationResult,
+    /// Quality score of this frame alone
+    pub instantaneous_score: f64,
+    /// Exponential moving average of this sensor's quality scores
+    pub smoothed_score: f64,
 }
 
 /// Quality metrics
@@ -50,6 +147,9 @@ pub struct QualityMetrics {
     pub accuracy: f64,
     /// Anomaly score
     pub anomaly_score: f64,
+    /// Fraction of size removed by compression (0.0 incompressible)
+    #[serde(default)]
+    pub compression_ratio: f64,
 }
 
 impl Default for ValidationConfig {
@@ -58,7 +158,22 @@ impl Default for ValidationConfig {
             min_quality_score: 0.7,
             enable_anomaly_detection: true,
             enable_temporal_consistency: true,
+            enable_metadata_validation: false,
+            enable_compression_metric: false,
             max_noise_threshold: 0.1,
+            per_type_thresholds: HashMap::new(),
+        }
+    }
+}
+
+impl From<crate::core::config::ValidationConfig> for ValidationConfig {
+    fn from(config: crate::core::config::ValidationConfig) -> Self {
+        Self {
+            min_quality_score: config.min_quality_score,
+            enable_anomaly_detection: config.enable_anomaly_detection,
+            enable_temporal_consistency: config.enable_temporal_consistency,
+            max_noise_threshold: config.max_noise_threshold,
+            ..Self::default()
         }
     }
 }
@@ -66,19 +181,175 @@ impl Default for ValidationConfig {
 impl DataValidator {
     /// Create a new data validator
     pub fn new() -> Self {
-        Self {
-            config: ValidationConfig::default(),
-        }
+        Self::with_config(ValidationConfig::default())
     }
 
     /// Create a new data validator with configuration
     pub fn with_config(config: ValidationConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            anomaly_detector: Box::new(ZScoreAnomalyDetector),
+            clock: Arc::new(SystemClock),
+            smoothing_factor: None,
+            smoothed_scores: tokio::sync::RwLock::new(HashMap::new()),
+            frozen_threshold: DEFAULT_FROZEN_FRAME_THRESHOLD,
+            frame_hashes: tokio::sync::RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Set how many identical repeats mark a sensor as frozen
+    pub fn set_frozen_frame_threshold(&mut self, repeats: u32) {
+        self.frozen_threshold = repeats;
+    }
+
+    /// Enable exponential moving average smoothing of quality scores
+    ///
+    /// `factor` weighs the newest score: a smoothed score is
+    /// `factor * new + (1 - factor) * previous`, kept per sensor id. Lower
+    /// factors smooth harder. Must be in `(0.0, 1.0]`.
+    pub fn enable_score_smoothing(&mut self, factor: f64) -> Result<(), Error> {
+        if !(factor > 0.0 && factor <= 1.0) {
+            return Err(Error::validation(format!(
+                "Smoothing factor must be in (0.0, 1.0], got {}",
+                factor
+            )));
+        }
+        self.smoothing_factor = Some(factor);
+        Ok(())
+    }
+
+    /// Replace the time source used for validation timestamps
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Replace the anomaly detector used for quality metrics
+    pub fn set_anomaly_detector(&mut self, detector: Box<dyn AnomalyDetector>) {
+        self.anomaly_detector = detector;
+    }
+
+    /// Validate a captured frame, correlating log records by its frame id
+    pub async fn validate_frame(
+        &self,
+        frame: &crate::sensors::SensorData,
+    ) -> Result<ValidationResult, Error> {
+        use tracing::Instrument;
+
+        let span = tracing::info_span!("validate", frame_id = %frame.frame_id);
+        async {
+            tracing::info!(frame_id = %frame.frame_id, "Validating frame");
+            let mut result = self
+                .validate_typed(&frame.sensor_type, &frame.data, &frame.metadata)
+                .await?;
+
+            // A frame captured far from the current time fails temporal
+            // consistency even if its content scores well
+            if self.config.enable_temporal_consistency {
+                let age = self.clock.now() - frame.timestamp;
+                if age.num_seconds().abs() > MAX_FRAME_AGE_SECONDS {
+                    tracing::warn!(
+                        frame_id = %frame.frame_id,
+                        age_seconds = age.num_seconds(),
+                        "Frame timestamp outside temporal consistency window"
+                    );
+                    result.is_valid = false;
+                }
+            }
+
+            // A sensor replaying the identical frame is likely stuck, no
+            // matter how well the content itself scores
+            if self.is_frozen(&frame.sensor_id, &frame.data).await {
+                tracing::warn!(
+                    frame_id = %frame.frame_id,
+                    sensor_id = %frame.sensor_id,
+                    "Sensor repeating identical frames beyond the frozen threshold"
+                );
+                result.frozen = true;
+                result.quality_score *= FROZEN_SCORE_PENALTY;
+                result.is_valid =
+                    result.is_valid && result.quality_score >= self.config.min_quality_score;
+            }
+
+            if self.config.enable_metadata_validation {
+                if let Err(e) =
+                    crate::sensors::metadata::validate(&frame.sensor_type, &frame.metadata)
+                {
+                    tracing::warn!(
+                        frame_id = %frame.frame_id,
+                        "Metadata schema check failed: {}",
+                        e
+                    );
+                    result.is_valid = false;
+                }
+            }
+
+            Ok(result)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Track frame content repeats and report whether a sensor is frozen
+    async fn is_frozen(&self, sensor_id: &str, data: &[u8]) -> bool {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        data.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let mut hashes = self.frame_hashes.write().await;
+        let repeats = match hashes.get_mut(sensor_id) {
+            Some(entry) if entry.0 == hash => {
+                entry.1 = entry.1.saturating_add(1);
+                entry.1
+            }
+            Some(entry) => {
+                *entry = (hash, 0);
+                0
+            }
+            None => {
+                hashes.insert(sensor_id.to_string(), (hash, 0));
+                0
+            }
+        };
+        repeats >= self.frozen_threshold
+    }
+
+    /// Validate a frame and report its smoothed quality score
+    ///
+    /// Runs [`validate_frame`](Self::validate_frame) and folds the score
+    /// into the per-sensor EMA. With smoothing disabled the smoothed score
+    /// equals the instantaneous one.
+    pub async fn validate_frame_smoothed(
+        &self,
+        frame: &crate::sensors::SensorData,
+    ) -> Result<SmoothedValidationResult, Error> {
+        let result = self.validate_frame(frame).await?;
+        let instantaneous_score = result.quality_score;
+
+        let smoothed_score = match self.smoothing_factor {
+            Some(factor) => {
+                let mut scores = self.smoothed_scores.write().await;
+                let smoothed = match scores.get(&frame.sensor_id) {
+                    Some(previous) => factor * instantaneous_score + (1.0 - factor) * previous,
+                    None => instantaneous_score,
+                };
+                scores.insert(frame.sensor_id.clone(), smoothed);
+                smoothed
+            }
+            None => instantaneous_score,
+        };
+
+        Ok(SmoothedValidationResult {
+            result,
+            instantaneous_score,
+            smoothed_score,
+        })
     }
 
     /// Validate sensor data
     pub async fn validate(&self, data: &[u8], metadata: &HashMap<String, String>) -> Result<ValidationResult, Error> {
-        let timestamp = chrono::Utc::now();
+        let timestamp = self.clock.now();
         
         // Calculate quality metrics
         let metrics = self.calculate_quality_metrics(data, metadata).await?;
@@ -98,16 +369,35 @@ impl DataValidator {
             metrics,
             signature,
             is_valid,
+            frozen: false,
         })
     }
 
+    /// Validate sensor data against the threshold for its sensor type
+    ///
+    /// Uses the per-type entry from
+    /// [`ValidationConfig::per_type_thresholds`] when one exists,
+    /// falling back to the global minimum quality score.
+    pub async fn validate_typed(
+        &self,
+        sensor_type: &crate::sensors::SensorType,
+        data: &[u8],
+        metadata: &HashMap<String, String>,
+    ) -> Result<ValidationResult, Error> {
+        let mut result = self.validate(data, metadata).await?;
+        if let Some(threshold) = self.config.per_type_thresholds.get(sensor_type) {
+            result.is_valid = result.quality_score >= *threshold;
+        }
+        Ok(result)
+    }
+
     /// Validate with custom configuration
     pub async fn validate_with_config(
         &self,
         data: &[u8],
         config: &ValidationConfig,
     ) -> Result<ValidationResult, Error> {
-        let timestamp = chrono::Utc::now();
+        let timestamp = self.clock.now();
         
         // Calculate quality metrics
         let metrics = self.calculate_quality_metrics(data, &HashMap::new()).await?;
@@ -127,6 +417,7 @@ impl DataValidator {
             metrics,
             signature,
             is_valid,
+            frozen: false,
         })
     }
 
@@ -147,15 +438,41 @@ impl DataValidator {
             0.0
         };
 
+        let compression_ratio = if self.config.enable_compression_metric {
+            self.calculate_compression_ratio(data)
+        } else {
+            0.0
+        };
+
         Ok(QualityMetrics {
             noise_level,
             completeness,
             consistency,
             accuracy,
             anomaly_score,
+            compression_ratio,
         })
     }
 
+    /// Calculate how much of the data's size compression removes
+    ///
+    /// Random, noise-like data compresses poorly and scores near 0.0;
+    /// structured data scores towards 1.0.
+    fn calculate_compression_ratio(&self, data: &[u8]) -> f64 {
+        if data.is_empty() {
+            return 0.0;
+        }
+        match zstd::encode_all(data, COMPRESSION_METRIC_LEVEL) {
+            Ok(compressed) => {
+                (1.0 - compressed.len() as f64 / data.len() as f64).clamp(0.0, 1.0)
+            }
+            Err(e) => {
+                tracing::warn!("Compression metric failed: {}", e);
+                0.0
+            }
+        }
+    }
+
     /// Calculate noise level
     fn calculate_noise_level(&self, data: &[u8]) -> f64 {
         // Simplified noise calculation
@@ -213,41 +530,26 @@ impl DataValidator {
         valid_count as f64 / data.len() as f64
     }
 
-    /// Calculate anomaly score
+    /// Calculate anomaly score via the configured detector
     async fn calculate_anomaly_score(&self, data: &[u8]) -> Result<f64, Error> {
-        // Simplified anomaly detection
-        if data.len() < 10 {
-            return Ok(0.0);
-        }
-        
-        // Calculate statistical measures
-        let mean = data.iter().map(|&x| x as f64).sum::<f64>() / data.len() as f64;
-        let std_dev = (data.iter()
-            .map(|&x| (x as f64 - mean).powi(2))
-            .sum::<f64>() / data.len() as f64).sqrt();
-        
-        // Count outliers (simplified)
-        let outliers = data.iter()
-            .filter(|&&x| (x as f64 - mean).abs() > 2.0 * std_dev)
-            .count();
-        
-        Ok(outliers as f64 / data.len() as f64)
+        Ok(self.anomaly_detector.score(data))
     }
 
     /// Calculate overall quality score
     fn calculate_quality_score(&self, metrics: &QualityMetrics) -> f64 {
-        let weights = [0.2, 0.2, 0.2, 0.2, 0.2]; // Equal weights for now
-        let scores = [
+        let mut scores = vec![
             1.0 - metrics.noise_level,
             metrics.completeness,
             metrics.consistency,
             metrics.accuracy,
             1.0 - metrics.anomaly_score,
         ];
-        
-        scores.iter().zip(weights.iter())
-            .map(|(score, weight)| score * weight)
-            .sum()
+        if self.config.enable_compression_metric {
+            scores.push(metrics.compression_ratio);
+        }
+
+        // Equal weights for now
+        scores.iter().sum::<f64>() / scores.len() as f64
     }
 
     /// Generate validation signature
@@ -262,3 +564,84 @@ impl DataValidator {
         hex::encode(hash)
     }
 }
+
+/// Digest signed by a [`SigningValidator`]: SHA-256 over data and timestamp
+fn signing_digest(data: &[u8], timestamp: &chrono::DateTime<chrono::Utc>) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher.update(timestamp.to_rfc3339().as_bytes());
+    hasher.finalize().into()
+}
+
+/// Validator that signs results with an Ed25519 keypair
+///
+/// Unlike [`DataValidator`]'s plain hash, the produced signature can be
+/// verified against the validator's public key with [`verify_signature`].
+pub struct SigningValidator {
+    validator: DataValidator,
+    signing_key: ed25519_dalek::SigningKey,
+}
+
+impl SigningValidator {
+    /// Create a signing validator with a freshly generated keypair
+    pub fn new(config: ValidationConfig) -> Self {
+        let signing_key = ed25519_dalek::SigningKey::generate(&mut rand::rngs::OsRng);
+        Self {
+            validator: DataValidator::with_config(config),
+            signing_key,
+        }
+    }
+
+    /// Create a signing validator from existing secret key bytes
+    pub fn from_secret_key(config: ValidationConfig, secret_key: &[u8; 32]) -> Self {
+        Self {
+            validator: DataValidator::with_config(config),
+            signing_key: ed25519_dalek::SigningKey::from_bytes(secret_key),
+        }
+    }
+
+    /// Public key other parties can verify signatures against
+    pub fn public_key(&self) -> [u8; 32] {
+        self.signing_key.verifying_key().to_bytes()
+    }
+
+    /// Validate sensor data, signing the result with the validator key
+    pub async fn validate(
+        &self,
+        data: &[u8],
+        metadata: &HashMap<String, String>,
+    ) -> Result<ValidationResult, Error> {
+        use ed25519_dalek::Signer;
+
+        let mut result = self.validator.validate(data, metadata).await?;
+        let digest = signing_digest(data, &result.timestamp);
+        result.signature = hex::encode(self.signing_key.sign(&digest).to_bytes());
+        Ok(result)
+    }
+}
+
+/// Verify an Ed25519 validation signature against a validator public key
+pub fn verify_signature(
+    public_key: &[u8; 32],
+    data: &[u8],
+    timestamp: &chrono::DateTime<chrono::Utc>,
+    signature: &str,
+) -> bool {
+    use ed25519_dalek::Verifier;
+
+    let Ok(verifying_key) = ed25519_dalek::VerifyingKey::from_bytes(public_key) else {
+        return false;
+    };
+    let Ok(signature_bytes) = hex::decode(signature) else {
+        return false;
+    };
+    let Ok(signature) = ed25519_dalek::Signature::from_slice(&signature_bytes) else {
+        return false;
+    };
+
+    verifying_key
+        .verify(&signing_digest(data, timestamp), &signature)
+        .is_ok()
+}
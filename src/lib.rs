@@ -12,17 +12,17 @@
 //! ## Quick Start
 //!
 //! ```rust,no_run
-//! use kova_core::{init, SensorManager, BlockchainClient};
+//! use kova_core::{init, SensorManager, SolanaClient};
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     init().await?;
-//!     
+//!
 //!     let mut sensor_manager = SensorManager::new();
-//!     let blockchain_client = BlockchainClient::new().await?;
-//!     
+//!     let solana_client = SolanaClient::new(Default::default()).await?;
+//!
 //!     // Your robotics application code here
-//!     
+//!
 //!     Ok(())
 //! }
 //! ```
@@ -40,12 +40,80 @@ pub mod sdk;
 pub mod sensors;
 
 /// Initialize the Kova Core system
-pub async fn init() -> Result<(), Box<dyn std::error::Error>> {
-    tracing_subscriber::fmt::init();
+///
+/// Thin wrapper over [`init_with`] using the default configuration and
+/// `INFO` level logging.
+pub async fn init() -> core::error::Result<()> {
+    init_with(&Config::default(), tracing::Level::INFO)?;
+    Ok(())
+}
+
+/// Initialize the Kova Core system with a configuration and log level
+///
+/// Uses `try_init` so calling this more than once (e.g. from tests or an
+/// embedding application that already set a subscriber) is a no-op instead
+/// of a panic.
+pub fn init_with(config: &Config, level: tracing::Level) -> core::error::Result<()> {
+    if tracing_subscriber::fmt()
+        .with_max_level(level)
+        .try_init()
+        .is_err()
+    {
+        tracing::debug!("Tracing subscriber already set; keeping the existing one");
+    }
     tracing::info!("Initializing Kova Core");
+    tracing::debug!("Loaded configuration: {:?}", config);
     Ok(())
 }
 
+/// Build ready-to-use managers from a configuration
+///
+/// Instantiates the Solana, IPFS and Arweave clients from
+/// `config.blockchain` and registers them with a [`BlockchainManager`],
+/// applies `config.sensors` timeouts to a [`SensorManager`], and builds a
+/// [`DataValidator`] from `config.validation` — the wiring every example
+/// previously repeated by hand.
+#[cfg(all(feature = "sensors", feature = "blockchain", feature = "validation"))]
+pub async fn bootstrap(
+    config: &Config,
+) -> core::error::Result<(SensorManager, BlockchainManager, DataValidator)> {
+    let mut sensor_manager = SensorManager::new();
+    sensor_manager
+        .set_capture_timeout(std::time::Duration::from_secs(config.sensors.timeout_seconds));
+
+    let blockchain_manager = BlockchainManager::new();
+    blockchain_manager
+        .add_client(
+            "solana".to_string(),
+            Box::new(
+                blockchain::solana::SolanaClient::new(config.blockchain.solana.clone().into())
+                    .await?,
+            ),
+        )
+        .await;
+    blockchain_manager
+        .add_client(
+            "ipfs".to_string(),
+            Box::new(
+                blockchain::ipfs::IPFSClient::new(config.blockchain.ipfs.clone().into()).await?,
+            ),
+        )
+        .await;
+    blockchain_manager
+        .add_client(
+            "arweave".to_string(),
+            Box::new(
+                blockchain::arweave::ArweaveClient::new(config.blockchain.arweave.clone().into())
+                    .await?,
+            ),
+        )
+        .await;
+
+    let validator = DataValidator::with_config(config.validation.clone().into());
+
+    Ok((sensor_manager, blockchain_manager, validator))
+}
+
 /// Re-export commonly used types
 pub use core::{
     config::Config,
@@ -54,9 +122,11 @@ pub use core::{
     protocol::ProtocolManager,
     rewards::RewardManager,
     storage::StorageManager,
-    validation::ValidationManager,
 };
 
+/// Re-export the system health report entry point
+pub use core::diagnostics::{diagnostics, DiagnosticsReport};
+
 /// Re-export sensor types
 #[cfg(feature = "sensors")]
 pub use sensors::{
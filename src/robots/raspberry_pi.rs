@@ -98,6 +98,7 @@ pub struct RaspberryPiRobot {
     is_initialized: bool,
     sensors: HashMap<String, PiSensor>,
     system_info: SystemInfo,
+    pin_states: HashMap<u8, bool>,
 }
 
 /// Pi sensor
@@ -139,6 +140,8 @@ pub struct SystemInfo {
     pub disk_usage: f32,
     pub temperature: f32,
     pub uptime: u64,
+    /// Whether the values were fabricated rather than read from hardware
+    pub is_simulated: bool,
 }
 
 impl Default for RaspberryPiConfig {
@@ -185,6 +188,12 @@ impl Default for RaspberryPiConfig {
 impl RaspberryPiRobot {
     /// Create a new Raspberry Pi robot
     pub fn new(id: String, config: RaspberryPiConfig) -> Result<Self, Error> {
+        let pin_states = config
+            .gpio_pins
+            .iter()
+            .filter_map(|(pin, pin_config)| pin_config.initial_value.map(|value| (*pin, value)))
+            .collect();
+
         Ok(Self {
             id,
             config,
@@ -196,7 +205,9 @@ impl RaspberryPiRobot {
                 disk_usage: 0.0,
                 temperature: 0.0,
                 uptime: 0,
+                is_simulated: cfg!(not(feature = "raspberry-pi")),
             },
+            pin_states,
         })
     }
 
@@ -254,6 +265,86 @@ impl RaspberryPiRobot {
         Ok(())
     }
 
+    /// Read a configured GPIO pin
+    ///
+    /// The pin must be configured as `Input`, or as `Output` to read back the
+    /// last driven level. Without the `raspberry-pi` feature the in-memory
+    /// pin state map is consulted instead of real hardware.
+    pub fn read_pin(&self, pin: u8) -> Result<bool, Error> {
+        let pin_config = self
+            .config
+            .gpio_pins
+            .get(&pin)
+            .ok_or_else(|| Error::sensor(format!("GPIO pin {} not configured", pin)))?;
+
+        match pin_config.mode {
+            PinMode::Input | PinMode::Output => {}
+            other => {
+                return Err(Error::sensor(format!(
+                    "GPIO pin {} is configured as {:?} and cannot be read",
+                    pin, other
+                )))
+            }
+        }
+
+        #[cfg(feature = "raspberry-pi")]
+        {
+            let gpio = rppal::gpio::Gpio::new()
+                .map_err(|e| Error::sensor(format!("GPIO access failed: {}", e)))?;
+            let gpio_pin = gpio
+                .get(pin)
+                .map_err(|e| Error::sensor(format!("GPIO pin {} unavailable: {}", pin, e)))?;
+            return Ok(gpio_pin.into_input().is_high());
+        }
+
+        #[cfg(not(feature = "raspberry-pi"))]
+        {
+            let default = matches!(pin_config.pull_up_down, Some(PullUpDown::PullUp));
+            Ok(*self.pin_states.get(&pin).unwrap_or(&default))
+        }
+    }
+
+    /// Write a configured GPIO pin
+    ///
+    /// The pin must be configured as `Output` or `PWM`. Without the
+    /// `raspberry-pi` feature the value is recorded in the in-memory pin
+    /// state map instead of being driven on real hardware.
+    pub fn write_pin(&mut self, pin: u8, value: bool) -> Result<(), Error> {
+        let pin_config = self
+            .config
+            .gpio_pins
+            .get(&pin)
+            .ok_or_else(|| Error::sensor(format!("GPIO pin {} not configured", pin)))?;
+
+        match pin_config.mode {
+            PinMode::Output | PinMode::PWM => {}
+            other => {
+                return Err(Error::sensor(format!(
+                    "GPIO pin {} is configured as {:?} and cannot be written",
+                    pin, other
+                )))
+            }
+        }
+
+        #[cfg(feature = "raspberry-pi")]
+        {
+            let gpio = rppal::gpio::Gpio::new()
+                .map_err(|e| Error::sensor(format!("GPIO access failed: {}", e)))?;
+            let mut gpio_pin = gpio
+                .get(pin)
+                .map_err(|e| Error::sensor(format!("GPIO pin {} unavailable: {}", pin, e)))?
+                .into_output();
+            if value {
+                gpio_pin.set_high();
+            } else {
+                gpio_pin.set_low();
+            }
+        }
+
+        self.pin_states.insert(pin, value);
+        Ok(())
+    }
+
     /// Add sensor
     pub fn add_sensor(&mut self, sensor: PiSensor) {
         self.sensors.insert(sensor.id.clone(), sensor);
@@ -277,14 +368,14 @@ impl RaspberryPiRobot {
     /// Read sensor value
     pub async fn read_sensor(&self, sensor_id: &str) -> Result<f32, Error> {
         if let Some(sensor) = self.sensors.get(sensor_id) {
-            self.simulate_sensor_reading(sensor).await
+            Self::simulate_sensor_reading(sensor).await
         } else {
             Err(Error::sensor("Sensor not found"))
         }
     }
 
     /// Simulate sensor reading
-    async fn simulate_sensor_reading(&self, sensor: &PiSensor) -> Result<f32, Error> {
+    async fn simulate_sensor_reading(sensor: &PiSensor) -> Result<f32, Error> {
         match sensor.sensor_type {
             PiSensorType::Temperature => {
                 // Simulate temperature sensor (20-30°C)
@@ -321,7 +412,7 @@ impl RaspberryPiRobot {
     pub async fn update_sensors(&mut self) -> Result<(), Error> {
         for sensor in self.sensors.values_mut() {
             if sensor.enabled {
-                let value = self.simulate_sensor_reading(sensor).await?;
+                let value = Self::simulate_sensor_reading(sensor).await?;
                 sensor.last_value = Some(value);
                 sensor.last_update = Some(chrono::Utc::now());
             }
@@ -330,18 +421,127 @@ impl RaspberryPiRobot {
     }
 
     /// Update system information
+    ///
+    /// With the `raspberry-pi` feature enabled the values come from
+    /// `/proc/stat`, `/proc/meminfo`, and the thermal zone; on other
+    /// platforms the simulated values are kept and flagged as such.
     pub async fn update_system_info(&mut self) -> Result<(), Error> {
-        if self.config.enable_system_monitoring {
+        if !self.config.enable_system_monitoring {
+            return Ok(());
+        }
+
+        #[cfg(feature = "raspberry-pi")]
+        {
+            self.system_info.cpu_usage = Self::read_cpu_usage().await?;
+            self.system_info.memory_usage = Self::read_memory_usage().await?;
+            self.system_info.temperature = Self::read_cpu_temperature().await?;
+            self.system_info.uptime = Self::read_uptime().await?;
+            self.system_info.is_simulated = false;
+        }
+
+        #[cfg(not(feature = "raspberry-pi"))]
+        {
             // Simulate system monitoring
             self.system_info.cpu_usage = 25.0 + 10.0 * (chrono::Utc::now().timestamp_millis() as f32 / 10000.0).sin();
             self.system_info.memory_usage = 60.0 + 15.0 * (chrono::Utc::now().timestamp_millis() as f32 / 15000.0).cos();
             self.system_info.disk_usage = 40.0 + 5.0 * (chrono::Utc::now().timestamp_millis() as f32 / 20000.0).sin();
             self.system_info.temperature = 45.0 + 5.0 * (chrono::Utc::now().timestamp_millis() as f32 / 12000.0).cos();
             self.system_info.uptime = chrono::Utc::now().timestamp() as u64;
+            self.system_info.is_simulated = true;
         }
+
         Ok(())
     }
 
+    /// Sample /proc/stat twice and derive overall CPU usage
+    #[cfg(feature = "raspberry-pi")]
+    async fn read_cpu_usage() -> Result<f32, Error> {
+        fn cpu_times(stat: &str) -> Option<(u64, u64)> {
+            let fields: Vec<u64> = stat
+                .lines()
+                .next()?
+                .split_whitespace()
+                .skip(1)
+                .filter_map(|f| f.parse().ok())
+                .collect();
+            let idle = *fields.get(3)? + fields.get(4).copied().unwrap_or(0);
+            let total: u64 = fields.iter().sum();
+            Some((idle, total))
+        }
+
+        let first = tokio::fs::read_to_string("/proc/stat")
+            .await
+            .map_err(|e| Error::sensor(format!("Failed to read /proc/stat: {}", e)))?;
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        let second = tokio::fs::read_to_string("/proc/stat")
+            .await
+            .map_err(|e| Error::sensor(format!("Failed to read /proc/stat: {}", e)))?;
+
+        let ((idle_a, total_a), (idle_b, total_b)) = cpu_times(&first)
+            .zip(cpu_times(&second))
+            .ok_or_else(|| Error::sensor("Malformed /proc/stat"))?;
+
+        let total_delta = total_b.saturating_sub(total_a);
+        if total_delta == 0 {
+            return Ok(0.0);
+        }
+        let idle_delta = idle_b.saturating_sub(idle_a);
+        Ok(100.0 * (1.0 - idle_delta as f32 / total_delta as f32))
+    }
+
+    /// Derive memory usage from /proc/meminfo
+    #[cfg(feature = "raspberry-pi")]
+    async fn read_memory_usage() -> Result<f32, Error> {
+        let meminfo = tokio::fs::read_to_string("/proc/meminfo")
+            .await
+            .map_err(|e| Error::sensor(format!("Failed to read /proc/meminfo: {}", e)))?;
+
+        let field = |name: &str| -> Option<u64> {
+            meminfo
+                .lines()
+                .find(|line| line.starts_with(name))?
+                .split_whitespace()
+                .nth(1)?
+                .parse()
+                .ok()
+        };
+
+        let total = field("MemTotal:").ok_or_else(|| Error::sensor("Malformed /proc/meminfo"))?;
+        let available =
+            field("MemAvailable:").ok_or_else(|| Error::sensor("Malformed /proc/meminfo"))?;
+        if total == 0 {
+            return Ok(0.0);
+        }
+        Ok(100.0 * (1.0 - available as f32 / total as f32))
+    }
+
+    /// Read the SoC temperature from the thermal zone
+    #[cfg(feature = "raspberry-pi")]
+    async fn read_cpu_temperature() -> Result<f32, Error> {
+        let raw = tokio::fs::read_to_string("/sys/class/thermal/thermal_zone0/temp")
+            .await
+            .map_err(|e| Error::sensor(format!("Failed to read thermal zone: {}", e)))?;
+        let millidegrees: f32 = raw
+            .trim()
+            .parse()
+            .map_err(|_| Error::sensor("Malformed thermal zone reading"))?;
+        Ok(millidegrees / 1000.0)
+    }
+
+    /// Read system uptime in seconds from /proc/uptime
+    #[cfg(feature = "raspberry-pi")]
+    async fn read_uptime() -> Result<u64, Error> {
+        let raw = tokio::fs::read_to_string("/proc/uptime")
+            .await
+            .map_err(|e| Error::sensor(format!("Failed to read /proc/uptime: {}", e)))?;
+        let seconds: f64 = raw
+            .split_whitespace()
+            .next()
+            .and_then(|f| f.parse().ok())
+            .ok_or_else(|| Error::sensor("Malformed /proc/uptime"))?;
+        Ok(seconds as u64)
+    }
+
     /// Get system information
     pub fn get_system_info(&self) -> &SystemInfo {
         &self.system_info
@@ -1,5 +1,6 @@
 //! ROS2 configuration
 
+use crate::core::Error;
 use serde::{Deserialize, Serialize};
 
 /// ROS2 configuration
@@ -13,6 +14,48 @@ pub struct ROS2Config {
     pub qos_profile: String,
 }
 
+/// Resolved ROS2 quality-of-service profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QosProfile {
+    /// Best-effort, small queue — suited to high-rate sensor streams
+    SensorData,
+    /// Reliable delivery with a default queue
+    Reliable,
+    /// Best-effort delivery with a default queue
+    BestEffort,
+    /// Reliable delivery for parameter traffic
+    Parameters,
+}
+
+impl QosProfile {
+    /// Parse a QoS profile name, erroring on unknown values
+    pub fn parse(name: &str) -> Result<Self, Error> {
+        match name {
+            "sensor_data" => Ok(Self::SensorData),
+            "reliable" | "default" => Ok(Self::Reliable),
+            "best_effort" => Ok(Self::BestEffort),
+            "parameters" => Ok(Self::Parameters),
+            other => Err(Error::config(format!("Unknown QoS profile: {}", other))),
+        }
+    }
+
+    /// Queue depth applied when this profile is used
+    pub fn queue_depth(&self) -> usize {
+        match self {
+            Self::SensorData => 5,
+            Self::Reliable | Self::BestEffort => 10,
+            Self::Parameters => 1000,
+        }
+    }
+}
+
+impl ROS2Config {
+    /// Resolve the configured QoS profile string
+    pub fn parsed_qos(&self) -> Result<QosProfile, Error> {
+        QosProfile::parse(&self.qos_profile)
+    }
+}
+
 impl Default for ROS2Config {
     fn default() -> Self {
         Self {
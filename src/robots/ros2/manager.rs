@@ -1,7 +1,9 @@
 //! ROS2 manager
 
 use crate::core::Error;
-use crate::robots::ros2::{ROS2Bridge, ROS2Config};
+use crate::robots::ros2::ROS2Bridge;
+use crate::robots::ros2::bridge::ROS2Config;
+use crate::sensors::SensorData;
 
 /// ROS2 manager for handling ROS2 operations
 pub struct ROS2Manager {
@@ -27,4 +29,73 @@ impl ROS2Manager {
     pub fn get_bridge(&self, name: &str) -> Option<&ROS2Bridge> {
         self.bridges.get(name)
     }
+
+    /// Connect every registered bridge, collecting per-bridge failures
+    pub async fn connect_all(&mut self) -> Result<(), Error> {
+        let mut failures = Vec::new();
+
+        for (name, bridge) in self.bridges.iter_mut() {
+            if let Err(e) = bridge.connect().await {
+                tracing::warn!("Failed to connect bridge {}: {}", name, e);
+                failures.push(format!("{}: {}", name, e));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::network(format!(
+                "Failed to connect {} bridge(s): {}",
+                failures.len(),
+                failures.join("; ")
+            )))
+        }
+    }
+
+    /// Disconnect every registered bridge, collecting per-bridge failures
+    pub async fn disconnect_all(&mut self) -> Result<(), Error> {
+        let mut failures = Vec::new();
+
+        for (name, bridge) in self.bridges.iter_mut() {
+            if let Err(e) = bridge.disconnect().await {
+                tracing::warn!("Failed to disconnect bridge {}: {}", name, e);
+                failures.push(format!("{}: {}", name, e));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::network(format!(
+                "Failed to disconnect {} bridge(s): {}",
+                failures.len(),
+                failures.join("; ")
+            )))
+        }
+    }
+
+    /// Publish sensor data on a topic through every registered bridge
+    ///
+    /// Per-bridge failures are collected and reported without aborting the
+    /// rest of the batch.
+    pub async fn publish_all(&self, topic: &str, data: &SensorData) -> Result<(), Error> {
+        let mut failures = Vec::new();
+
+        for (name, bridge) in self.bridges.iter() {
+            if let Err(e) = bridge.publish(topic, data).await {
+                tracing::warn!("Failed to publish via bridge {}: {}", name, e);
+                failures.push(format!("{}: {}", name, e));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::network(format!(
+                "Failed to publish on {} bridge(s): {}",
+                failures.len(),
+                failures.join("; ")
+            )))
+        }
+    }
 }
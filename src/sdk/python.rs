@@ -1,6 +1,7 @@
 //! Python SDK for Kova Core
 
 use crate::core::Error;
+use crate::sensors::SensorType;
 use serde::{Deserialize, Serialize};
 
 /// Python SDK client
@@ -59,13 +60,12 @@ impl PythonSDK {
 
     /// Generate Python code for sensor data processing
     pub fn generate_sensor_code(&self, sensor_type: &str) -> Result<String, Error> {
-        let code = match sensor_type {
-            "camera" => self.generate_camera_code(),
-            "lidar" => self.generate_lidar_code(),
-            "imu" => self.generate_imu_code(),
-            "gps" => self.generate_gps_code(),
-            "thermal" => self.generate_thermal_code(),
-            _ => return Err(Error::sensor("Unknown sensor type")),
+        let code = match sensor_type.parse::<SensorType>()? {
+            SensorType::Camera => self.generate_camera_code(),
+            SensorType::LiDAR => self.generate_lidar_code(),
+            SensorType::IMU => self.generate_imu_code(),
+            SensorType::GPS => self.generate_gps_code(),
+            SensorType::Thermal => self.generate_thermal_code(),
         };
         Ok(code)
     }
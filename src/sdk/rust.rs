@@ -1,11 +1,27 @@
 //! Rust SDK for Kova Core
 
+use crate::api::websocket::{Envelope, WebSocketMessage};
 use crate::core::Error;
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+/// Delay between WebSocket reconnection attempts
+const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+/// Consecutive failed reconnections before a subscription gives up
+const MAX_RECONNECT_ATTEMPTS: u32 = 5;
+
+type WsStream = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
 
 /// Rust SDK client
 pub struct RustSDK {
     config: RustSDKConfig,
+    client: reqwest::Client,
 }
 
 /// Rust SDK configuration
@@ -50,8 +66,53 @@ impl RustSDK {
         if config.enable_logging {
             tracing::info!("Initializing Rust SDK with endpoint: {}", config.api_endpoint);
         }
-        
-        Ok(Self { config })
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .build()
+            .map_err(|e| Error::network(format!("Failed to build HTTP client: {}", e)))?;
+
+        Ok(Self { config, client })
+    }
+
+    /// Build a request against the configured endpoint with auth headers
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let url = format!(
+            "{}/{}",
+            self.config.api_endpoint.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        );
+        let mut builder = self.client.request(method, url);
+        if let Some(api_key) = &self.config.api_key {
+            builder = builder.header("x-api-key", api_key);
+        }
+        builder
+    }
+
+    /// Send a request and parse the `ApiResponse<T>` envelope
+    async fn execute<T: DeserializeOwned>(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<SDKResponse<T>, Error> {
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        let body: SDKResponse<T> = response
+            .json()
+            .await
+            .map_err(|e| Error::network(format!("Invalid response body: {}", e)))?;
+
+        if !status.is_success() {
+            return Err(Error::network(format!(
+                "Server returned {}: {}",
+                status,
+                body.error.unwrap_or_else(|| "no error detail".to_string())
+            )));
+        }
+        Ok(body)
     }
 
     /// Initialize the SDK
@@ -61,43 +122,159 @@ impl RustSDK {
     }
 
     /// Send sensor data
+    ///
+    /// POSTs the raw bytes to `/sensor-data` on the configured endpoint.
     pub async fn send_sensor_data(&self, data: &[u8]) -> Result<SDKResponse<String>, Error> {
-        let response = SDKResponse {
-            success: true,
-            data: Some("sensor_data_received".to_string()),
-            error: None,
-            timestamp: chrono::Utc::now(),
-        };
-        Ok(response)
+        let builder = self
+            .request(reqwest::Method::POST, "/sensor-data")
+            .header("content-type", "application/octet-stream")
+            .body(data.to_vec());
+        self.execute(builder).await
     }
 
     /// Get validation result
+    ///
+    /// GETs `/validation/{id}` on the configured endpoint.
     pub async fn get_validation_result(&self, id: &str) -> Result<SDKResponse<ValidationResult>, Error> {
-        let result = ValidationResult {
-            id: id.to_string(),
-            quality_score: 0.85,
-            is_valid: true,
-            timestamp: chrono::Utc::now(),
-        };
-
-        let response = SDKResponse {
-            success: true,
-            data: Some(result),
-            error: None,
-            timestamp: chrono::Utc::now(),
-        };
-        Ok(response)
+        let builder = self.request(reqwest::Method::GET, &format!("/validation/{}", id));
+        self.execute(builder).await
     }
 
     /// Submit contribution
+    ///
+    /// POSTs the contribution as JSON to `/contributions` on the configured
+    /// endpoint.
     pub async fn submit_contribution(&self, contribution: &Contribution) -> Result<SDKResponse<String>, Error> {
-        let response = SDKResponse {
-            success: true,
-            data: Some("contribution_submitted".to_string()),
-            error: None,
-            timestamp: chrono::Utc::now(),
-        };
-        Ok(response)
+        let builder = self
+            .request(reqwest::Method::POST, "/contributions")
+            .json(contribution);
+        self.execute(builder).await
+    }
+
+    /// The WebSocket endpoint derived from the configured API endpoint
+    fn ws_endpoint(&self) -> String {
+        let endpoint = self.config.api_endpoint.trim_end_matches('/');
+        if let Some(rest) = endpoint.strip_prefix("https://") {
+            format!("wss://{}", rest)
+        } else if let Some(rest) = endpoint.strip_prefix("http://") {
+            format!("ws://{}", rest)
+        } else {
+            endpoint.to_string()
+        }
+    }
+
+    /// Subscribe to a topic on the WebSocket server
+    ///
+    /// Connects, authenticates with the configured API key, subscribes to the
+    /// topic and yields incoming messages as a stream. Transient connection
+    /// drops are retried with a short delay; the stream ends after
+    /// [`MAX_RECONNECT_ATTEMPTS`] consecutive failures or when the consumer
+    /// drops it.
+    pub async fn subscribe(
+        &self,
+        topic: &str,
+    ) -> Result<impl Stream<Item = WebSocketMessage>, Error> {
+        let url = self.ws_endpoint();
+        let api_key = self.config.api_key.clone();
+        let topic = topic.to_string();
+
+        // Connect eagerly so configuration problems surface here, not on the
+        // first poll of the stream.
+        let ws = connect_and_subscribe(&url, api_key.as_deref(), &topic).await?;
+
+        let (tx, rx) = mpsc::channel(100);
+        tokio::spawn(pump_subscription(ws, url, api_key, topic, tx));
+
+        Ok(futures_util::stream::unfold(rx, |mut rx| async {
+            rx.recv().await.map(|message| (message, rx))
+        }))
+    }
+}
+
+/// Open a WebSocket connection, authenticate and subscribe to a topic
+async fn connect_and_subscribe(
+    url: &str,
+    api_key: Option<&str>,
+    topic: &str,
+) -> Result<WsStream, Error> {
+    let (mut ws, _) = tokio_tungstenite::connect_async(url)
+        .await
+        .map_err(|e| Error::network(format!("WebSocket connection failed: {}", e)))?;
+
+    if let Some(api_key) = api_key {
+        let auth = Envelope::new(WebSocketMessage::Auth {
+            api_key: api_key.to_string(),
+        })
+        .encode()?;
+        ws.send(Message::Text(auth))
+            .await
+            .map_err(|e| Error::network(format!("WebSocket auth failed: {}", e)))?;
+    }
+
+    let subscribe = Envelope::new(WebSocketMessage::Subscribe {
+        topic: topic.to_string(),
+    })
+    .encode()?;
+    ws.send(Message::Text(subscribe))
+        .await
+        .map_err(|e| Error::network(format!("WebSocket subscribe failed: {}", e)))?;
+
+    Ok(ws)
+}
+
+/// Forward messages from the server into the subscription channel,
+/// reconnecting on transient drops
+async fn pump_subscription(
+    mut ws: WsStream,
+    url: String,
+    api_key: Option<String>,
+    topic: String,
+    tx: mpsc::Sender<WebSocketMessage>,
+) {
+    let mut failures = 0u32;
+    loop {
+        match ws.next().await {
+            Some(Ok(Message::Text(text))) => {
+                if let Ok(message) = Envelope::decode(&text) {
+                    if tx.send(message).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            Some(Ok(Message::Binary(bytes))) => {
+                if let Ok(message) = WebSocketMessage::decode_binary(&bytes) {
+                    if tx.send(message).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            Some(Ok(_)) => {}
+            Some(Err(_)) | None => {
+                // Connection dropped: retry with a delay, re-subscribing on
+                // success so the server restores our topic filter.
+                loop {
+                    if tx.is_closed() {
+                        return;
+                    }
+                    failures += 1;
+                    if failures > MAX_RECONNECT_ATTEMPTS {
+                        tracing::warn!("WebSocket subscription to {} gave up reconnecting", topic);
+                        return;
+                    }
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                    match connect_and_subscribe(&url, api_key.as_deref(), &topic).await {
+                        Ok(reconnected) => {
+                            ws = reconnected;
+                            failures = 0;
+                            break;
+                        }
+                        Err(e) => {
+                            tracing::debug!("WebSocket reconnect attempt failed: {}", e);
+                        }
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -1,7 +1,9 @@
 //! Camera sensor implementation
 
 use crate::core::Error;
-use crate::sensors::{Sensor, SensorData, SensorType};
+use crate::sensors::{Sensor, SensorData, SensorState, SensorType};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -26,6 +28,16 @@ pub struct CameraConfig {
     pub focus_mode: FocusMode,
     /// White balance mode
     pub white_balance_mode: WhiteBalanceMode,
+    /// Maximum accepted resolution (width, height)
+    #[serde(default = "default_max_resolution")]
+    pub max_resolution: (u32, u32),
+    /// Seed driving synthetic captures, for reproducible test data
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+fn default_max_resolution() -> (u32, u32) {
+    (7680, 4320)
 }
 
 /// Image format
@@ -75,6 +87,117 @@ pub enum WhiteBalanceMode {
     Manual,
 }
 
+impl CameraConfig {
+    /// Start building a configuration from the defaults
+    pub fn builder() -> CameraConfigBuilder {
+        CameraConfigBuilder::default()
+    }
+}
+
+/// Chainable builder for [`CameraConfig`]
+///
+/// Starts from [`CameraConfig::default`]; `build` validates ranges so a
+/// misconfigured camera fails before it reaches [`Camera::new`].
+#[derive(Debug, Clone, Default)]
+pub struct CameraConfigBuilder {
+    config: CameraConfig,
+}
+
+impl CameraConfigBuilder {
+    /// Set the image resolution
+    pub fn resolution(mut self, width: u32, height: u32) -> Self {
+        self.config.resolution = (width, height);
+        self
+    }
+
+    /// Set the frame rate in FPS
+    pub fn frame_rate(mut self, frame_rate: u32) -> Self {
+        self.config.frame_rate = frame_rate;
+        self
+    }
+
+    /// Set the image format
+    pub fn format(mut self, format: ImageFormat) -> Self {
+        self.config.format = format;
+        self
+    }
+
+    /// Enable or disable auto exposure
+    pub fn auto_exposure(mut self, enabled: bool) -> Self {
+        self.config.auto_exposure = enabled;
+        self
+    }
+
+    /// Enable or disable auto white balance
+    pub fn auto_white_balance(mut self, enabled: bool) -> Self {
+        self.config.auto_white_balance = enabled;
+        self
+    }
+
+    /// Set the exposure compensation (-2.0 to 2.0)
+    pub fn exposure_compensation(mut self, ev: f32) -> Self {
+        self.config.exposure_compensation = ev;
+        self
+    }
+
+    /// Set the ISO sensitivity
+    pub fn iso_sensitivity(mut self, iso: u32) -> Self {
+        self.config.iso_sensitivity = iso;
+        self
+    }
+
+    /// Set the focus mode
+    pub fn focus_mode(mut self, mode: FocusMode) -> Self {
+        self.config.focus_mode = mode;
+        self
+    }
+
+    /// Set the white balance mode
+    pub fn white_balance_mode(mut self, mode: WhiteBalanceMode) -> Self {
+        self.config.white_balance_mode = mode;
+        self
+    }
+
+    /// Set the maximum accepted resolution
+    pub fn max_resolution(mut self, width: u32, height: u32) -> Self {
+        self.config.max_resolution = (width, height);
+        self
+    }
+
+    /// Seed the synthetic data generator for reproducible captures
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    /// Validate the configuration and return it
+    pub fn build(self) -> Result<CameraConfig, Error> {
+        let (width, height) = self.config.resolution;
+        let (max_width, max_height) = self.config.max_resolution;
+        if width == 0 || height == 0 {
+            return Err(Error::config("Camera resolution must be non-zero"));
+        }
+        if width > max_width || height > max_height {
+            return Err(Error::config(format!(
+                "Camera resolution {}x{} exceeds maximum {}x{}",
+                width, height, max_width, max_height
+            )));
+        }
+        if self.config.frame_rate == 0 {
+            return Err(Error::config("Camera frame rate must be non-zero"));
+        }
+        if !(-2.0..=2.0).contains(&self.config.exposure_compensation) {
+            return Err(Error::config(
+                "Exposure compensation must be between -2.0 and 2.0",
+            ));
+        }
+        if self.config.iso_sensitivity == 0 {
+            return Err(Error::config("ISO sensitivity must be non-zero"));
+        }
+        Ok(self.config)
+    }
+}
+
 impl Default for CameraConfig {
     fn default() -> Self {
         Self {
@@ -87,6 +210,8 @@ impl Default for CameraConfig {
             iso_sensitivity: 100,
             focus_mode: FocusMode::Auto,
             white_balance_mode: WhiteBalanceMode::Auto,
+            max_resolution: default_max_resolution(),
+            seed: None,
         }
     }
 }
@@ -96,22 +221,82 @@ pub struct Camera {
     id: String,
     config: CameraConfig,
     is_initialized: bool,
+    state: SensorState,
+    roi: Option<(u32, u32, u32, u32)>,
+    rng: Option<rand::rngs::StdRng>,
 }
 
 impl Camera {
     /// Create a new camera sensor
+    ///
+    /// The configuration is validated up front so misconfigured cameras
+    /// fail at construction instead of producing empty or huge buffers.
     pub fn new(id: String, config: CameraConfig) -> Result<Self, Error> {
+        let (width, height) = config.resolution;
+        let (max_width, max_height) = config.max_resolution;
+
+        if width == 0 || height == 0 {
+            return Err(Error::sensor(format!(
+                "Invalid camera resolution {}x{}: width and height must be non-zero",
+                width, height
+            )));
+        }
+        if width > max_width || height > max_height {
+            return Err(Error::sensor(format!(
+                "Camera resolution {}x{} exceeds maximum {}x{}",
+                width, height, max_width, max_height
+            )));
+        }
+        if config.frame_rate == 0 {
+            return Err(Error::sensor("Camera frame rate must be non-zero"));
+        }
+
         Ok(Self {
             id,
+            rng: config.seed.map(rand::rngs::StdRng::seed_from_u64),
             config,
             is_initialized: false,
+            state: SensorState::Uninitialized,
+            roi: None,
         })
     }
 
+    /// Restrict captures to a region of interest
+    ///
+    /// The region is given as top-left corner plus size in pixels and must
+    /// lie entirely within the configured resolution.
+    pub fn set_roi(&mut self, x: u32, y: u32, w: u32, h: u32) -> Result<(), Error> {
+        let (width, height) = self.config.resolution;
+
+        if w == 0 || h == 0 {
+            return Err(Error::sensor("ROI width and height must be non-zero"));
+        }
+        if x.saturating_add(w) > width || y.saturating_add(h) > height {
+            return Err(Error::sensor(format!(
+                "ROI {}x{} at ({}, {}) exceeds frame resolution {}x{}",
+                w, h, x, y, width, height
+            )));
+        }
+
+        self.roi = Some((x, y, w, h));
+        Ok(())
+    }
+
+    /// Reset captures to the full frame
+    pub fn clear_roi(&mut self) {
+        self.roi = None;
+    }
+
+    /// Get the active region of interest, if any
+    pub fn roi(&self) -> Option<(u32, u32, u32, u32)> {
+        self.roi
+    }
+
     /// Initialize the camera
     pub async fn initialize(&mut self) -> Result<(), Error> {
         tracing::info!("Initializing camera: {}", self.id);
         self.is_initialized = true;
+        self.state = SensorState::Ready;
         Ok(())
     }
 
@@ -123,22 +308,64 @@ impl Camera {
 
         // Simulate image capture
         let image_data = self.generate_test_image().await?;
-        Ok(image_data)
+
+        match self.roi {
+            Some(roi) => self.crop_to_roi(&image_data, roi),
+            None => Ok(image_data),
+        }
+    }
+
+    /// Extract the ROI rows from a full raw frame
+    fn crop_to_roi(&self, frame: &[u8], (x, y, w, h): (u32, u32, u32, u32)) -> Result<Vec<u8>, Error> {
+        let bytes_per_pixel = match self.config.format {
+            ImageFormat::RGB => 3,
+            ImageFormat::RGBA => 4,
+            ImageFormat::Grayscale => 1,
+            _ => return Err(Error::sensor("ROI crop requires a raw image format")),
+        };
+
+        // The ROI was validated against the resolution at set time, but
+        // update_config may have shrunk the frame since; re-check before
+        // slicing instead of panicking out of bounds
+        let (width, height) = self.config.resolution;
+        if x.saturating_add(w) > width || y.saturating_add(h) > height {
+            return Err(Error::sensor(format!(
+                "ROI {}x{} at ({}, {}) exceeds frame resolution {}x{}",
+                w, h, x, y, width, height
+            )));
+        }
+        let stride = width as usize * bytes_per_pixel;
+        let row_bytes = w as usize * bytes_per_pixel;
+
+        let mut cropped = Vec::with_capacity(h as usize * row_bytes);
+        for row in y..y + h {
+            let start = row as usize * stride + x as usize * bytes_per_pixel;
+            cropped.extend_from_slice(&frame[start..start + row_bytes]);
+        }
+
+        Ok(cropped)
     }
 
     /// Generate test image data
-    async fn generate_test_image(&self) -> Result<Vec<u8>, Error> {
+    async fn generate_test_image(&mut self) -> Result<Vec<u8>, Error> {
         let (width, height) = self.config.resolution;
         let pixel_count = (width * height) as usize;
-        
+
+        // A seeded camera shifts the pattern by a PRNG offset so captures
+        // are reproducible per seed but differ between seeds
+        let offset = match &mut self.rng {
+            Some(rng) => rng.gen::<u32>() as usize,
+            None => 0,
+        };
+
         match self.config.format {
             ImageFormat::RGB => {
                 let mut data = vec![0u8; pixel_count * 3];
                 for i in 0..pixel_count {
                     let base = i * 3;
-                    data[base] = (i % 256) as u8;     // Red
-                    data[base + 1] = ((i * 2) % 256) as u8; // Green
-                    data[base + 2] = ((i * 3) % 256) as u8; // Blue
+                    data[base] = ((i + offset) % 256) as u8;     // Red
+                    data[base + 1] = (((i + offset) * 2) % 256) as u8; // Green
+                    data[base + 2] = (((i + offset) * 3) % 256) as u8; // Blue
                 }
                 Ok(data)
             }
@@ -146,16 +373,16 @@ impl Camera {
                 let mut data = vec![0u8; pixel_count * 4];
                 for i in 0..pixel_count {
                     let base = i * 4;
-                    data[base] = (i % 256) as u8;     // Red
-                    data[base + 1] = ((i * 2) % 256) as u8; // Green
-                    data[base + 2] = ((i * 3) % 256) as u8; // Blue
+                    data[base] = ((i + offset) % 256) as u8;     // Red
+                    data[base + 1] = (((i + offset) * 2) % 256) as u8; // Green
+                    data[base + 2] = (((i + offset) * 3) % 256) as u8; // Blue
                     data[base + 3] = 255; // Alpha
                 }
                 Ok(data)
             }
             ImageFormat::Grayscale => {
                 let data = (0..pixel_count)
-                    .map(|i| (i % 256) as u8)
+                    .map(|i| ((i + offset) % 256) as u8)
                     .collect();
                 Ok(data)
             }
@@ -170,11 +397,17 @@ impl Camera {
 
     /// Update camera configuration
     pub fn update_config(&mut self, config: CameraConfig) {
+        self.rng = config.seed.map(rand::rngs::StdRng::seed_from_u64);
         self.config = config;
     }
 }
 
+#[async_trait]
 impl Sensor for Camera {
+    async fn initialize(&mut self) -> Result<(), Error> {
+        Camera::initialize(self).await
+    }
+
     fn id(&self) -> &str {
         &self.id
     }
@@ -184,23 +417,40 @@ impl Sensor for Camera {
     }
     
     async fn capture(&mut self) -> Result<SensorData, Error> {
-        let data = self.capture().await?;
+        self.state = SensorState::Capturing;
+        let data = match Camera::capture(self).await {
+            Ok(data) => data,
+            Err(e) => {
+                self.state = SensorState::Error(e.to_string());
+                return Err(e);
+            }
+        };
+        self.state = SensorState::Ready;
         let mut metadata = HashMap::new();
         metadata.insert("resolution".to_string(), format!("{}x{}", self.config.resolution.0, self.config.resolution.1));
         metadata.insert("format".to_string(), format!("{:?}", self.config.format));
         metadata.insert("frame_rate".to_string(), self.config.frame_rate.to_string());
-        
+        if let Some((x, y, w, h)) = self.roi {
+            metadata.insert("roi".to_string(), format!("{},{},{}x{}", x, y, w, h));
+        }
+
         Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
             sensor_id: self.id.clone(),
             sensor_type: SensorType::Camera,
             timestamp: chrono::Utc::now(),
             data,
             metadata,
+            checksum: None,
         })
     }
     
     async fn is_available(&self) -> bool {
-        self.is_initialized
+        matches!(self.state, SensorState::Ready | SensorState::Capturing)
+    }
+
+    fn state(&self) -> SensorState {
+        self.state.clone()
     }
     
     fn config(&self) -> &dyn std::fmt::Debug {
@@ -0,0 +1,103 @@
+//! Sensor fusion for dead reckoning
+//!
+//! Combines GPS fixes with IMU integration so consumers keep a pose
+//! estimate while GPS drops out.
+
+use crate::sensors::gps::GPSData;
+use crate::sensors::imu::IMUData;
+
+/// Metres of arc per degree of latitude
+const METERS_PER_DEGREE: f64 = 111_320.0;
+
+/// Fused pose estimate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoseEstimate {
+    /// Latitude in degrees
+    pub latitude: f64,
+    /// Longitude in degrees
+    pub longitude: f64,
+    /// Heading in degrees, clockwise from north
+    pub heading: f64,
+}
+
+/// Dead-reckoning estimator combining GPS fixes with IMU propagation
+///
+/// Each GPS fix resets the estimate to the measured position; between
+/// fixes the pose is propagated by integrating IMU acceleration and
+/// angular velocity from the last known velocity.
+pub struct DeadReckoner {
+    estimate: Option<PoseEstimate>,
+    /// Velocity in the local frame, metres per second (north, east)
+    velocity: (f64, f64),
+    last_update: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl Default for DeadReckoner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeadReckoner {
+    /// Create a new dead reckoner with no initial fix
+    pub fn new() -> Self {
+        Self {
+            estimate: None,
+            velocity: (0.0, 0.0),
+            last_update: None,
+        }
+    }
+
+    /// Correct the estimate with a GPS fix
+    pub fn update_gps(&mut self, fix: &GPSData) {
+        let heading_rad = fix.heading.to_radians();
+        self.estimate = Some(PoseEstimate {
+            latitude: fix.latitude,
+            longitude: fix.longitude,
+            heading: fix.heading,
+        });
+        self.velocity = (
+            fix.speed * heading_rad.cos(),
+            fix.speed * heading_rad.sin(),
+        );
+        self.last_update = Some(fix.timestamp);
+    }
+
+    /// Propagate the estimate with an IMU sample
+    ///
+    /// Does nothing until an initial GPS fix has been received.
+    pub fn update_imu(&mut self, sample: &IMUData) {
+        let (Some(mut estimate), Some(last_update)) = (self.estimate, self.last_update) else {
+            return;
+        };
+
+        let dt = (sample.timestamp - last_update).num_milliseconds() as f64 / 1000.0;
+        if dt <= 0.0 {
+            return;
+        }
+
+        // Integrate yaw rate, then rotate body-frame acceleration into the
+        // local north/east frame before integrating velocity and position
+        estimate.heading =
+            (estimate.heading + (sample.angular_velocity[2] as f64).to_degrees() * dt)
+                .rem_euclid(360.0);
+        let heading_rad = estimate.heading.to_radians();
+
+        let forward = sample.linear_acceleration[0] as f64;
+        let lateral = sample.linear_acceleration[1] as f64;
+        self.velocity.0 += (forward * heading_rad.cos() - lateral * heading_rad.sin()) * dt;
+        self.velocity.1 += (forward * heading_rad.sin() + lateral * heading_rad.cos()) * dt;
+
+        estimate.latitude += self.velocity.0 * dt / METERS_PER_DEGREE;
+        estimate.longitude +=
+            self.velocity.1 * dt / (METERS_PER_DEGREE * estimate.latitude.to_radians().cos());
+
+        self.estimate = Some(estimate);
+        self.last_update = Some(sample.timestamp);
+    }
+
+    /// Current fused estimate, if an initial GPS fix has been received
+    pub fn current_estimate(&self) -> Option<PoseEstimate> {
+        self.estimate
+    }
+}
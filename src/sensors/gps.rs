@@ -1,7 +1,9 @@
 //! GPS sensor implementation
 
 use crate::core::Error;
-use crate::sensors::{Sensor, SensorData, SensorType};
+use crate::sensors::{Sensor, SensorData, SensorState, SensorType};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -20,6 +22,9 @@ pub struct GPSConfig {
     pub reference_ellipsoid: String,
     /// Coordinate system
     pub coordinate_system: CoordinateSystem,
+    /// Seed driving synthetic captures, for reproducible test data
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 /// Coordinate system
@@ -52,12 +57,48 @@ pub struct GPSData {
     pub heading: f64,
     /// Number of satellites
     pub satellite_count: u32,
+    /// Horizontal dilution of precision
+    pub hdop: f64,
+    /// Vertical dilution of precision
+    pub vdop: f64,
+    /// Position (3D) dilution of precision
+    pub pdop: f64,
     /// Fix quality
     pub fix_quality: FixQuality,
     /// Timestamp
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
+impl GPSData {
+    /// Fill the DOP fields from an NMEA GSA sentence
+    ///
+    /// Accepts `$GPGSA` (and the GNSS variants `$GNGSA`/`$GLGSA`), whose
+    /// last three fields carry PDOP, HDOP and VDOP. A trailing `*hh`
+    /// checksum is tolerated but not verified.
+    pub fn apply_gsa_sentence(&mut self, sentence: &str) -> Result<(), Error> {
+        let sentence = sentence.trim();
+        let body = sentence.split('*').next().unwrap_or(sentence);
+        let fields: Vec<&str> = body.split(',').collect();
+
+        if !fields[0].ends_with("GSA") || fields.len() < 18 {
+            return Err(Error::sensor(format!(
+                "Not a GSA sentence: {}",
+                sentence
+            )));
+        }
+
+        let parse = |value: &str, name: &str| {
+            value.parse::<f64>().map_err(|_| {
+                Error::sensor(format!("Malformed {} in GSA sentence: {}", name, value))
+            })
+        };
+        self.pdop = parse(fields[15], "PDOP")?;
+        self.hdop = parse(fields[16], "HDOP")?;
+        self.vdop = parse(fields[17], "VDOP")?;
+        Ok(())
+    }
+}
+
 /// GPS fix quality
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FixQuality {
@@ -82,6 +123,7 @@ impl Default for GPSConfig {
             enable_rtk: false,
             reference_ellipsoid: "WGS84".to_string(),
             coordinate_system: CoordinateSystem::WGS84,
+            seed: None,
         }
     }
 }
@@ -91,7 +133,10 @@ pub struct GPS {
     id: String,
     config: GPSConfig,
     is_initialized: bool,
+    state: SensorState,
     last_position: Option<(f64, f64, f64)>,
+    geofence: Option<Vec<(f64, f64)>>,
+    rng: Option<rand::rngs::StdRng>,
 }
 
 impl GPS {
@@ -99,16 +144,49 @@ impl GPS {
     pub fn new(id: String, config: GPSConfig) -> Result<Self, Error> {
         Ok(Self {
             id,
+            rng: config.seed.map(rand::rngs::StdRng::seed_from_u64),
             config,
             is_initialized: false,
+            state: SensorState::Uninitialized,
             last_position: None,
+            geofence: None,
         })
     }
 
+    /// Set the permitted operating area as a lat/lon polygon
+    ///
+    /// Vertices are `(latitude, longitude)` pairs in WGS84 degrees; the
+    /// polygon is closed implicitly. While a geofence is set, captured
+    /// frames carry an `inside_geofence` metadata flag.
+    pub fn set_geofence(&mut self, polygon: Vec<(f64, f64)>) -> Result<(), Error> {
+        if polygon.len() < 3 {
+            return Err(Error::sensor("Geofence polygon needs at least 3 vertices"));
+        }
+        self.geofence = Some(polygon);
+        Ok(())
+    }
+
+    /// Remove the geofence
+    pub fn clear_geofence(&mut self) {
+        self.geofence = None;
+    }
+
+    /// Check whether a fix lies inside the geofence
+    ///
+    /// Returns `true` when no geofence is set. Uses an even-odd ray cast
+    /// over the polygon edges; adequate for fences far from the antimeridian.
+    pub fn check_geofence(&self, data: &GPSData) -> bool {
+        let Some(polygon) = &self.geofence else {
+            return true;
+        };
+        point_in_polygon(data.latitude, data.longitude, polygon)
+    }
+
     /// Initialize the GPS
     pub async fn initialize(&mut self) -> Result<(), Error> {
         tracing::info!("Initializing GPS: {}", self.id);
         self.is_initialized = true;
+        self.state = SensorState::Ready;
         Ok(())
     }
 
@@ -122,9 +200,14 @@ impl GPS {
     }
 
     /// Generate test GPS data
-    async fn generate_test_gps_data(&self) -> Result<GPSData, Error> {
+    async fn generate_test_gps_data(&mut self) -> Result<GPSData, Error> {
         let timestamp = chrono::Utc::now();
-        let time = timestamp.timestamp_millis() as f64 / 1000.0;
+        // A seeded receiver draws its drive value from the PRNG so fixes
+        // are reproducible; otherwise the wall clock drives the simulation
+        let time = match &mut self.rng {
+            Some(rng) => rng.gen::<f64>() * 1000.0,
+            None => timestamp.timestamp_millis() as f64 / 1000.0,
+        };
         
         // Simulate movement around a base location
         let base_lat = 37.7749; // San Francisco
@@ -170,6 +253,11 @@ impl GPS {
             FixQuality::GPSFix
         };
         
+        // Dilution of precision improves with satellites in view
+        let hdop = (10.0 / satellite_count as f64).max(0.5);
+        let vdop = hdop * 1.4;
+        let pdop = (hdop * hdop + vdop * vdop).sqrt();
+
         Ok(GPSData {
             latitude,
             longitude,
@@ -178,6 +266,9 @@ impl GPS {
             speed,
             heading,
             satellite_count,
+            hdop,
+            vdop,
+            pdop,
             fix_quality,
             timestamp,
         })
@@ -245,6 +336,11 @@ impl GPS {
         // Serialize satellite count and fix quality
         data.extend_from_slice(&gps_data.satellite_count.to_le_bytes());
         data.push(gps_data.fix_quality as u8);
+
+        // Serialize dilution of precision
+        data.extend_from_slice(&gps_data.hdop.to_le_bytes());
+        data.extend_from_slice(&gps_data.vdop.to_le_bytes());
+        data.extend_from_slice(&gps_data.pdop.to_le_bytes());
         
         // Serialize timestamp
         let timestamp_bytes = gps_data.timestamp.timestamp_millis().to_le_bytes();
@@ -260,11 +356,34 @@ impl GPS {
 
     /// Update GPS configuration
     pub fn update_config(&mut self, config: GPSConfig) {
+        self.rng = config.seed.map(rand::rngs::StdRng::seed_from_u64);
         self.config = config;
     }
 }
 
+/// Even-odd ray cast test for a point against a lat/lon polygon
+fn point_in_polygon(latitude: f64, longitude: f64, polygon: &[(f64, f64)]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let (lat_i, lon_i) = polygon[i];
+        let (lat_j, lon_j) = polygon[j];
+        if (lon_i > longitude) != (lon_j > longitude)
+            && latitude < (lat_j - lat_i) * (longitude - lon_i) / (lon_j - lon_i) + lat_i
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+#[async_trait]
 impl Sensor for GPS {
+    async fn initialize(&mut self) -> Result<(), Error> {
+        GPS::initialize(self).await
+    }
+
     fn id(&self) -> &str {
         &self.id
     }
@@ -274,7 +393,15 @@ impl Sensor for GPS {
     }
     
     async fn capture(&mut self) -> Result<SensorData, Error> {
-        let gps_data = self.capture().await?;
+        self.state = SensorState::Capturing;
+        let gps_data = match GPS::capture(self).await {
+            Ok(data) => data,
+            Err(e) => {
+                self.state = SensorState::Error(e.to_string());
+                return Err(e);
+            }
+        };
+        self.state = SensorState::Ready;
         let data = self.serialize_gps_data(&gps_data)?;
         
         let mut metadata = HashMap::new();
@@ -284,18 +411,27 @@ impl Sensor for GPS {
         metadata.insert("accuracy".to_string(), gps_data.accuracy.to_string());
         metadata.insert("satellite_count".to_string(), gps_data.satellite_count.to_string());
         metadata.insert("fix_quality".to_string(), format!("{:?}", gps_data.fix_quality));
-        
+        if self.geofence.is_some() {
+            metadata.insert("inside_geofence".to_string(), self.check_geofence(&gps_data).to_string());
+        }
+
         Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
             sensor_id: self.id.clone(),
             sensor_type: SensorType::GPS,
             timestamp: chrono::Utc::now(),
             data,
             metadata,
+            checksum: None,
         })
     }
     
     async fn is_available(&self) -> bool {
-        self.is_initialized
+        matches!(self.state, SensorState::Ready | SensorState::Capturing)
+    }
+
+    fn state(&self) -> SensorState {
+        self.state.clone()
     }
     
     fn config(&self) -> &dyn std::fmt::Debug {
@@ -0,0 +1,111 @@
+//! Multiplexing sensor group
+
+use crate::core::Error;
+use crate::sensors::{Sensor, SensorData, SensorType};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// Logical sensor backed by several physical sensors of the same type
+///
+/// On capture the member frames are concatenated, each preceded by a
+/// small header carrying the source id and payload length, and member
+/// metadata is merged under source-prefixed keys.
+pub struct SensorGroup {
+    id: String,
+    sensor_type: SensorType,
+    members: Vec<Box<dyn Sensor>>,
+    config: String,
+}
+
+impl SensorGroup {
+    /// Create an empty group for the given sensor type
+    pub fn new(id: String, sensor_type: SensorType) -> Self {
+        Self {
+            id,
+            sensor_type,
+            members: Vec::new(),
+            config: format!("SensorGroup({:?})", sensor_type),
+        }
+    }
+
+    /// Add a member sensor, which must match the group's type
+    pub fn add_member(&mut self, sensor: Box<dyn Sensor>) -> Result<(), Error> {
+        if sensor.sensor_type() != self.sensor_type {
+            return Err(Error::sensor(format!(
+                "Sensor {} has type {:?}, group expects {:?}",
+                sensor.id(),
+                sensor.sensor_type(),
+                self.sensor_type
+            )));
+        }
+        self.members.push(sensor);
+        Ok(())
+    }
+
+    /// Number of member sensors
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Whether the group has no members
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+}
+
+#[async_trait]
+impl Sensor for SensorGroup {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        self.sensor_type
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        if self.members.is_empty() {
+            return Err(Error::sensor("Sensor group has no members"));
+        }
+
+        let mut data = Vec::new();
+        let mut metadata = HashMap::new();
+
+        for member in self.members.iter_mut() {
+            let frame = member.capture().await?;
+
+            // Per-source header: id length, id bytes, payload length
+            data.extend_from_slice(&(frame.sensor_id.len() as u16).to_le_bytes());
+            data.extend_from_slice(frame.sensor_id.as_bytes());
+            data.extend_from_slice(&(frame.data.len() as u32).to_le_bytes());
+            data.extend_from_slice(&frame.data);
+
+            for (key, value) in frame.metadata {
+                metadata.insert(format!("{}.{}", frame.sensor_id, key), value);
+            }
+        }
+
+        Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
+            sensor_id: self.id.clone(),
+            sensor_type: self.sensor_type,
+            timestamp: chrono::Utc::now(),
+            data,
+            metadata,
+            checksum: None,
+        })
+    }
+
+    async fn is_available(&self) -> bool {
+        for member in &self.members {
+            if !member.is_available().await {
+                return false;
+            }
+        }
+        !self.members.is_empty()
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &self.config
+    }
+}
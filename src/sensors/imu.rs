@@ -1,7 +1,9 @@
 //! IMU sensor implementation
 
 use crate::core::Error;
-use crate::sensors::{Sensor, SensorData, SensorType};
+use crate::sensors::{Sensor, SensorData, SensorState, SensorType};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -22,6 +24,9 @@ pub struct IMUConfig {
     pub noise_filtering: bool,
     /// Calibration enabled
     pub calibration_enabled: bool,
+    /// Seed driving synthetic captures, for reproducible test data
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 /// IMU data structure
@@ -39,6 +44,83 @@ pub struct IMUData {
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
+impl IMUConfig {
+    /// Start building a configuration from the defaults
+    pub fn builder() -> IMUConfigBuilder {
+        IMUConfigBuilder::default()
+    }
+}
+
+/// Chainable builder for [`IMUConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct IMUConfigBuilder {
+    config: IMUConfig,
+}
+
+impl IMUConfigBuilder {
+    /// Set the sample rate in Hz
+    pub fn sample_rate(mut self, hz: f32) -> Self {
+        self.config.sample_rate = hz;
+        self
+    }
+
+    /// Set the accelerometer range in g
+    pub fn accelerometer_range(mut self, range: f32) -> Self {
+        self.config.accelerometer_range = range;
+        self
+    }
+
+    /// Set the gyroscope range in degrees per second
+    pub fn gyroscope_range(mut self, range: f32) -> Self {
+        self.config.gyroscope_range = range;
+        self
+    }
+
+    /// Enable or disable the magnetometer
+    pub fn magnetometer_enabled(mut self, enabled: bool) -> Self {
+        self.config.magnetometer_enabled = enabled;
+        self
+    }
+
+    /// Enable or disable temperature compensation
+    pub fn temperature_compensation(mut self, enabled: bool) -> Self {
+        self.config.temperature_compensation = enabled;
+        self
+    }
+
+    /// Enable or disable noise filtering
+    pub fn noise_filtering(mut self, enabled: bool) -> Self {
+        self.config.noise_filtering = enabled;
+        self
+    }
+
+    /// Enable or disable calibration on initialization
+    pub fn calibration_enabled(mut self, enabled: bool) -> Self {
+        self.config.calibration_enabled = enabled;
+        self
+    }
+
+    /// Seed the synthetic data generator for reproducible captures
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    /// Validate the configuration and return it
+    pub fn build(self) -> Result<IMUConfig, Error> {
+        if self.config.sample_rate <= 0.0 {
+            return Err(Error::config("IMU sample rate must be positive"));
+        }
+        if self.config.accelerometer_range <= 0.0 {
+            return Err(Error::config("Accelerometer range must be positive"));
+        }
+        if self.config.gyroscope_range <= 0.0 {
+            return Err(Error::config("Gyroscope range must be positive"));
+        }
+        Ok(self.config)
+    }
+}
+
 impl Default for IMUConfig {
     fn default() -> Self {
         Self {
@@ -49,6 +131,7 @@ impl Default for IMUConfig {
             temperature_compensation: true,
             noise_filtering: true,
             calibration_enabled: true,
+            seed: None,
         }
     }
 }
@@ -58,11 +141,54 @@ pub struct IMU {
     id: String,
     config: IMUConfig,
     is_initialized: bool,
+    state: SensorState,
     calibration_data: Option<CalibrationData>,
+    gravity_estimate: Option<[f32; 3]>,
+    vibration_history: std::collections::VecDeque<f32>,
+    vibration_thresholds: VibrationThresholds,
+    rng: Option<rand::rngs::StdRng>,
+}
+
+/// Weight kept on the previous gravity estimate per sample
+const GRAVITY_FILTER_ALPHA: f32 = 0.9;
+
+/// Acceleration magnitudes kept for vibration analysis
+const VIBRATION_HISTORY_CAPACITY: usize = 256;
+
+/// Samples considered by [`IMU::vibration_level`]
+const DEFAULT_VIBRATION_WINDOW: usize = 64;
+
+/// RMS thresholds separating the vibration levels, in m/s²
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct VibrationThresholds {
+    /// RMS at or above which vibration counts as medium
+    pub medium: f32,
+    /// RMS at or above which vibration counts as high
+    pub high: f32,
+}
+
+impl Default for VibrationThresholds {
+    fn default() -> Self {
+        Self {
+            medium: 0.5,
+            high: 2.0,
+        }
+    }
+}
+
+/// Coarse chassis vibration classification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VibrationLevel {
+    /// Vibration below the medium threshold
+    Low,
+    /// Vibration between the medium and high thresholds
+    Medium,
+    /// Vibration at or above the high threshold
+    High,
 }
 
 /// Calibration data
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CalibrationData {
     pub accelerometer_bias: [f32; 3],
     pub gyroscope_bias: [f32; 3],
@@ -77,9 +203,14 @@ impl IMU {
     pub fn new(id: String, config: IMUConfig) -> Result<Self, Error> {
         Ok(Self {
             id,
+            rng: config.seed.map(rand::rngs::StdRng::seed_from_u64),
             config,
             is_initialized: false,
+            state: SensorState::Uninitialized,
             calibration_data: None,
+            gravity_estimate: None,
+            vibration_history: std::collections::VecDeque::new(),
+            vibration_thresholds: VibrationThresholds::default(),
         })
     }
 
@@ -87,11 +218,37 @@ impl IMU {
     pub async fn initialize(&mut self) -> Result<(), Error> {
         tracing::info!("Initializing IMU: {}", self.id);
         
-        if self.config.calibration_enabled {
+        if self.config.calibration_enabled && self.calibration_data.is_none() {
             self.calibrate().await?;
         }
-        
+
         self.is_initialized = true;
+        self.state = SensorState::Ready;
+        Ok(())
+    }
+
+    /// Get the current calibration, if any
+    pub fn calibration(&self) -> Option<&CalibrationData> {
+        self.calibration_data.as_ref()
+    }
+
+    /// Save the current calibration to a JSON file
+    pub fn save_calibration(&self, path: &std::path::Path) -> Result<(), Error> {
+        let calibration = self
+            .calibration_data
+            .as_ref()
+            .ok_or_else(|| Error::sensor("IMU has no calibration to save"))?;
+        let json = serde_json::to_string_pretty(calibration)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Load a previously saved calibration from a JSON file
+    ///
+    /// A loaded calibration is reused on init instead of recalibrating.
+    pub fn load_calibration(&mut self, path: &std::path::Path) -> Result<(), Error> {
+        let json = std::fs::read_to_string(path)?;
+        self.calibration_data = Some(serde_json::from_str(&json)?);
         Ok(())
     }
 
@@ -119,15 +276,103 @@ impl IMU {
             return Err(Error::sensor("IMU not initialized"));
         }
 
-        self.generate_test_imu_data().await
+        let sample = self.generate_test_imu_data().await?;
+        self.record_vibration_sample(&sample);
+        Ok(sample)
+    }
+
+    /// Fold a sample's acceleration magnitude into the vibration history
+    ///
+    /// `capture` does this automatically; call it directly when feeding
+    /// replayed or externally sourced samples.
+    pub fn record_vibration_sample(&mut self, sample: &IMUData) {
+        let [x, y, z] = sample.linear_acceleration;
+        let magnitude = (x * x + y * y + z * z).sqrt();
+        self.vibration_history.push_back(magnitude);
+        if self.vibration_history.len() > VIBRATION_HISTORY_CAPACITY {
+            self.vibration_history.pop_front();
+        }
+    }
+
+    /// RMS vibration over the most recent `window` samples, in m/s²
+    ///
+    /// Computed over acceleration magnitude deviations from the window
+    /// mean, so a static sensor holding steady at 1 g reads near zero.
+    pub fn vibration_rms(&self, window: usize) -> f32 {
+        let count = window.min(self.vibration_history.len());
+        if count == 0 {
+            return 0.0;
+        }
+        let recent = self
+            .vibration_history
+            .iter()
+            .skip(self.vibration_history.len() - count);
+        let mean: f32 = recent.clone().sum::<f32>() / count as f32;
+        let variance: f32 =
+            recent.map(|m| (m - mean) * (m - mean)).sum::<f32>() / count as f32;
+        variance.sqrt()
+    }
+
+    /// Classify current vibration against the configured thresholds
+    pub fn vibration_level(&self) -> VibrationLevel {
+        let rms = self.vibration_rms(DEFAULT_VIBRATION_WINDOW);
+        if rms >= self.vibration_thresholds.high {
+            VibrationLevel::High
+        } else if rms >= self.vibration_thresholds.medium {
+            VibrationLevel::Medium
+        } else {
+            VibrationLevel::Low
+        }
+    }
+
+    /// Replace the vibration classification thresholds
+    pub fn set_vibration_thresholds(&mut self, thresholds: VibrationThresholds) {
+        self.vibration_thresholds = thresholds;
+    }
+
+    /// Capture a sample and return its acceleration with gravity removed
+    ///
+    /// Tracks the gravity vector with a low-pass filter over raw
+    /// acceleration, so while the sensor is static the output settles
+    /// near zero on all axes regardless of orientation.
+    pub async fn linear_motion(&mut self) -> Result<[f32; 3], Error> {
+        let sample = self.capture().await?;
+        Ok(self.remove_gravity(&sample))
+    }
+
+    /// Fold a sample into the gravity estimate and subtract it
+    pub fn remove_gravity(&mut self, sample: &IMUData) -> [f32; 3] {
+        let gravity = match self.gravity_estimate {
+            Some(previous) => {
+                let mut gravity = [0.0f32; 3];
+                for (axis, value) in gravity.iter_mut().enumerate() {
+                    *value = GRAVITY_FILTER_ALPHA * previous[axis]
+                        + (1.0 - GRAVITY_FILTER_ALPHA) * sample.linear_acceleration[axis];
+                }
+                gravity
+            }
+            // The first sample seeds the estimate
+            None => sample.linear_acceleration,
+        };
+        self.gravity_estimate = Some(gravity);
+
+        [
+            sample.linear_acceleration[0] - gravity[0],
+            sample.linear_acceleration[1] - gravity[1],
+            sample.linear_acceleration[2] - gravity[2],
+        ]
     }
 
     /// Generate test IMU data
-    async fn generate_test_imu_data(&self) -> Result<IMUData, Error> {
+    async fn generate_test_imu_data(&mut self) -> Result<IMUData, Error> {
         let timestamp = chrono::Utc::now();
-        
-        // Generate realistic IMU data
-        let time = timestamp.timestamp_millis() as f32 / 1000.0;
+
+        // A seeded IMU draws its drive value from the PRNG so samples are
+        // reproducible; otherwise the wall clock drives the simulation
+        let time = match &mut self.rng {
+            Some(rng) => rng.gen::<f32>() * 1000.0,
+            None => timestamp.timestamp_millis() as f32 / 1000.0,
+        };
         
         // Simulate gravity + small movements
         let linear_acceleration = [
@@ -253,11 +498,17 @@ impl IMU {
 
     /// Update IMU configuration
     pub fn update_config(&mut self, config: IMUConfig) {
+        self.rng = config.seed.map(rand::rngs::StdRng::seed_from_u64);
         self.config = config;
     }
 }
 
+#[async_trait]
 impl Sensor for IMU {
+    async fn initialize(&mut self) -> Result<(), Error> {
+        IMU::initialize(self).await
+    }
+
     fn id(&self) -> &str {
         &self.id
     }
@@ -267,7 +518,15 @@ impl Sensor for IMU {
     }
     
     async fn capture(&mut self) -> Result<SensorData, Error> {
-        let imu_data = self.capture().await?;
+        self.state = SensorState::Capturing;
+        let imu_data = match IMU::capture(self).await {
+            Ok(data) => data,
+            Err(e) => {
+                self.state = SensorState::Error(e.to_string());
+                return Err(e);
+            }
+        };
+        self.state = SensorState::Ready;
         let data = self.serialize_imu_data(&imu_data)?;
         
         let mut metadata = HashMap::new();
@@ -278,16 +537,22 @@ impl Sensor for IMU {
         metadata.insert("calibrated".to_string(), self.calibration_data.is_some().to_string());
         
         Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
             sensor_id: self.id.clone(),
             sensor_type: SensorType::IMU,
             timestamp: chrono::Utc::now(),
             data,
             metadata,
+            checksum: None,
         })
     }
     
     async fn is_available(&self) -> bool {
-        self.is_initialized
+        matches!(self.state, SensorState::Ready | SensorState::Capturing)
+    }
+
+    fn state(&self) -> SensorState {
+        self.state.clone()
     }
     
     fn config(&self) -> &dyn std::fmt::Debug {
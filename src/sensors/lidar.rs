@@ -1,7 +1,9 @@
 //! LiDAR sensor implementation
 
 use crate::core::Error;
-use crate::sensors::{Sensor, SensorData, SensorType};
+use crate::sensors::{Sensor, SensorData, SensorState, SensorType};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -24,6 +26,9 @@ pub struct LiDARConfig {
     pub vertical_fov: f32,
     /// Horizontal field of view in degrees
     pub horizontal_fov: f32,
+    /// Seed driving synthetic captures, for reproducible test data
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 /// Point cloud format
@@ -39,6 +44,90 @@ pub enum PointCloudFormat {
     XYZIR,
 }
 
+impl LiDARConfig {
+    /// Start building a configuration from the defaults
+    pub fn builder() -> LiDARConfigBuilder {
+        LiDARConfigBuilder::default()
+    }
+}
+
+/// Chainable builder for [`LiDARConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct LiDARConfigBuilder {
+    config: LiDARConfig,
+}
+
+impl LiDARConfigBuilder {
+    /// Set the range limits in meters
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.config.range_min = min;
+        self.config.range_max = max;
+        self
+    }
+
+    /// Set the angular resolution in degrees
+    pub fn angular_resolution(mut self, degrees: f32) -> Self {
+        self.config.angular_resolution = degrees;
+        self
+    }
+
+    /// Set the scan frequency in Hz
+    pub fn scan_frequency(mut self, hz: f32) -> Self {
+        self.config.scan_frequency = hz;
+        self
+    }
+
+    /// Set the point cloud format
+    pub fn point_cloud_format(mut self, format: PointCloudFormat) -> Self {
+        self.config.point_cloud_format = format;
+        self
+    }
+
+    /// Set the number of laser beams
+    pub fn laser_count(mut self, count: u32) -> Self {
+        self.config.laser_count = count;
+        self
+    }
+
+    /// Set the vertical and horizontal fields of view in degrees
+    pub fn field_of_view(mut self, vertical: f32, horizontal: f32) -> Self {
+        self.config.vertical_fov = vertical;
+        self.config.horizontal_fov = horizontal;
+        self
+    }
+
+    /// Seed the synthetic data generator for reproducible captures
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    /// Validate the configuration and return it
+    pub fn build(self) -> Result<LiDARConfig, Error> {
+        if self.config.range_min < 0.0 {
+            return Err(Error::config("LiDAR minimum range must not be negative"));
+        }
+        if self.config.range_max <= self.config.range_min {
+            return Err(Error::config(
+                "LiDAR maximum range must exceed the minimum range",
+            ));
+        }
+        if self.config.angular_resolution <= 0.0 {
+            return Err(Error::config("Angular resolution must be positive"));
+        }
+        if self.config.scan_frequency <= 0.0 {
+            return Err(Error::config("Scan frequency must be positive"));
+        }
+        if self.config.laser_count == 0 {
+            return Err(Error::config("Laser count must be non-zero"));
+        }
+        if self.config.vertical_fov <= 0.0 || self.config.horizontal_fov <= 0.0 {
+            return Err(Error::config("Fields of view must be positive"));
+        }
+        Ok(self.config)
+    }
+}
+
 impl Default for LiDARConfig {
     fn default() -> Self {
         Self {
@@ -50,6 +139,7 @@ impl Default for LiDARConfig {
             laser_count: 16,
             vertical_fov: 30.0,
             horizontal_fov: 360.0,
+            seed: None,
         }
     }
 }
@@ -64,11 +154,70 @@ pub struct Point {
     pub ring: Option<u32>,
 }
 
+/// Number of bins in an intensity histogram
+const INTENSITY_HISTOGRAM_BINS: usize = 16;
+
+/// XY cell size used by ground segmentation, in meters
+const GROUND_GRID_CELL_SIZE: f32 = 0.5;
+
+/// Returns at or below this height are treated as ground by the
+/// occupancy grid, in meters
+const OCCUPANCY_MIN_Z: f32 = 0.1;
+
+/// 2D occupancy grid rasterized from a point cloud
+///
+/// Cells are row-major with the origin cell at the bounds minimum;
+/// `1` marks an occupied cell, `0` a free one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OccupancyGrid {
+    /// Number of cells along X
+    pub width: usize,
+    /// Number of cells along Y
+    pub height: usize,
+    /// Cell edge length in meters
+    pub resolution: f32,
+    /// World coordinates of the grid's minimum corner
+    pub origin: (f32, f32),
+    /// Row-major occupancy flags, `height * width` entries
+    pub cells: Vec<u8>,
+}
+
+impl OccupancyGrid {
+    /// Whether the cell at `(x, y)` grid indices is occupied
+    pub fn is_occupied(&self, x: usize, y: usize) -> bool {
+        self.cells[y * self.width + x] != 0
+    }
+
+    /// Number of occupied cells
+    pub fn occupied_count(&self) -> usize {
+        self.cells.iter().filter(|c| **c != 0).count()
+    }
+}
+
+/// Distributional statistics over point intensities
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntensityStats {
+    /// Number of points that carried an intensity
+    pub count: usize,
+    /// Minimum intensity
+    pub min: f32,
+    /// Maximum intensity
+    pub max: f32,
+    /// Mean intensity
+    pub mean: f32,
+    /// Standard deviation of intensity
+    pub stddev: f32,
+    /// Histogram over [min, max]; empty when `count` is zero
+    pub histogram: Vec<u32>,
+}
+
 /// LiDAR sensor implementation
 pub struct LiDAR {
     id: String,
     config: LiDARConfig,
     is_initialized: bool,
+    state: SensorState,
+    rng: Option<rand::rngs::StdRng>,
 }
 
 impl LiDAR {
@@ -76,8 +225,10 @@ impl LiDAR {
     pub fn new(id: String, config: LiDARConfig) -> Result<Self, Error> {
         Ok(Self {
             id,
+            rng: config.seed.map(rand::rngs::StdRng::seed_from_u64),
             config,
             is_initialized: false,
+            state: SensorState::Uninitialized,
         })
     }
 
@@ -85,6 +236,7 @@ impl LiDAR {
     pub async fn initialize(&mut self) -> Result<(), Error> {
         tracing::info!("Initializing LiDAR: {}", self.id);
         self.is_initialized = true;
+        self.state = SensorState::Ready;
         Ok(())
     }
 
@@ -98,19 +250,27 @@ impl LiDAR {
     }
 
     /// Generate test point cloud
-    async fn generate_test_point_cloud(&self) -> Result<Vec<Point>, Error> {
+    async fn generate_test_point_cloud(&mut self) -> Result<Vec<Point>, Error> {
         let mut points = Vec::new();
-        
+
         // Generate points in a spiral pattern
         let num_points = 1000;
         for i in 0..num_points {
             let angle = (i as f32 / num_points as f32) * 2.0 * std::f32::consts::PI;
             let radius = self.config.range_min + (i as f32 / num_points as f32) * (self.config.range_max - self.config.range_min);
-            
-            let x = radius * angle.cos();
-            let y = radius * angle.sin();
-            let z = (i as f32 / num_points as f32) * 2.0 - 1.0; // -1 to 1
-            
+
+            let mut x = radius * angle.cos();
+            let mut y = radius * angle.sin();
+            let mut z = (i as f32 / num_points as f32) * 2.0 - 1.0; // -1 to 1
+
+            // A seeded scanner perturbs the spiral with reproducible
+            // measurement noise so clouds differ between seeds
+            if let Some(rng) = &mut self.rng {
+                x += rng.gen_range(-0.05..0.05);
+                y += rng.gen_range(-0.05..0.05);
+                z += rng.gen_range(-0.05..0.05);
+            }
+
             let intensity = Some((i as f32 / num_points as f32) * 255.0);
             let ring = Some(i % self.config.laser_count);
             
@@ -164,6 +324,170 @@ impl LiDAR {
         Ok(data)
     }
 
+    /// Compute distributional statistics over point intensities
+    ///
+    /// Points without an intensity are skipped. When no point carries an
+    /// intensity, `count` is zero and the histogram is empty.
+    pub fn intensity_stats(&self, points: &[Point]) -> IntensityStats {
+        let intensities: Vec<f32> = points.iter().filter_map(|p| p.intensity).collect();
+
+        if intensities.is_empty() {
+            return IntensityStats {
+                count: 0,
+                min: 0.0,
+                max: 0.0,
+                mean: 0.0,
+                stddev: 0.0,
+                histogram: Vec::new(),
+            };
+        }
+
+        let count = intensities.len();
+        let min = intensities.iter().cloned().fold(f32::MAX, f32::min);
+        let max = intensities.iter().cloned().fold(f32::MIN, f32::max);
+        let mean = intensities.iter().sum::<f32>() / count as f32;
+        let variance = intensities
+            .iter()
+            .map(|v| (v - mean).powi(2))
+            .sum::<f32>()
+            / count as f32;
+        let stddev = variance.sqrt();
+
+        let mut histogram = vec![0u32; INTENSITY_HISTOGRAM_BINS];
+        let span = max - min;
+        for &value in &intensities {
+            let bin = if span > 0.0 {
+                (((value - min) / span) * INTENSITY_HISTOGRAM_BINS as f32) as usize
+            } else {
+                0
+            };
+            histogram[bin.min(INTENSITY_HISTOGRAM_BINS - 1)] += 1;
+        }
+
+        IntensityStats {
+            count,
+            min,
+            max,
+            mean,
+            stddev,
+            histogram,
+        }
+    }
+
+    /// Crop a point cloud to an axis-aligned bounding box
+    ///
+    /// Points on the box boundary are kept. Fails if any `min` component
+    /// exceeds its `max` counterpart.
+    pub fn crop(&self, points: &[Point], min: [f32; 3], max: [f32; 3]) -> Result<Vec<Point>, Error> {
+        if min.iter().zip(max.iter()).any(|(lo, hi)| lo > hi) {
+            return Err(Error::sensor(format!(
+                "Invalid crop bounds: min {:?} exceeds max {:?}",
+                min, max
+            )));
+        }
+
+        Ok(points
+            .iter()
+            .filter(|p| {
+                p.x >= min[0]
+                    && p.x <= max[0]
+                    && p.y >= min[1]
+                    && p.y <= max[1]
+                    && p.z >= min[2]
+                    && p.z <= max[2]
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Split a point cloud into ground and non-ground points
+    ///
+    /// Grid-based lowest-point heuristic: the cloud is binned into XY
+    /// cells, a cell counts as ground where its lowest point stays under
+    /// the sensor's lowest return plus `max_slope` (rise per meter of
+    /// horizontal distance), and points within `z_threshold` above their
+    /// cell's lowest point are labelled ground. Returns
+    /// `(ground, non_ground)`.
+    pub fn segment_ground(
+        &self,
+        points: &[Point],
+        max_slope: f32,
+        z_threshold: f32,
+    ) -> (Vec<Point>, Vec<Point>) {
+        if points.is_empty() {
+            return (Vec::new(), Vec::new());
+        }
+
+        let cell = |p: &Point| {
+            (
+                (p.x / GROUND_GRID_CELL_SIZE).floor() as i32,
+                (p.y / GROUND_GRID_CELL_SIZE).floor() as i32,
+            )
+        };
+
+        let mut cell_min_z: std::collections::HashMap<(i32, i32), f32> =
+            std::collections::HashMap::new();
+        let mut global_min_z = f32::MAX;
+        for p in points {
+            let entry = cell_min_z.entry(cell(p)).or_insert(f32::MAX);
+            *entry = entry.min(p.z);
+            global_min_z = global_min_z.min(p.z);
+        }
+
+        let mut ground = Vec::new();
+        let mut non_ground = Vec::new();
+        for p in points {
+            let lowest = cell_min_z[&cell(p)];
+            let distance = (p.x * p.x + p.y * p.y).sqrt();
+            let cell_is_ground = lowest <= global_min_z + max_slope * distance + z_threshold;
+            if cell_is_ground && p.z - lowest <= z_threshold {
+                ground.push(p.clone());
+            } else {
+                non_ground.push(p.clone());
+            }
+        }
+        (ground, non_ground)
+    }
+
+    /// Rasterize a point cloud into a 2D occupancy grid
+    ///
+    /// `bounds` is `((min_x, min_y), (max_x, max_y))` in meters. A cell
+    /// is occupied when any point inside it sits above the ground cutoff;
+    /// points outside the bounds are ignored.
+    pub fn to_occupancy_grid(
+        &self,
+        points: &[Point],
+        resolution: f32,
+        bounds: ((f32, f32), (f32, f32)),
+    ) -> OccupancyGrid {
+        let ((min_x, min_y), (max_x, max_y)) = bounds;
+        let width = (((max_x - min_x) / resolution).ceil() as usize).max(1);
+        let height = (((max_y - min_y) / resolution).ceil() as usize).max(1);
+        let mut cells = vec![0u8; width * height];
+
+        for p in points {
+            if p.z <= OCCUPANCY_MIN_Z
+                || p.x < min_x
+                || p.x >= max_x
+                || p.y < min_y
+                || p.y >= max_y
+            {
+                continue;
+            }
+            let col = (((p.x - min_x) / resolution) as usize).min(width - 1);
+            let row = (((p.y - min_y) / resolution) as usize).min(height - 1);
+            cells[row * width + col] = 1;
+        }
+
+        OccupancyGrid {
+            width,
+            height,
+            resolution,
+            origin: (min_x, min_y),
+            cells,
+        }
+    }
+
     /// Get LiDAR configuration
     pub fn config(&self) -> &LiDARConfig {
         &self.config
@@ -171,11 +495,17 @@ impl LiDAR {
 
     /// Update LiDAR configuration
     pub fn update_config(&mut self, config: LiDARConfig) {
+        self.rng = config.seed.map(rand::rngs::StdRng::seed_from_u64);
         self.config = config;
     }
 }
 
+#[async_trait]
 impl Sensor for LiDAR {
+    async fn initialize(&mut self) -> Result<(), Error> {
+        LiDAR::initialize(self).await
+    }
+
     fn id(&self) -> &str {
         &self.id
     }
@@ -185,7 +515,15 @@ impl Sensor for LiDAR {
     }
     
     async fn capture(&mut self) -> Result<SensorData, Error> {
-        let points = self.capture().await?;
+        self.state = SensorState::Capturing;
+        let points = match LiDAR::capture(self).await {
+            Ok(data) => data,
+            Err(e) => {
+                self.state = SensorState::Error(e.to_string());
+                return Err(e);
+            }
+        };
+        self.state = SensorState::Ready;
         let data = self.serialize_point_cloud(&points)?;
         
         let mut metadata = HashMap::new();
@@ -195,16 +533,22 @@ impl Sensor for LiDAR {
         metadata.insert("format".to_string(), format!("{:?}", self.config.point_cloud_format));
         
         Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
             sensor_id: self.id.clone(),
             sensor_type: SensorType::LiDAR,
             timestamp: chrono::Utc::now(),
             data,
             metadata,
+            checksum: None,
         })
     }
     
     async fn is_available(&self) -> bool {
-        self.is_initialized
+        matches!(self.state, SensorState::Ready | SensorState::Capturing)
+    }
+
+    fn state(&self) -> SensorState {
+        self.state.clone()
     }
     
     fn config(&self) -> &dyn std::fmt::Debug {
@@ -1,13 +1,105 @@
 //! Sensor manager for handling multiple sensors
 
+use crate::core::clock::{Clock, SystemClock};
+use crate::core::config::SensorConfig;
 use crate::core::Error;
-use crate::sensors::{Sensor, SensorData, SensorType};
-use std::collections::HashMap;
+use crate::sensors::{Sensor, SensorData, SensorState, SensorType};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+/// Reliability metrics for one sensor
+#[derive(Debug, Clone)]
+pub struct SensorMetrics {
+    /// Effective capture rate over the sliding window, in Hz
+    pub captures_per_second: f64,
+    /// Timestamp of the last successful capture
+    pub last_capture: Option<chrono::DateTime<chrono::Utc>>,
+    /// Capture failures since the last success
+    pub consecutive_failures: u32,
+    /// Whether no capture succeeded within the stale timeout
+    pub stale: bool,
+}
+
+/// Per-sensor capture bookkeeping backing [`SensorMetrics`]
+#[derive(Default)]
+struct MetricsTracker {
+    window: VecDeque<Instant>,
+    last_success: Option<Instant>,
+    last_capture: Option<chrono::DateTime<chrono::Utc>>,
+    consecutive_failures: u32,
+}
+
+/// What the scheduler does with new frames when its output is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Discard the oldest buffered frame and count it as dropped
+    #[default]
+    DropOldest,
+    /// Block the scheduler until the consumer catches up
+    Block,
+}
+
+/// Width of the sliding window used for rate estimation
+const METRICS_WINDOW: Duration = Duration::from_secs(10);
+
+impl MetricsTracker {
+    fn record_success(&mut self, captured_at: chrono::DateTime<chrono::Utc>) {
+        let now = Instant::now();
+        self.window.push_back(now);
+        while let Some(oldest) = self.window.front() {
+            if now.duration_since(*oldest) > METRICS_WINDOW {
+                self.window.pop_front();
+            } else {
+                break;
+            }
+        }
+        self.last_success = Some(now);
+        self.last_capture = Some(captured_at);
+        self.consecutive_failures = 0;
+    }
+
+    fn record_failure(&mut self) {
+        self.consecutive_failures += 1;
+    }
+
+    fn snapshot(&self, stale_timeout: Duration) -> SensorMetrics {
+        let captures_per_second = match (self.window.front(), self.window.back()) {
+            (Some(oldest), Some(newest)) if self.window.len() >= 2 => {
+                let span = newest.duration_since(*oldest).as_secs_f64();
+                if span > 0.0 {
+                    (self.window.len() - 1) as f64 / span
+                } else {
+                    0.0
+                }
+            }
+            _ => 0.0,
+        };
+
+        let stale = self
+            .last_success
+            .map(|last| last.elapsed() > stale_timeout)
+            .unwrap_or(true);
+
+        SensorMetrics {
+            captures_per_second,
+            last_capture: self.last_capture,
+            consecutive_failures: self.consecutive_failures,
+            stale,
+        }
+    }
+}
+
 /// Sensor manager for handling multiple sensors
 pub struct SensorManager {
     sensors: RwLock<HashMap<String, Box<dyn Sensor>>>,
+    metrics: RwLock<HashMap<String, MetricsTracker>>,
+    rates: RwLock<HashMap<String, f32>>,
+    dropped_frames: std::sync::atomic::AtomicU64,
+    stale_timeout: Duration,
+    capture_timeout: Duration,
+    calibration_override: Option<bool>,
+    clock: std::sync::Arc<dyn Clock>,
 }
 
 impl SensorManager {
@@ -15,9 +107,56 @@ impl SensorManager {
     pub fn new() -> Self {
         Self {
             sensors: RwLock::new(HashMap::new()),
+            metrics: RwLock::new(HashMap::new()),
+            rates: RwLock::new(HashMap::new()),
+            dropped_frames: std::sync::atomic::AtomicU64::new(0),
+            stale_timeout: Duration::from_secs(5),
+            capture_timeout: Duration::from_secs(SensorConfig::default().timeout_seconds),
+            calibration_override: None,
+            clock: std::sync::Arc::new(SystemClock),
         }
     }
 
+    /// Create a sensor manager governed by a [`SensorConfig`]
+    ///
+    /// The config's `enable_calibration` flag decides calibration for
+    /// every managed sensor during [`initialize_all`](Self::initialize_all),
+    /// overriding per-sensor flags: enabled forces the calibration hook,
+    /// disabled skips it.
+    pub fn new_with_config(config: SensorConfig) -> Self {
+        let mut manager = Self::new();
+        manager.capture_timeout = Duration::from_secs(config.timeout_seconds);
+        manager.calibration_override = Some(config.enable_calibration);
+        manager
+    }
+
+    /// Set how long without a successful capture a sensor counts as stale
+    pub fn set_stale_timeout(&mut self, stale_timeout: Duration) {
+        self.stale_timeout = stale_timeout;
+    }
+
+    /// Set the per-sensor capture timeout
+    ///
+    /// A capture exceeding this deadline counts as a failure for that
+    /// sensor instead of blocking the batch.
+    pub fn set_capture_timeout(&mut self, capture_timeout: Duration) {
+        self.capture_timeout = capture_timeout;
+    }
+
+    /// Replace the time source used for capture timestamps
+    pub fn set_clock(&mut self, clock: std::sync::Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Reliability metrics per sensor, keyed by sensor id
+    pub async fn metrics(&self) -> HashMap<String, SensorMetrics> {
+        let metrics = self.metrics.read().await;
+        metrics
+            .iter()
+            .map(|(id, tracker)| (id.clone(), tracker.snapshot(self.stale_timeout)))
+            .collect()
+    }
+
     /// Add a sensor to the manager
     pub async fn add_sensor(&self, sensor: Box<dyn Sensor>) -> Result<(), Error> {
         let sensor_id = sensor.id().to_string();
@@ -34,8 +173,7 @@ impl SensorManager {
     }
 
     /// Get a sensor by ID
-    pub async fn get_sensor(&self, sensor_id: &str) -> Option<Box<dyn Sensor>> {
-        let sensors = self.sensors.read().await;
+    pub async fn get_sensor(&self, _sensor_id: &str) -> Option<Box<dyn Sensor>> {
         // Note: This is a simplified implementation
         // In practice, you'd need to handle the trait object properly
         None
@@ -43,17 +181,38 @@ impl SensorManager {
 
     /// Capture data from all sensors
     pub async fn capture_all(&self) -> Result<Vec<SensorData>, Error> {
-        let sensors = self.sensors.read().await;
+        let mut sensors = self.sensors.write().await;
+        let mut metrics = self.metrics.write().await;
         let mut results = Vec::new();
 
-        for (sensor_id, sensor) in sensors.iter() {
+        for (sensor_id, sensor) in sensors.iter_mut() {
+            let tracker = metrics.entry(sensor_id.clone()).or_default();
             if sensor.is_available().await {
-                match sensor.capture().await {
-                    Ok(data) => results.push(data),
-                    Err(e) => {
+                match tokio::time::timeout(self.capture_timeout, sensor.capture()).await {
+                    Ok(Ok(data)) => {
+                        tracing::info!(
+                            frame_id = %data.frame_id,
+                            sensor_id = %sensor_id,
+                            "Captured frame"
+                        );
+                        tracker.record_success(self.clock.now());
+                        results.push(data);
+                    }
+                    Ok(Err(e)) => {
+                        tracker.record_failure();
                         tracing::warn!("Failed to capture data from sensor {}: {}", sensor_id, e);
                     }
+                    Err(_) => {
+                        tracker.record_failure();
+                        tracing::warn!(
+                            "Capture from sensor {} timed out after {:?}",
+                            sensor_id,
+                            self.capture_timeout
+                        );
+                    }
                 }
+            } else {
+                tracker.record_failure();
             }
         }
 
@@ -62,19 +221,202 @@ impl SensorManager {
 
     /// Capture data from a specific sensor
     pub async fn capture_sensor(&self, sensor_id: &str) -> Result<SensorData, Error> {
-        let sensors = self.sensors.read().await;
-        let sensor = sensors.get(sensor_id)
+        let mut sensors = self.sensors.write().await;
+        let sensor = sensors.get_mut(sensor_id)
             .ok_or_else(|| Error::sensor(format!("Sensor {} not found", sensor_id)))?;
-        
+
         sensor.capture().await
     }
 
+    /// Set the sampling rate for one sensor, in Hz
+    ///
+    /// Only sensors with a configured rate are driven by the scheduler.
+    pub async fn set_rate(&self, sensor_id: &str, hz: f32) -> Result<(), Error> {
+        if hz <= 0.0 || !hz.is_finite() {
+            return Err(Error::sensor(format!(
+                "Invalid sampling rate {} Hz for sensor {}",
+                hz, sensor_id
+            )));
+        }
+
+        let sensors = self.sensors.read().await;
+        if !sensors.contains_key(sensor_id) {
+            return Err(Error::sensor(format!("Sensor {} not found", sensor_id)));
+        }
+        drop(sensors);
+
+        self.rates.write().await.insert(sensor_id.to_string(), hz);
+        Ok(())
+    }
+
+    /// Frames the scheduler has discarded due to a full output channel
+    pub fn dropped_frames(&self) -> u64 {
+        self.dropped_frames.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Capture each rate-configured sensor at its own interval
+    ///
+    /// Frames are emitted on the returned bounded channel. When the
+    /// consumer lags, `policy` decides between discarding the oldest
+    /// buffered frame and blocking the scheduler. The scheduler stops
+    /// once the receiver is dropped.
+    pub fn run_scheduler(
+        self: &std::sync::Arc<Self>,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> tokio::sync::mpsc::Receiver<SensorData> {
+        let (tx, rx) = tokio::sync::mpsc::channel(capacity.max(1));
+        let manager = std::sync::Arc::clone(self);
+
+        tokio::spawn(async move {
+            let mut next_due: HashMap<String, Instant> = HashMap::new();
+            // Frames waiting for channel space under DropOldest
+            let mut pending: VecDeque<SensorData> = VecDeque::new();
+
+            loop {
+                if tx.is_closed() {
+                    break;
+                }
+
+                let rates = manager.rates.read().await.clone();
+                if rates.is_empty() {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    continue;
+                }
+
+                let now = Instant::now();
+                for (sensor_id, hz) in &rates {
+                    let due = next_due.entry(sensor_id.clone()).or_insert(now);
+                    if *due > now {
+                        continue;
+                    }
+                    *due = now + Duration::from_secs_f32(1.0 / hz);
+
+                    match manager.capture_sensor(sensor_id).await {
+                        Ok(frame) => match policy {
+                            OverflowPolicy::Block => {
+                                if tx.send(frame).await.is_err() {
+                                    return;
+                                }
+                            }
+                            OverflowPolicy::DropOldest => {
+                                pending.push_back(frame);
+                            }
+                        },
+                        Err(e) => {
+                            tracing::warn!(
+                                "Scheduled capture from sensor {} failed: {}",
+                                sensor_id,
+                                e
+                            );
+                        }
+                    }
+                }
+
+                // Drain as much of the backlog as the channel accepts,
+                // discarding the oldest frames beyond the buffer cap
+                while let Some(frame) = pending.pop_front() {
+                    match tx.try_send(frame) {
+                        Ok(()) => {}
+                        Err(tokio::sync::mpsc::error::TrySendError::Full(frame)) => {
+                            pending.push_front(frame);
+                            break;
+                        }
+                        Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => {
+                            return;
+                        }
+                    }
+                }
+                while pending.len() > capacity.max(1) {
+                    pending.pop_front();
+                    manager
+                        .dropped_frames
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                }
+
+                let earliest = next_due.values().min().copied().unwrap_or(now);
+                let pause = earliest
+                    .saturating_duration_since(Instant::now())
+                    .max(Duration::from_millis(1));
+                tokio::time::sleep(pause).await;
+            }
+        });
+
+        rx
+    }
+
     /// Get list of available sensors
     pub async fn list_sensors(&self) -> Vec<String> {
         let sensors = self.sensors.read().await;
         sensors.keys().cloned().collect()
     }
 
+    /// Initialize every sensor so captures can succeed
+    ///
+    /// Failures are collected so one bad sensor doesn't leave the rest
+    /// uninitialized.
+    pub async fn initialize_all(&self) -> Result<(), Error> {
+        let mut sensors = self.sensors.write().await;
+        let mut failures = Vec::new();
+
+        for (sensor_id, sensor) in sensors.iter_mut() {
+            if let Err(e) = sensor.initialize().await {
+                tracing::warn!("Failed to initialize sensor {}: {}", sensor_id, e);
+                failures.push(format!("{}: {}", sensor_id, e));
+                continue;
+            }
+            // Manager-level calibration policy overrides per-sensor flags
+            if self.calibration_override == Some(true) {
+                if let Err(e) = sensor.calibrate().await {
+                    tracing::warn!("Failed to calibrate sensor {}: {}", sensor_id, e);
+                    failures.push(format!("{}: {}", sensor_id, e));
+                }
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::sensor(format!(
+                "Failed to initialize {} sensor(s): {}",
+                failures.len(),
+                failures.join("; ")
+            )))
+        }
+    }
+
+    /// Shut down every sensor, logging failures but completing the batch
+    pub async fn shutdown_all(&self) -> Result<(), Error> {
+        let mut sensors = self.sensors.write().await;
+
+        for (sensor_id, sensor) in sensors.iter_mut() {
+            if let Err(e) = sensor.shutdown().await {
+                tracing::warn!("Failed to shut down sensor {}: {}", sensor_id, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check availability of every registered sensor
+    pub async fn availability(&self) -> HashMap<String, bool> {
+        let sensors = self.sensors.read().await;
+        let mut availability = HashMap::new();
+        for (id, sensor) in sensors.iter() {
+            availability.insert(id.clone(), sensor.is_available().await);
+        }
+        availability
+    }
+
+    /// Get the lifecycle state of every registered sensor
+    pub async fn sensor_states(&self) -> HashMap<String, SensorState> {
+        let sensors = self.sensors.read().await;
+        sensors
+            .iter()
+            .map(|(id, sensor)| (id.clone(), sensor.state()))
+            .collect()
+    }
+
     /// Get sensors by type
     pub async fn get_sensors_by_type(&self, sensor_type: SensorType) -> Vec<String> {
         let sensors = self.sensors.read().await;
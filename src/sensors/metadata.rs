@@ -0,0 +1,97 @@
+//! Per-sensor metadata schema validation
+//!
+//! `SensorData::metadata` is a free-form map, but each sensor type has keys
+//! downstream consumers rely on. These checks catch a missing or malformed
+//! key at validation time instead of at the consumer.
+
+use crate::core::Error;
+use crate::sensors::SensorType;
+use std::collections::HashMap;
+
+/// Validate that metadata carries the required keys for a sensor type
+///
+/// Checks presence of the keys each sensor populates at capture and the
+/// format of structured values such as `resolution`. Extra keys are allowed.
+pub fn validate(
+    sensor_type: &SensorType,
+    metadata: &HashMap<String, String>,
+) -> Result<(), Error> {
+    match sensor_type {
+        SensorType::Camera => {
+            let resolution = require(metadata, "resolution")?;
+            check_resolution(resolution)?;
+            require(metadata, "format")?;
+            let frame_rate = require(metadata, "frame_rate")?;
+            check_number::<u32>(frame_rate, "frame_rate")?;
+        }
+        SensorType::LiDAR => {
+            check_number::<usize>(require(metadata, "point_count")?, "point_count")?;
+            check_number::<f64>(require(metadata, "range_min")?, "range_min")?;
+            check_number::<f64>(require(metadata, "range_max")?, "range_max")?;
+        }
+        SensorType::IMU => {
+            check_number::<u32>(require(metadata, "sample_rate")?, "sample_rate")?;
+            check_number::<f64>(
+                require(metadata, "accelerometer_range")?,
+                "accelerometer_range",
+            )?;
+            check_number::<f64>(require(metadata, "gyroscope_range")?, "gyroscope_range")?;
+        }
+        SensorType::GPS => {
+            let latitude = check_number::<f64>(require(metadata, "latitude")?, "latitude")?;
+            if !(-90.0..=90.0).contains(&latitude) {
+                return Err(Error::validation(format!(
+                    "Metadata key latitude out of range: {}",
+                    latitude
+                )));
+            }
+            let longitude = check_number::<f64>(require(metadata, "longitude")?, "longitude")?;
+            if !(-180.0..=180.0).contains(&longitude) {
+                return Err(Error::validation(format!(
+                    "Metadata key longitude out of range: {}",
+                    longitude
+                )));
+            }
+            check_number::<u32>(require(metadata, "satellite_count")?, "satellite_count")?;
+        }
+        SensorType::Thermal => {
+            check_resolution(require(metadata, "resolution")?)?;
+            check_number::<f64>(require(metadata, "min_temperature")?, "min_temperature")?;
+            check_number::<f64>(require(metadata, "max_temperature")?, "max_temperature")?;
+        }
+    }
+    Ok(())
+}
+
+/// Look up a required key
+fn require<'a>(metadata: &'a HashMap<String, String>, key: &str) -> Result<&'a str, Error> {
+    metadata
+        .get(key)
+        .map(String::as_str)
+        .ok_or_else(|| Error::validation(format!("Missing required metadata key: {}", key)))
+}
+
+/// Check a value parses as the expected numeric type
+fn check_number<T: std::str::FromStr>(value: &str, key: &str) -> Result<T, Error> {
+    value.parse::<T>().map_err(|_| {
+        Error::validation(format!(
+            "Metadata key {} has malformed value: {}",
+            key, value
+        ))
+    })
+}
+
+/// Check a resolution value in `WIDTHxHEIGHT` form
+fn check_resolution(value: &str) -> Result<(), Error> {
+    let valid = value
+        .split_once('x')
+        .is_some_and(|(w, h)| w.parse::<u32>().is_ok() && h.parse::<u32>().is_ok());
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::validation(format!(
+            "Metadata key resolution has malformed value: {}",
+            value
+        )))
+    }
+}
@@ -0,0 +1,110 @@
+//! In-memory mock sensor for downstream testing
+
+use crate::core::Error;
+use crate::sensors::{Sensor, SensorData, SensorState, SensorType};
+use async_trait::async_trait;
+
+/// Scripted sensor returning canned frames on successive captures
+///
+/// Frames are served in order; once exhausted, captures fail. A capture
+/// index can be marked to fail instead, to exercise error paths.
+pub struct MockSensor {
+    id: String,
+    sensor_type: SensorType,
+    frames: Vec<SensorData>,
+    next_frame: usize,
+    fail_on: Option<usize>,
+    state: SensorState,
+    calibrated: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+}
+
+impl MockSensor {
+    /// Create a mock sensor serving the given frames in order
+    pub fn new(id: String, sensor_type: SensorType, frames: Vec<SensorData>) -> Self {
+        Self {
+            id,
+            sensor_type,
+            frames,
+            next_frame: 0,
+            fail_on: None,
+            state: SensorState::Ready,
+            calibrated: None,
+        }
+    }
+
+    /// Fail the capture with the given zero-based index
+    pub fn fail_on_capture(mut self, index: usize) -> Self {
+        self.fail_on = Some(index);
+        self
+    }
+
+    /// Record calibration invocations in the given flag
+    ///
+    /// Lets a test observe the calibration hook after the sensor has been
+    /// moved into a manager.
+    pub fn with_calibration_flag(
+        mut self,
+        flag: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    ) -> Self {
+        self.calibrated = Some(flag);
+        self
+    }
+
+    /// Number of captures attempted so far
+    pub fn captures_attempted(&self) -> usize {
+        self.next_frame
+    }
+}
+
+#[async_trait]
+impl Sensor for MockSensor {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        self.sensor_type
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        let index = self.next_frame;
+        self.next_frame += 1;
+
+        if self.fail_on == Some(index) {
+            let error = Error::sensor(format!("Mock sensor configured to fail capture {}", index));
+            self.state = SensorState::Error(error.to_string());
+            return Err(error);
+        }
+
+        match self.frames.get(index) {
+            Some(frame) => {
+                self.state = SensorState::Ready;
+                Ok(frame.clone())
+            }
+            None => {
+                let error = Error::sensor("Mock sensor has no more frames");
+                self.state = SensorState::Error(error.to_string());
+                Err(error)
+            }
+        }
+    }
+
+    async fn is_available(&self) -> bool {
+        matches!(self.state, SensorState::Ready | SensorState::Capturing)
+    }
+
+    fn state(&self) -> SensorState {
+        self.state.clone()
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &self.sensor_type
+    }
+
+    async fn calibrate(&mut self) -> Result<(), Error> {
+        if let Some(flag) = &self.calibrated {
+            flag.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+        Ok(())
+    }
+}
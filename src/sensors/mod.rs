@@ -1,16 +1,90 @@
 //! Sensor processing and management
 
 pub mod camera;
+pub mod fusion;
 pub mod gps;
+pub mod group;
 pub mod imu;
 pub mod lidar;
+pub mod metadata;
+#[cfg(feature = "test-utils")]
+pub mod mock;
+pub mod proto;
+pub mod rate_limited;
+pub mod recorder;
+pub mod redaction;
+pub mod registry;
+pub mod replay;
+pub mod sync;
 pub mod thermal;
+pub mod units;
 pub mod manager;
 
-pub use manager::SensorManager;
+pub use camera::{Camera, CameraConfig};
+pub use gps::{GPSConfig, GPS};
+pub use group::SensorGroup;
+pub use imu::{IMUConfig, IMU};
+pub use lidar::{LiDAR, LiDARConfig};
+pub use manager::{OverflowPolicy, SensorManager, SensorMetrics};
+#[cfg(feature = "test-utils")]
+pub use mock::MockSensor;
+pub use rate_limited::RateLimited;
+pub use recorder::{RecorderConfig, SessionRecorder};
+pub use redaction::{BoundingBox, RedactionPolicy, RegionProvider};
+pub use registry::{SensorFactory, SensorRegistry};
+pub use replay::{ReplayConfig, ReplaySensor};
+pub use sync::FrameSynchronizer;
+pub use thermal::{Thermal, ThermalConfig};
+pub use units::UnitSystem;
+
+/// Config for any supported sensor, tagged by kind
+///
+/// Lets a config-driven application describe its sensors in TOML/JSON and
+/// instantiate them through [`create`] without matching on concrete types.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SensorSpec {
+    /// Camera sensor config
+    Camera(camera::CameraConfig),
+    /// LiDAR sensor config
+    LiDAR(lidar::LiDARConfig),
+    /// IMU sensor config
+    IMU(imu::IMUConfig),
+    /// GPS sensor config
+    GPS(gps::GPSConfig),
+    /// Thermal sensor config
+    Thermal(thermal::ThermalConfig),
+}
+
+impl SensorSpec {
+    /// The sensor type this spec produces
+    pub fn sensor_type(&self) -> SensorType {
+        match self {
+            Self::Camera(_) => SensorType::Camera,
+            Self::LiDAR(_) => SensorType::LiDAR,
+            Self::IMU(_) => SensorType::IMU,
+            Self::GPS(_) => SensorType::GPS,
+            Self::Thermal(_) => SensorType::Thermal,
+        }
+    }
+}
+
+/// Instantiate a sensor from a spec
+pub fn create(id: String, spec: SensorSpec) -> Result<Box<dyn Sensor>, crate::core::Error> {
+    Ok(match spec {
+        SensorSpec::Camera(config) => Box::new(camera::Camera::new(id, config)?),
+        SensorSpec::LiDAR(config) => Box::new(lidar::LiDAR::new(id, config)?),
+        SensorSpec::IMU(config) => Box::new(imu::IMU::new(id, config)?),
+        SensorSpec::GPS(config) => Box::new(gps::GPS::new(id, config)?),
+        SensorSpec::Thermal(config) => Box::new(thermal::Thermal::new(id, config)?),
+    })
+}
 
 /// Common sensor types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
 pub enum SensorType {
     /// Camera sensor
     Camera,
@@ -24,22 +98,125 @@ pub enum SensorType {
     Thermal,
 }
 
+impl SensorType {
+    /// The stable lowercase string form used on the wire and in the SDKs
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Camera => "camera",
+            Self::LiDAR => "lidar",
+            Self::IMU => "imu",
+            Self::GPS => "gps",
+            Self::Thermal => "thermal",
+        }
+    }
+}
+
+impl std::fmt::Display for SensorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for SensorType {
+    type Err = crate::core::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "camera" => Ok(Self::Camera),
+            "lidar" => Ok(Self::LiDAR),
+            "imu" => Ok(Self::IMU),
+            "gps" => Ok(Self::GPS),
+            "thermal" => Ok(Self::Thermal),
+            other => Err(crate::core::Error::sensor(format!(
+                "Unknown sensor type: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Serde helper encoding raw sensor bytes as base64 strings in JSON
+mod base64_bytes {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(data))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(&encoded).map_err(serde::de::Error::custom)
+    }
+}
+
 /// Sensor data structure
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SensorData {
+    /// Unique frame id correlating log records across pipeline stages
+    pub frame_id: uuid::Uuid,
     /// Sensor ID
     pub sensor_id: String,
     /// Sensor type
     pub sensor_type: SensorType,
     /// Timestamp
     pub timestamp: chrono::DateTime<chrono::Utc>,
-    /// Raw data
+    /// Raw data, carried as base64 in JSON to stay compact
+    #[serde(with = "base64_bytes")]
     pub data: Vec<u8>,
     /// Metadata
     pub metadata: std::collections::HashMap<String, String>,
+    /// Optional CRC32 checksum of the raw data
+    pub checksum: Option<u32>,
+}
+
+impl SensorData {
+    /// Attach a CRC32 checksum computed over the raw data
+    pub fn with_checksum(mut self) -> Self {
+        self.checksum = Some(crc32fast::hash(&self.data));
+        self
+    }
+
+    /// Verify the raw data against the stored checksum
+    ///
+    /// Frames without a checksum verify trivially.
+    pub fn verify_checksum(&self) -> bool {
+        match self.checksum {
+            Some(expected) => crc32fast::hash(&self.data) == expected,
+            None => true,
+        }
+    }
+
+    /// Decode the raw payload as thermal data
+    ///
+    /// Fails if the frame did not come from a thermal sensor.
+    pub fn as_thermal(&self) -> Result<crate::sensors::thermal::ThermalData, crate::core::Error> {
+        if self.sensor_type != SensorType::Thermal {
+            return Err(crate::core::Error::sensor(format!(
+                "Expected thermal data, got {:?}",
+                self.sensor_type
+            )));
+        }
+        crate::sensors::thermal::Thermal::deserialize_thermal_data(&self.data)
+    }
+}
+
+/// Lifecycle state of a sensor
+#[derive(Debug, Clone, PartialEq)]
+pub enum SensorState {
+    /// Not yet initialized
+    Uninitialized,
+    /// Initialized and able to capture
+    Ready,
+    /// A capture is in progress
+    Capturing,
+    /// A fault occurred; the message describes the failure
+    Error(String),
 }
 
 /// Sensor trait
+#[async_trait::async_trait]
 pub trait Sensor: Send + Sync {
     /// Get sensor ID
     fn id(&self) -> &str;
@@ -52,7 +229,38 @@ pub trait Sensor: Send + Sync {
     
     /// Check if sensor is available
     async fn is_available(&self) -> bool;
-    
+
+    /// Get the sensor lifecycle state
+    ///
+    /// The default assumes a sensor that is always ready.
+    fn state(&self) -> SensorState {
+        SensorState::Ready
+    }
+
     /// Get sensor configuration
     fn config(&self) -> &dyn std::fmt::Debug;
+
+    /// Prepare the sensor for capturing
+    ///
+    /// The default is a no-op for sensors that need no setup.
+    async fn initialize(&mut self) -> Result<(), crate::core::Error> {
+        Ok(())
+    }
+
+    /// Run the sensor's calibration routine
+    ///
+    /// Invoked by [`SensorManager`](manager::SensorManager) during batch
+    /// initialization when manager-level calibration is enabled; the
+    /// default is a no-op for sensors that need none.
+    async fn calibrate(&mut self) -> Result<(), crate::core::Error> {
+        Ok(())
+    }
+
+    /// Release any resources held by the sensor
+    ///
+    /// Hardware-backed sensors override this to close device handles;
+    /// the default is a no-op.
+    async fn shutdown(&mut self) -> Result<(), crate::core::Error> {
+        Ok(())
+    }
 }
@@ -0,0 +1,91 @@
+//! Protobuf wire format for sensor frames
+//!
+//! Mirrors `proto/sensor_data.proto`, which is the schema shared with the
+//! Python/JS SDKs. The message is defined with prost derives so no protoc
+//! step is needed at build time.
+
+use crate::core::Error;
+use crate::sensors::{SensorData, SensorType};
+use prost::Message;
+use std::collections::HashMap;
+
+/// Wire representation of [`SensorData`]
+#[derive(Clone, PartialEq, Message)]
+pub struct SensorDataProto {
+    /// Sensor identifier
+    #[prost(string, tag = "1")]
+    pub sensor_id: String,
+    /// Sensor type name: "camera", "lidar", "imu", "gps", or "thermal"
+    #[prost(string, tag = "2")]
+    pub sensor_type: String,
+    /// Capture time in milliseconds since the Unix epoch
+    #[prost(int64, tag = "3")]
+    pub timestamp_ms: i64,
+    /// Raw sensor payload
+    #[prost(bytes = "vec", tag = "4")]
+    pub data: Vec<u8>,
+    /// Free-form metadata
+    #[prost(map = "string, string", tag = "5")]
+    pub metadata: HashMap<String, String>,
+    /// Optional CRC32 of the raw payload
+    #[prost(uint32, optional, tag = "6")]
+    pub checksum: Option<u32>,
+}
+
+fn type_name(sensor_type: SensorType) -> &'static str {
+    match sensor_type {
+        SensorType::Camera => "camera",
+        SensorType::LiDAR => "lidar",
+        SensorType::IMU => "imu",
+        SensorType::GPS => "gps",
+        SensorType::Thermal => "thermal",
+    }
+}
+
+fn parse_type_name(name: &str) -> Result<SensorType, Error> {
+    match name {
+        "camera" => Ok(SensorType::Camera),
+        "lidar" => Ok(SensorType::LiDAR),
+        "imu" => Ok(SensorType::IMU),
+        "gps" => Ok(SensorType::GPS),
+        "thermal" => Ok(SensorType::Thermal),
+        other => Err(Error::sensor(format!(
+            "Unknown sensor type: {}",
+            other
+        ))),
+    }
+}
+
+impl SensorData {
+    /// Encode the frame into the shared protobuf wire format
+    pub fn to_protobuf(&self) -> Vec<u8> {
+        let proto = SensorDataProto {
+            sensor_id: self.sensor_id.clone(),
+            sensor_type: type_name(self.sensor_type).to_string(),
+            timestamp_ms: self.timestamp.timestamp_millis(),
+            data: self.data.clone(),
+            metadata: self.metadata.clone(),
+            checksum: self.checksum,
+        };
+        proto.encode_to_vec()
+    }
+
+    /// Decode a frame from the shared protobuf wire format
+    pub fn from_protobuf(bytes: &[u8]) -> Result<Self, Error> {
+        let proto = SensorDataProto::decode(bytes)
+            .map_err(|e| Error::sensor(format!("Failed to decode protobuf: {}", e)))?;
+
+        let timestamp = chrono::DateTime::from_timestamp_millis(proto.timestamp_ms)
+            .ok_or_else(|| Error::sensor("Timestamp out of range"))?;
+
+        Ok(Self {
+            frame_id: uuid::Uuid::new_v4(),
+            sensor_id: proto.sensor_id,
+            sensor_type: parse_type_name(&proto.sensor_type)?,
+            timestamp,
+            data: proto.data,
+            metadata: proto.metadata,
+            checksum: proto.checksum,
+        })
+    }
+}
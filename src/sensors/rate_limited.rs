@@ -0,0 +1,77 @@
+//! Rate-limiting sensor wrapper
+
+use crate::core::Error;
+use crate::sensors::{Sensor, SensorData, SensorType};
+use async_trait::async_trait;
+use std::time::{Duration, Instant};
+
+/// Wrapper that limits how often the inner sensor is actually polled
+///
+/// Captures within `min_interval` of the previous one return the cached
+/// last frame, so a high-rate sensor can feed a slower pipeline without
+/// changing the sensor itself.
+pub struct RateLimited<S: Sensor> {
+    inner: S,
+    min_interval: Duration,
+    last_capture: Option<Instant>,
+    cached: Option<SensorData>,
+}
+
+impl<S: Sensor> RateLimited<S> {
+    /// Wrap a sensor, polling it at most once per `min_interval`
+    pub fn new(inner: S, min_interval: Duration) -> Self {
+        Self {
+            inner,
+            min_interval,
+            last_capture: None,
+            cached: None,
+        }
+    }
+
+    /// Access the wrapped sensor
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwrap back into the inner sensor
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<S: Sensor> Sensor for RateLimited<S> {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        self.inner.sensor_type()
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        let due = self
+            .last_capture
+            .map(|last| last.elapsed() >= self.min_interval)
+            .unwrap_or(true);
+
+        if due {
+            let data = self.inner.capture().await?;
+            self.last_capture = Some(Instant::now());
+            self.cached = Some(data.clone());
+            return Ok(data);
+        }
+
+        self.cached
+            .clone()
+            .ok_or_else(|| Error::sensor("No cached frame available yet"))
+    }
+
+    async fn is_available(&self) -> bool {
+        self.inner.is_available().await
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        self.inner.config()
+    }
+}
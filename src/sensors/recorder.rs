@@ -0,0 +1,141 @@
+//! Session recorder persisting captured frames to disk
+
+use crate::core::Error;
+use crate::sensors::{SensorData, SensorManager};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Recorder configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecorderConfig {
+    /// Base path of the session log
+    pub path: PathBuf,
+    /// Rotate to a new file once the current one exceeds this many bytes
+    pub max_file_size: u64,
+}
+
+impl RecorderConfig {
+    /// Record to the given path with a 64 MB rotation threshold
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            max_file_size: 64 * 1024 * 1024,
+        }
+    }
+}
+
+/// Appends captured frames to a length-prefixed log file
+///
+/// Each record is a protobuf-encoded `SensorData` frame prefixed with a
+/// little-endian `u32` length — the format read by `ReplaySensor`. Files
+/// rotate automatically once they exceed the configured size; rotated
+/// files carry a numeric suffix (`session.log.1`, `session.log.2`, ...).
+pub struct SessionRecorder {
+    config: RecorderConfig,
+    file: Option<File>,
+    bytes_written: u64,
+    rotation: usize,
+    files: Vec<PathBuf>,
+}
+
+impl SessionRecorder {
+    /// Create a stopped recorder
+    pub fn new(config: RecorderConfig) -> Self {
+        Self {
+            config,
+            file: None,
+            bytes_written: 0,
+            rotation: 0,
+            files: Vec::new(),
+        }
+    }
+
+    /// Open the session log and begin accepting frames
+    pub fn start(&mut self) -> Result<(), Error> {
+        if self.file.is_some() {
+            return Ok(());
+        }
+
+        let path = self.current_path();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        self.bytes_written = file.metadata()?.len();
+        self.file = Some(file);
+        if !self.files.contains(&path) {
+            self.files.push(path);
+        }
+        Ok(())
+    }
+
+    /// Flush and close the session log
+    pub fn stop(&mut self) -> Result<(), Error> {
+        if let Some(mut file) = self.file.take() {
+            file.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Whether the recorder is currently accepting frames
+    pub fn is_recording(&self) -> bool {
+        self.file.is_some()
+    }
+
+    /// Paths written during this session, in order
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Append one frame to the session log
+    pub fn record(&mut self, frame: &SensorData) -> Result<(), Error> {
+        let encoded = frame.to_protobuf();
+        let record_len = 4 + encoded.len() as u64;
+
+        if self.bytes_written > 0 && self.bytes_written + record_len > self.config.max_file_size {
+            self.rotate()?;
+        }
+
+        let file = self
+            .file
+            .as_mut()
+            .ok_or_else(|| Error::sensor("Recorder is not started"))?;
+        file.write_all(&(encoded.len() as u32).to_le_bytes())?;
+        file.write_all(&encoded)?;
+        self.bytes_written += record_len;
+        Ok(())
+    }
+
+    /// Capture all sensors through the manager and record the frames
+    pub async fn record_capture(
+        &mut self,
+        manager: &SensorManager,
+    ) -> Result<Vec<SensorData>, Error> {
+        let frames = manager.capture_all().await?;
+        for frame in &frames {
+            self.record(frame)?;
+        }
+        Ok(frames)
+    }
+
+    /// Close the current file and open the next rotation
+    fn rotate(&mut self) -> Result<(), Error> {
+        self.stop()?;
+        self.rotation += 1;
+        self.bytes_written = 0;
+        self.start()
+    }
+
+    /// Path of the file currently being written
+    fn current_path(&self) -> PathBuf {
+        if self.rotation == 0 {
+            self.config.path.clone()
+        } else {
+            let mut name = self.config.path.as_os_str().to_owned();
+            name.push(format!(".{}", self.rotation));
+            PathBuf::from(name)
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Camera frame redaction
+//!
+//! Cameras in public spaces can capture faces or license plates that must
+//! not reach storage. The policy blurs externally supplied regions of a
+//! raw RGB frame; detection itself stays outside this crate.
+
+use crate::core::Error;
+use crate::sensors::{SensorData, SensorType};
+
+/// Default Gaussian sigma, in pixels
+const DEFAULT_BLUR_SIGMA: f32 = 4.0;
+
+/// Rectangle to redact, in pixel coordinates from the top-left corner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BoundingBox {
+    /// Left edge
+    pub x: u32,
+    /// Top edge
+    pub y: u32,
+    /// Width in pixels
+    pub width: u32,
+    /// Height in pixels
+    pub height: u32,
+}
+
+/// Supplies the regions to redact in a frame
+///
+/// Implemented by integrators over an external face/plate detector.
+pub trait RegionProvider: Send + Sync {
+    /// Bounding boxes to redact in the given frame
+    fn regions(&self, frame: &SensorData) -> Vec<BoundingBox>;
+}
+
+/// Blurs regions of raw RGB camera frames before storage
+#[derive(Debug, Clone)]
+pub struct RedactionPolicy {
+    sigma: f32,
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self {
+            sigma: DEFAULT_BLUR_SIGMA,
+        }
+    }
+}
+
+impl RedactionPolicy {
+    /// Create a policy blurring with the given Gaussian sigma
+    pub fn new(sigma: f32) -> Result<Self, Error> {
+        if sigma <= 0.0 {
+            return Err(Error::sensor("Blur sigma must be positive"));
+        }
+        Ok(Self { sigma })
+    }
+
+    /// Blur the given regions of a camera frame in place
+    ///
+    /// The frame must be a camera frame carrying raw RGB data and a
+    /// `resolution` metadata entry matching the buffer size.
+    pub fn apply(&self, frame: &mut SensorData, boxes: &[BoundingBox]) -> Result<(), Error> {
+        if frame.sensor_type != SensorType::Camera {
+            return Err(Error::sensor("Redaction applies to camera frames only"));
+        }
+        let resolution = frame
+            .metadata
+            .get("resolution")
+            .ok_or_else(|| Error::sensor("Camera frame lacks resolution metadata"))?;
+        let (width, height) = resolution
+            .split_once('x')
+            .and_then(|(w, h)| Some((w.parse::<u32>().ok()?, h.parse::<u32>().ok()?)))
+            .ok_or_else(|| {
+                Error::sensor(format!("Malformed resolution metadata: {}", resolution))
+            })?;
+
+        for bbox in boxes {
+            self.blur_region(&mut frame.data, width, height, bbox)?;
+        }
+        Ok(())
+    }
+
+    /// Gaussian-blur one rectangle of a raw RGB buffer in place
+    ///
+    /// The box is clipped to the image; the blur samples only from inside
+    /// the box so redacted content cannot bleed back in from outside.
+    pub fn blur_region(
+        &self,
+        frame: &mut [u8],
+        width: u32,
+        height: u32,
+        bbox: &BoundingBox,
+    ) -> Result<(), Error> {
+        if frame.len() != (width as usize) * (height as usize) * 3 {
+            return Err(Error::sensor(format!(
+                "Frame buffer is {} bytes, expected {}x{} RGB",
+                frame.len(),
+                width,
+                height
+            )));
+        }
+
+        let x0 = bbox.x.min(width) as usize;
+        let y0 = bbox.y.min(height) as usize;
+        let x1 = bbox.x.saturating_add(bbox.width).min(width) as usize;
+        let y1 = bbox.y.saturating_add(bbox.height).min(height) as usize;
+        if x0 >= x1 || y0 >= y1 {
+            return Ok(());
+        }
+
+        let kernel = gaussian_kernel(self.sigma);
+        let radius = kernel.len() / 2;
+        let stride = width as usize * 3;
+
+        // Separable blur: horizontal pass into a copy, vertical pass back
+        let mut horizontal = frame.to_vec();
+        for y in y0..y1 {
+            for x in x0..x1 {
+                for channel in 0..3 {
+                    let mut sum = 0.0f32;
+                    for (k, weight) in kernel.iter().enumerate() {
+                        let sx = (x as isize + k as isize - radius as isize)
+                            .clamp(x0 as isize, x1 as isize - 1) as usize;
+                        sum += weight * frame[y * stride + sx * 3 + channel] as f32;
+                    }
+                    horizontal[y * stride + x * 3 + channel] = sum.round() as u8;
+                }
+            }
+        }
+        for y in y0..y1 {
+            for x in x0..x1 {
+                for channel in 0..3 {
+                    let mut sum = 0.0f32;
+                    for (k, weight) in kernel.iter().enumerate() {
+                        let sy = (y as isize + k as isize - radius as isize)
+                            .clamp(y0 as isize, y1 as isize - 1) as usize;
+                        sum += weight * horizontal[sy * stride + x * 3 + channel] as f32;
+                    }
+                    frame[y * stride + x * 3 + channel] = sum.round() as u8;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Normalized 1D Gaussian kernel spanning three sigmas each side
+fn gaussian_kernel(sigma: f32) -> Vec<f32> {
+    let radius = (sigma * 3.0).ceil() as usize;
+    let mut kernel = Vec::with_capacity(2 * radius + 1);
+    for i in 0..=2 * radius {
+        let offset = i as f32 - radius as f32;
+        kernel.push((-offset * offset / (2.0 * sigma * sigma)).exp());
+    }
+    let total: f32 = kernel.iter().sum();
+    for weight in &mut kernel {
+        *weight /= total;
+    }
+    kernel
+}
@@ -0,0 +1,110 @@
+//! Factory registry for sensor types
+//!
+//! [`SensorSpec`](crate::sensors::SensorSpec) covers the sensors this crate
+//! ships; the registry is the extension point for everything else. An
+//! integrator registers a factory under a string type name and the rest of
+//! the stack builds the sensor from configuration without knowing the
+//! concrete type.
+
+use crate::core::Error;
+use crate::sensors::{camera, gps, imu, lidar, thermal, Sensor};
+use std::collections::HashMap;
+
+/// Builds a sensor from an id and a JSON configuration
+pub type SensorFactory =
+    Box<dyn Fn(String, &serde_json::Value) -> Result<Box<dyn Sensor>, Error> + Send + Sync>;
+
+/// Registry of sensor factories keyed by type name
+pub struct SensorRegistry {
+    factories: HashMap<String, SensorFactory>,
+}
+
+impl SensorRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self {
+            factories: HashMap::new(),
+        }
+    }
+
+    /// Create a registry with the crate's built-in sensor types
+    ///
+    /// Registers `camera`, `lidar`, `imu`, `gps` and `thermal`, each
+    /// deserializing its JSON config into the matching config struct.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register("camera", |id, config| {
+            let config: camera::CameraConfig = parse_config("camera", config)?;
+            Ok(Box::new(camera::Camera::new(id, config)?) as Box<dyn Sensor>)
+        });
+        registry.register("lidar", |id, config| {
+            let config: lidar::LiDARConfig = parse_config("lidar", config)?;
+            Ok(Box::new(lidar::LiDAR::new(id, config)?) as Box<dyn Sensor>)
+        });
+        registry.register("imu", |id, config| {
+            let config: imu::IMUConfig = parse_config("imu", config)?;
+            Ok(Box::new(imu::IMU::new(id, config)?) as Box<dyn Sensor>)
+        });
+        registry.register("gps", |id, config| {
+            let config: gps::GPSConfig = parse_config("gps", config)?;
+            Ok(Box::new(gps::GPS::new(id, config)?) as Box<dyn Sensor>)
+        });
+        registry.register("thermal", |id, config| {
+            let config: thermal::ThermalConfig = parse_config("thermal", config)?;
+            Ok(Box::new(thermal::Thermal::new(id, config)?) as Box<dyn Sensor>)
+        });
+        registry
+    }
+
+    /// Register a factory under a type name, replacing any existing one
+    pub fn register<F>(&mut self, type_name: impl Into<String>, factory: F)
+    where
+        F: Fn(String, &serde_json::Value) -> Result<Box<dyn Sensor>, Error>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.factories.insert(type_name.into(), Box::new(factory));
+    }
+
+    /// Build a sensor of the given type from a JSON configuration
+    pub fn create(
+        &self,
+        type_name: &str,
+        id: String,
+        config_json: &serde_json::Value,
+    ) -> Result<Box<dyn Sensor>, Error> {
+        let factory = self.factories.get(type_name).ok_or_else(|| {
+            Error::sensor(format!("No sensor factory registered for type {}", type_name))
+        })?;
+        factory(id, config_json)
+    }
+
+    /// Whether a factory is registered for the type name
+    pub fn contains(&self, type_name: &str) -> bool {
+        self.factories.contains_key(type_name)
+    }
+
+    /// Registered type names, sorted
+    pub fn type_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.factories.keys().cloned().collect();
+        names.sort();
+        names
+    }
+}
+
+impl Default for SensorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Deserialize a factory's JSON config with a typed error
+fn parse_config<T: serde::de::DeserializeOwned>(
+    type_name: &str,
+    config: &serde_json::Value,
+) -> Result<T, Error> {
+    serde_json::from_value(config.clone()).map_err(|e| {
+        Error::sensor(format!("Invalid {} sensor config: {}", type_name, e))
+    })
+}
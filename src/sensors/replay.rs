@@ -0,0 +1,129 @@
+//! Replay sensor feeding recorded frames from disk
+
+use crate::core::Error;
+use crate::sensors::{Sensor, SensorData, SensorState, SensorType};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Replay configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayConfig {
+    /// Sleep the original inter-frame interval before each capture
+    pub realtime: bool,
+    /// Restart from the first frame at end-of-file instead of erroring
+    pub loop_playback: bool,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            realtime: false,
+            loop_playback: false,
+        }
+    }
+}
+
+/// Sensor that replays a recorded capture session from disk
+///
+/// The file holds protobuf-encoded `SensorData` frames, each prefixed
+/// with a little-endian `u32` length — the format written by the session
+/// recorder. Frames are returned in recorded order.
+pub struct ReplaySensor {
+    id: String,
+    config: ReplayConfig,
+    frames: Vec<SensorData>,
+    next_frame: usize,
+    state: SensorState,
+}
+
+impl ReplaySensor {
+    /// Load a recorded session from a length-prefixed frame log
+    pub fn from_file(id: String, path: &Path, config: ReplayConfig) -> Result<Self, Error> {
+        let bytes = std::fs::read(path)?;
+
+        let mut frames = Vec::new();
+        let mut offset = 0usize;
+        while offset < bytes.len() {
+            let header = bytes
+                .get(offset..offset + 4)
+                .ok_or_else(|| Error::sensor("Truncated frame length header"))?;
+            let length = u32::from_le_bytes(header.try_into().unwrap()) as usize;
+            offset += 4;
+
+            let payload = bytes
+                .get(offset..offset + length)
+                .ok_or_else(|| Error::sensor("Truncated frame payload"))?;
+            offset += length;
+
+            frames.push(SensorData::from_protobuf(payload)?);
+        }
+
+        if frames.is_empty() {
+            return Err(Error::sensor(format!(
+                "Replay file {} contains no frames",
+                path.display()
+            )));
+        }
+
+        Ok(Self {
+            id,
+            config,
+            frames,
+            next_frame: 0,
+            state: SensorState::Ready,
+        })
+    }
+
+    /// Number of frames in the recording
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+#[async_trait]
+impl Sensor for ReplaySensor {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        self.frames[0].sensor_type
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        if self.next_frame >= self.frames.len() {
+            if self.config.loop_playback {
+                self.next_frame = 0;
+            } else {
+                let error = Error::sensor("Replay reached end of recording");
+                self.state = SensorState::Error(error.to_string());
+                return Err(error);
+            }
+        }
+
+        let index = self.next_frame;
+        self.next_frame += 1;
+
+        if self.config.realtime && index > 0 {
+            let gap = self.frames[index].timestamp - self.frames[index - 1].timestamp;
+            if let Ok(gap) = gap.to_std() {
+                tokio::time::sleep(gap).await;
+            }
+        }
+
+        Ok(self.frames[index].clone())
+    }
+
+    async fn is_available(&self) -> bool {
+        matches!(self.state, SensorState::Ready | SensorState::Capturing)
+    }
+
+    fn state(&self) -> SensorState {
+        self.state.clone()
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &self.config
+    }
+}
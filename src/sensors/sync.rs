@@ -0,0 +1,153 @@
+//! Cross-sensor timestamp alignment
+//!
+//! Fusing camera + LiDAR + IMU needs temporally aligned frames, but each
+//! sensor timestamps independently at capture. The synchronizer buffers
+//! recent frames per sensor and emits aligned sets anchored on a reference
+//! sensor, picking each other sensor's nearest frame within a tolerance.
+
+use crate::core::Error;
+use crate::sensors::SensorData;
+use std::collections::{HashMap, VecDeque};
+
+/// Frames buffered per sensor before the oldest are discarded
+const DEFAULT_BUFFER_DEPTH: usize = 64;
+
+/// Aligns frames from several sensors by capture timestamp
+pub struct FrameSynchronizer {
+    reference: String,
+    others: Vec<String>,
+    tolerance: chrono::Duration,
+    buffer_depth: usize,
+    buffers: HashMap<String, VecDeque<SensorData>>,
+    dropped_sets: u64,
+}
+
+impl FrameSynchronizer {
+    /// Create a synchronizer anchored on a reference sensor
+    ///
+    /// `sensors` lists every participating sensor id; it must contain the
+    /// reference. Each emitted set holds one frame per sensor, each within
+    /// `tolerance` of the reference frame's timestamp.
+    pub fn new(
+        reference: impl Into<String>,
+        sensors: &[String],
+        tolerance: chrono::Duration,
+    ) -> Result<Self, Error> {
+        let reference = reference.into();
+        if !sensors.contains(&reference) {
+            return Err(Error::sensor(format!(
+                "Reference sensor {} is not in the sensor list",
+                reference
+            )));
+        }
+        if tolerance <= chrono::Duration::zero() {
+            return Err(Error::sensor("Alignment tolerance must be positive"));
+        }
+
+        let others = sensors
+            .iter()
+            .filter(|id| **id != reference)
+            .cloned()
+            .collect();
+        let buffers = sensors
+            .iter()
+            .map(|id| (id.clone(), VecDeque::new()))
+            .collect();
+
+        Ok(Self {
+            reference,
+            others,
+            tolerance,
+            buffer_depth: DEFAULT_BUFFER_DEPTH,
+            buffers,
+            dropped_sets: 0,
+        })
+    }
+
+    /// Sets dropped because they could not be completed within tolerance
+    pub fn dropped_sets(&self) -> u64 {
+        self.dropped_sets
+    }
+
+    /// Buffer a frame and emit an aligned set if one completes
+    ///
+    /// Frames from sensors the synchronizer was not built with are ignored.
+    /// The emitted set starts with the reference frame, followed by the
+    /// other sensors in registration order.
+    pub fn push(&mut self, frame: SensorData) -> Option<Vec<SensorData>> {
+        let buffer = self.buffers.get_mut(&frame.sensor_id)?;
+        buffer.push_back(frame);
+        if buffer.len() > self.buffer_depth {
+            buffer.pop_front();
+        }
+        self.try_align()
+    }
+
+    /// Attempt to complete a set around the oldest buffered reference frame
+    fn try_align(&mut self) -> Option<Vec<SensorData>> {
+        loop {
+            let reference_ts = self.buffers[&self.reference].front()?.timestamp;
+
+            let mut matches = Vec::with_capacity(self.others.len());
+            let mut incomplete = false;
+            let mut hopeless = false;
+
+            for id in &self.others {
+                let buffer = &self.buffers[id];
+                let nearest = buffer
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, frame)| {
+                        (frame.timestamp - reference_ts).num_milliseconds().abs()
+                    })
+                    .filter(|(_, frame)| {
+                        (frame.timestamp - reference_ts).abs() <= self.tolerance
+                    });
+
+                match nearest {
+                    Some((index, _)) => matches.push((id.clone(), index)),
+                    None => {
+                        incomplete = true;
+                        // If this sensor already has frames past the window,
+                        // nothing arriving later can be closer: the set is lost.
+                        if buffer
+                            .back()
+                            .is_some_and(|frame| frame.timestamp - reference_ts > self.tolerance)
+                        {
+                            hopeless = true;
+                        }
+                    }
+                }
+            }
+
+            if !incomplete {
+                let mut set = vec![self
+                    .buffers
+                    .get_mut(&self.reference)
+                    .unwrap()
+                    .pop_front()
+                    .unwrap()];
+                for (id, index) in matches {
+                    let buffer = self.buffers.get_mut(&id).unwrap();
+                    set.push(buffer.remove(index).unwrap());
+                    // Frames older than the one consumed are stale now
+                    buffer.drain(..index.min(buffer.len()));
+                }
+                return Some(set);
+            }
+
+            if hopeless {
+                self.buffers.get_mut(&self.reference).unwrap().pop_front();
+                self.dropped_sets += 1;
+                tracing::debug!(
+                    "Dropped unalignable frame set around reference timestamp {}",
+                    reference_ts
+                );
+                continue;
+            }
+
+            // Waiting on more frames
+            return None;
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! Thermal sensor implementation
 
 use crate::core::Error;
-use crate::sensors::{Sensor, SensorData, SensorType};
+use crate::sensors::{Sensor, SensorData, SensorState, SensorType};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -24,10 +26,13 @@ pub struct ThermalConfig {
     pub enable_calibration: bool,
     /// Calibration data
     pub calibration_data: Option<CalibrationData>,
+    /// Seed driving synthetic captures, for reproducible test data
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 /// Calibration data for thermal sensor
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CalibrationData {
     pub offset: f32,
     pub gain: f32,
@@ -35,6 +40,63 @@ pub struct CalibrationData {
     pub temperature_lut: Vec<f32>,
 }
 
+/// Colormap applied when exporting a temperature map as an image
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorMap {
+    /// Perceptually uniform dark-to-bright map
+    Inferno,
+    /// Classic blue-to-red rainbow map
+    Jet,
+    /// Plain luminance ramp
+    Grayscale,
+}
+
+impl ColorMap {
+    /// Map a normalized temperature in `0.0..=1.0` to an RGB pixel
+    pub fn color(&self, value: f32) -> [u8; 3] {
+        let v = value.clamp(0.0, 1.0);
+        match self {
+            ColorMap::Grayscale => {
+                let level = (v * 255.0).round() as u8;
+                [level, level, level]
+            }
+            ColorMap::Jet => {
+                let channel = |center: f32| {
+                    ((1.5 - (4.0 * v - center).abs()).clamp(0.0, 1.0) * 255.0).round() as u8
+                };
+                [channel(3.0), channel(2.0), channel(1.0)]
+            }
+            ColorMap::Inferno => {
+                let anchors: [[f32; 3]; 8] = [
+                    [0.0, 0.0, 4.0],
+                    [40.0, 11.0, 84.0],
+                    [101.0, 21.0, 110.0],
+                    [159.0, 42.0, 99.0],
+                    [212.0, 72.0, 66.0],
+                    [245.0, 125.0, 21.0],
+                    [250.0, 193.0, 39.0],
+                    [252.0, 255.0, 164.0],
+                ];
+                let position = v * (anchors.len() - 1) as f32;
+                let low = position.floor() as usize;
+                let high = (low + 1).min(anchors.len() - 1);
+                let t = position - low as f32;
+                let lerp = |a: f32, b: f32| (a + (b - a) * t).round() as u8;
+                [
+                    lerp(anchors[low][0], anchors[high][0]),
+                    lerp(anchors[low][1], anchors[high][1]),
+                    lerp(anchors[low][2], anchors[high][2]),
+                ]
+            }
+        }
+    }
+
+    /// The color this map assigns to the hottest value
+    pub fn peak(&self) -> [u8; 3] {
+        self.color(1.0)
+    }
+}
+
 /// Thermal data structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThermalData {
@@ -65,6 +127,7 @@ impl Default for ThermalConfig {
             atmospheric_temp: 20.0,
             enable_calibration: true,
             calibration_data: None,
+            seed: None,
         }
     }
 }
@@ -74,6 +137,8 @@ pub struct Thermal {
     id: String,
     config: ThermalConfig,
     is_initialized: bool,
+    state: SensorState,
+    rng: Option<rand::rngs::StdRng>,
 }
 
 impl Thermal {
@@ -81,8 +146,10 @@ impl Thermal {
     pub fn new(id: String, config: ThermalConfig) -> Result<Self, Error> {
         Ok(Self {
             id,
+            rng: config.seed.map(rand::rngs::StdRng::seed_from_u64),
             config,
             is_initialized: false,
+            state: SensorState::Uninitialized,
         })
     }
 
@@ -90,11 +157,33 @@ impl Thermal {
     pub async fn initialize(&mut self) -> Result<(), Error> {
         tracing::info!("Initializing thermal sensor: {}", self.id);
         
-        if self.config.enable_calibration {
+        if self.config.enable_calibration && self.config.calibration_data.is_none() {
             self.calibrate().await?;
         }
-        
+
         self.is_initialized = true;
+        self.state = SensorState::Ready;
+        Ok(())
+    }
+
+    /// Save the current calibration to a JSON file
+    pub fn save_calibration(&self, path: &std::path::Path) -> Result<(), Error> {
+        let calibration = self
+            .config
+            .calibration_data
+            .as_ref()
+            .ok_or_else(|| Error::sensor("Thermal sensor has no calibration to save"))?;
+        let json = serde_json::to_string_pretty(calibration)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Load a previously saved calibration from a JSON file
+    ///
+    /// A loaded calibration is reused on init instead of recalibrating.
+    pub fn load_calibration(&mut self, path: &std::path::Path) -> Result<(), Error> {
+        let json = std::fs::read_to_string(path)?;
+        self.config.calibration_data = Some(serde_json::from_str(&json)?);
         Ok(())
     }
 
@@ -126,9 +215,14 @@ impl Thermal {
     }
 
     /// Generate test thermal data
-    async fn generate_test_thermal_data(&self) -> Result<ThermalData, Error> {
+    async fn generate_test_thermal_data(&mut self) -> Result<ThermalData, Error> {
         let timestamp = chrono::Utc::now();
-        let time = timestamp.timestamp_millis() as f32 / 1000.0;
+        // A seeded sensor draws its drive value from the PRNG so frames
+        // are reproducible; otherwise the wall clock drives the simulation
+        let time = match &mut self.rng {
+            Some(rng) => rng.gen::<f32>() * 1000.0,
+            None => timestamp.timestamp_millis() as f32 / 1000.0,
+        };
         
         let (width, height) = self.config.resolution;
         let mut temperature_map = vec![vec![0.0; width as usize]; height as usize];
@@ -152,7 +246,10 @@ impl Thermal {
                 let noise = 2.0 * (time * 2.0 + x as f32 + y as f32).sin();
                 
                 let temperature = base_temp + heat_source_1 + heat_source_2 + noise;
-                
+
+                // Correct for emissivity and atmospheric transmission
+                let temperature = self.apply_radiometric_correction(temperature);
+
                 // Apply temperature range limits
                 let temperature = temperature
                     .max(self.config.temperature_range.0)
@@ -285,6 +382,33 @@ impl Thermal {
         cold_spots
     }
 
+    /// Correct a raw apparent temperature for emissivity and atmospheric transmission
+    ///
+    /// Uses the standard radiometric model: the measured signal is a mix of
+    /// emitted, reflected, and atmospheric radiance, combined in the fourth
+    /// power of absolute temperature. The reflected background is assumed to
+    /// be at the atmospheric temperature.
+    pub fn apply_radiometric_correction(&self, raw: f32) -> f32 {
+        let emissivity = self.config.emissivity.clamp(0.01, 1.0);
+        let transmission = self.atmospheric_transmission();
+
+        let raw_k = raw + 273.15;
+        let atm_k = self.config.atmospheric_temp + 273.15;
+
+        let measured = raw_k.powi(4);
+        let reflected = (1.0 - emissivity) * transmission * atm_k.powi(4);
+        let atmospheric = (1.0 - transmission) * atm_k.powi(4);
+
+        let object = (measured - reflected - atmospheric) / (emissivity * transmission);
+        object.max(0.0).powf(0.25) - 273.15
+    }
+
+    /// Estimate atmospheric transmission from distance and humidity
+    fn atmospheric_transmission(&self) -> f32 {
+        let attenuation = 0.006 * (1.0 + self.config.humidity);
+        (-attenuation * self.config.distance).exp().clamp(0.5, 1.0)
+    }
+
     /// Serialize thermal data to bytes
     pub fn serialize_thermal_data(&self, thermal_data: &ThermalData) -> Result<Vec<u8>, Error> {
         let mut data = Vec::new();
@@ -324,10 +448,124 @@ impl Thermal {
         // Serialize timestamp
         let timestamp_bytes = thermal_data.timestamp.timestamp_millis().to_le_bytes();
         data.extend_from_slice(&timestamp_bytes);
-        
+
         Ok(data)
     }
 
+    /// Deserialize thermal data from bytes produced by `serialize_thermal_data`
+    pub fn deserialize_thermal_data(data: &[u8]) -> Result<ThermalData, Error> {
+        fn take<'a>(data: &'a [u8], offset: &mut usize, len: usize) -> Result<&'a [u8], Error> {
+            let slice = data
+                .get(*offset..*offset + len)
+                .ok_or_else(|| Error::sensor("Truncated thermal data"))?;
+            *offset += len;
+            Ok(slice)
+        }
+
+        fn read_usize(data: &[u8], offset: &mut usize) -> Result<usize, Error> {
+            Ok(usize::from_le_bytes(take(data, offset, 8)?.try_into().unwrap()))
+        }
+
+        fn read_u32(data: &[u8], offset: &mut usize) -> Result<u32, Error> {
+            Ok(u32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap()))
+        }
+
+        fn read_f32(data: &[u8], offset: &mut usize) -> Result<f32, Error> {
+            Ok(f32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap()))
+        }
+
+        fn read_spots(data: &[u8], offset: &mut usize) -> Result<Vec<(u32, u32, f32)>, Error> {
+            let count = read_usize(data, offset)?;
+            if count > 1_000 {
+                return Err(Error::sensor("Invalid thermal spot count"));
+            }
+            let mut spots = Vec::with_capacity(count);
+            for _ in 0..count {
+                let x = read_u32(data, offset)?;
+                let y = read_u32(data, offset)?;
+                let temp = read_f32(data, offset)?;
+                spots.push((x, y, temp));
+            }
+            Ok(spots)
+        }
+
+        let mut offset = 0usize;
+
+        let width = read_usize(data, &mut offset)?;
+        let height = read_usize(data, &mut offset)?;
+        if width == 0 || height == 0 || width.saturating_mul(height) > 1_000_000 {
+            return Err(Error::sensor("Invalid thermal resolution"));
+        }
+
+        let mut temperature_map = Vec::with_capacity(height);
+        for _ in 0..height {
+            let mut row = Vec::with_capacity(width);
+            for _ in 0..width {
+                row.push(read_f32(data, &mut offset)?);
+            }
+            temperature_map.push(row);
+        }
+
+        let min_temperature = read_f32(data, &mut offset)?;
+        let max_temperature = read_f32(data, &mut offset)?;
+        let avg_temperature = read_f32(data, &mut offset)?;
+
+        let hot_spots = read_spots(data, &mut offset)?;
+        let cold_spots = read_spots(data, &mut offset)?;
+
+        let timestamp_millis = i64::from_le_bytes(take(data, &mut offset, 8)?.try_into().unwrap());
+        let timestamp = chrono::DateTime::from_timestamp_millis(timestamp_millis)
+            .ok_or_else(|| Error::sensor("Invalid thermal timestamp"))?;
+
+        Ok(ThermalData {
+            temperature_map,
+            min_temperature,
+            max_temperature,
+            avg_temperature,
+            hot_spots,
+            cold_spots,
+            timestamp,
+        })
+    }
+
+    /// Render a temperature map as a colormapped PNG
+    ///
+    /// Temperatures are normalized between the frame's min and max before
+    /// the colormap is applied, so the hottest pixel always gets the
+    /// map's peak color. Image dimensions follow the temperature map.
+    pub fn to_png(&self, data: &ThermalData, colormap: ColorMap) -> Result<Vec<u8>, Error> {
+        let height = data.temperature_map.len() as u32;
+        let width = data.temperature_map.first().map_or(0, Vec::len) as u32;
+        if width == 0 || height == 0 {
+            return Err(Error::sensor("Thermal data has an empty temperature map"));
+        }
+
+        let span = data.max_temperature - data.min_temperature;
+        let mut image = image::RgbImage::new(width, height);
+        for (y, row) in data.temperature_map.iter().enumerate() {
+            if row.len() as u32 != width {
+                return Err(Error::sensor("Thermal temperature map rows differ in length"));
+            }
+            for (x, temperature) in row.iter().enumerate() {
+                let normalized = if span > 0.0 {
+                    (temperature - data.min_temperature) / span
+                } else {
+                    0.0
+                };
+                image.put_pixel(x as u32, y as u32, image::Rgb(colormap.color(normalized)));
+            }
+        }
+
+        let mut bytes = Vec::new();
+        image
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image::ImageOutputFormat::Png,
+            )
+            .map_err(|e| Error::sensor(format!("Failed to encode thermal PNG: {}", e)))?;
+        Ok(bytes)
+    }
+
     /// Get thermal configuration
     pub fn config(&self) -> &ThermalConfig {
         &self.config
@@ -335,11 +573,17 @@ impl Thermal {
 
     /// Update thermal configuration
     pub fn update_config(&mut self, config: ThermalConfig) {
+        self.rng = config.seed.map(rand::rngs::StdRng::seed_from_u64);
         self.config = config;
     }
 }
 
+#[async_trait]
 impl Sensor for Thermal {
+    async fn initialize(&mut self) -> Result<(), Error> {
+        Thermal::initialize(self).await
+    }
+
     fn id(&self) -> &str {
         &self.id
     }
@@ -349,7 +593,15 @@ impl Sensor for Thermal {
     }
     
     async fn capture(&mut self) -> Result<SensorData, Error> {
-        let thermal_data = self.capture().await?;
+        self.state = SensorState::Capturing;
+        let thermal_data = match Thermal::capture(self).await {
+            Ok(data) => data,
+            Err(e) => {
+                self.state = SensorState::Error(e.to_string());
+                return Err(e);
+            }
+        };
+        self.state = SensorState::Ready;
         let data = self.serialize_thermal_data(&thermal_data)?;
         
         let mut metadata = HashMap::new();
@@ -362,16 +614,22 @@ impl Sensor for Thermal {
         metadata.insert("emissivity".to_string(), self.config.emissivity.to_string());
         
         Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
             sensor_id: self.id.clone(),
             sensor_type: SensorType::Thermal,
             timestamp: chrono::Utc::now(),
             data,
             metadata,
+            checksum: None,
         })
     }
     
     async fn is_available(&self) -> bool {
-        self.is_initialized
+        matches!(self.state, SensorState::Ready | SensorState::Capturing)
+    }
+
+    fn state(&self) -> SensorState {
+        self.state.clone()
     }
     
     fn config(&self) -> &dyn std::fmt::Debug {
@@ -0,0 +1,119 @@
+//! Unit normalization for sensor data
+
+use crate::sensors::gps::GPSData;
+use crate::sensors::thermal::ThermalData;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Canonical unit system for normalized sensor readings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnitSystem {
+    /// SI units (m/s, °C, meters)
+    SI,
+    /// Imperial units (mph, °F, feet)
+    Imperial,
+}
+
+impl Default for UnitSystem {
+    fn default() -> Self {
+        Self::SI
+    }
+}
+
+impl UnitSystem {
+    /// Convert a temperature from Celsius into this system
+    pub fn convert_temperature(&self, celsius: f32) -> f32 {
+        match self {
+            Self::SI => celsius,
+            Self::Imperial => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    /// Convert a speed from m/s into this system
+    pub fn convert_speed(&self, meters_per_second: f64) -> f64 {
+        match self {
+            Self::SI => meters_per_second,
+            Self::Imperial => meters_per_second * 2.236_936_292,
+        }
+    }
+
+    /// Convert a distance from meters into this system
+    pub fn convert_distance(&self, meters: f64) -> f64 {
+        match self {
+            Self::SI => meters,
+            Self::Imperial => meters * 3.280_839_895,
+        }
+    }
+
+    /// Unit tag for temperatures in this system
+    pub fn temperature_unit(&self) -> &'static str {
+        match self {
+            Self::SI => "celsius",
+            Self::Imperial => "fahrenheit",
+        }
+    }
+
+    /// Unit tag for speeds in this system
+    pub fn speed_unit(&self) -> &'static str {
+        match self {
+            Self::SI => "m/s",
+            Self::Imperial => "mph",
+        }
+    }
+
+    /// Unit tag for distances in this system
+    pub fn distance_unit(&self) -> &'static str {
+        match self {
+            Self::SI => "m",
+            Self::Imperial => "ft",
+        }
+    }
+}
+
+/// Normalize thermal data (captured in Celsius) into the given unit system,
+/// tagging the applied units in the metadata
+pub fn normalize_thermal_data(
+    system: UnitSystem,
+    thermal_data: &mut ThermalData,
+    metadata: &mut HashMap<String, String>,
+) {
+    for row in thermal_data.temperature_map.iter_mut() {
+        for temp in row.iter_mut() {
+            *temp = system.convert_temperature(*temp);
+        }
+    }
+
+    thermal_data.min_temperature = system.convert_temperature(thermal_data.min_temperature);
+    thermal_data.max_temperature = system.convert_temperature(thermal_data.max_temperature);
+    thermal_data.avg_temperature = system.convert_temperature(thermal_data.avg_temperature);
+
+    for (_, _, temp) in thermal_data.hot_spots.iter_mut() {
+        *temp = system.convert_temperature(*temp);
+    }
+    for (_, _, temp) in thermal_data.cold_spots.iter_mut() {
+        *temp = system.convert_temperature(*temp);
+    }
+
+    metadata.insert(
+        "temperature_unit".to_string(),
+        system.temperature_unit().to_string(),
+    );
+}
+
+/// Normalize GPS data (captured in SI units) into the given unit system,
+/// tagging the applied units in the metadata
+pub fn normalize_gps_data(
+    system: UnitSystem,
+    gps_data: &mut GPSData,
+    metadata: &mut HashMap<String, String>,
+) {
+    gps_data.speed = system.convert_speed(gps_data.speed);
+    gps_data.altitude = system.convert_distance(gps_data.altitude);
+    gps_data.accuracy = system.convert_distance(gps_data.accuracy);
+
+    metadata.insert("speed_unit".to_string(), system.speed_unit().to_string());
+    metadata.insert(
+        "altitude_unit".to_string(),
+        system.distance_unit().to_string(),
+    );
+}
@@ -0,0 +1,50 @@
+//! Unit tests for pluggable anomaly detection
+
+use kova_core::core::validation::{
+    AnomalyDetector, DataValidator, IqrAnomalyDetector, ZScoreAnomalyDetector,
+};
+use std::collections::HashMap;
+
+/// Detector that flags everything, to prove injection is honored
+struct AlwaysAnomalous;
+
+impl AnomalyDetector for AlwaysAnomalous {
+    fn score(&self, _data: &[u8]) -> f64 {
+        1.0
+    }
+}
+
+#[tokio::test]
+async fn test_validator_uses_injected_detector() {
+    let mut validator = DataValidator::new();
+    validator.set_anomaly_detector(Box::new(AlwaysAnomalous));
+
+    let data = vec![128u8; 64];
+    let result = validator.validate(&data, &HashMap::new()).await.unwrap();
+
+    assert_eq!(result.metrics.anomaly_score, 1.0);
+}
+
+#[test]
+fn test_iqr_detector_flags_clear_outlier() {
+    // Tight cluster with a single extreme sample
+    let mut data = vec![100u8; 99];
+    data.push(255);
+
+    let score = IqrAnomalyDetector.score(&data);
+    assert!(score > 0.0);
+    assert!((score - 0.01).abs() < 1e-9);
+}
+
+#[test]
+fn test_uniform_data_scores_zero() {
+    let data = vec![100u8; 100];
+
+    assert_eq!(IqrAnomalyDetector.score(&data), 0.0);
+    assert_eq!(ZScoreAnomalyDetector.score(&data), 0.0);
+}
+
+#[test]
+fn test_short_data_scores_zero() {
+    assert_eq!(IqrAnomalyDetector.score(&[1, 2, 3]), 0.0);
+}
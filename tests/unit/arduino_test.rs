@@ -0,0 +1,46 @@
+//! Unit tests for the Arduino robot integration
+
+use kova_core::robots::arduino::{ArduinoCommand, ArduinoConfig, ArduinoResponse, ArduinoRobot};
+
+#[tokio::test]
+async fn test_ping_round_trips_over_loopback() {
+    let mut robot = ArduinoRobot::new("robot_001".to_string(), ArduinoConfig::default()).unwrap();
+    robot.connect_loopback();
+
+    let response = robot.send_command(ArduinoCommand::Ping).await.unwrap();
+    assert!(matches!(response, ArduinoResponse::Pong));
+}
+
+#[tokio::test]
+async fn test_write_command_acknowledged_over_loopback() {
+    let mut robot = ArduinoRobot::new("robot_001".to_string(), ArduinoConfig::default()).unwrap();
+    robot.connect_loopback();
+
+    let response = robot
+        .send_command(ArduinoCommand::WriteDigital { pin: 13, value: true })
+        .await
+        .unwrap();
+    assert!(matches!(response, ArduinoResponse::Success { .. }));
+}
+
+#[tokio::test]
+async fn test_uptime_counts_from_connection() {
+    let mut robot = ArduinoRobot::new("robot_001".to_string(), ArduinoConfig::default()).unwrap();
+    assert_eq!(robot.get_status().uptime, 0);
+
+    robot.connect_loopback();
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+
+    let uptime = robot.get_status().uptime;
+    assert!((1..=5).contains(&uptime), "unexpected uptime: {}", uptime);
+
+    robot.disconnect().await.unwrap();
+    assert_eq!(robot.get_status().uptime, 0);
+}
+
+#[tokio::test]
+async fn test_send_command_requires_connection() {
+    let robot = ArduinoRobot::new("robot_001".to_string(), ArduinoConfig::default()).unwrap();
+
+    assert!(robot.send_command(ArduinoCommand::Ping).await.is_err());
+}
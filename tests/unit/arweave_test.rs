@@ -0,0 +1,70 @@
+//! Unit tests for the Arweave client against a scripted mock gateway
+
+use kova_core::blockchain::arweave::{ArweaveClient, ArweaveConfig};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Serve one scripted response body per incoming request
+async fn mock_gateway(responses: Vec<String>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        for body in responses {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                break;
+            };
+            let mut buffer = vec![0u8; 4096];
+            let _ = stream.read(&mut buffer).await;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: text/plain\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    url
+}
+
+fn config_for(url: String) -> ArweaveConfig {
+    ArweaveConfig {
+        gateway_url: url,
+        timeout_seconds: 2,
+        retry_attempts: 1,
+        private_key: None,
+    }
+}
+
+#[tokio::test]
+async fn test_estimate_cost_uses_network_price() {
+    let url = mock_gateway(vec!["123456".to_string()]).await;
+    let client = ArweaveClient::new(config_for(url)).await.unwrap();
+
+    let estimate = client.estimate_cost(1024).await.unwrap();
+    assert_eq!(estimate, 123_456);
+}
+
+#[tokio::test]
+async fn test_query_by_tags_parses_transaction_ids() {
+    let body = r#"{"data":{"transactions":{"edges":[{"node":{"id":"tx_one"}},{"node":{"id":"tx_two"}}]}}}"#;
+    let url = mock_gateway(vec![body.to_string()]).await;
+    let client = ArweaveClient::new(config_for(url)).await.unwrap();
+
+    let tags = vec![("App-Name".to_string(), "Kova".to_string())];
+    let ids = client.query_by_tags(&tags).await.unwrap();
+
+    assert_eq!(ids, vec!["tx_one", "tx_two"]);
+}
+
+#[tokio::test]
+async fn test_estimate_cost_falls_back_when_unreachable() {
+    // Nothing is listening on this port
+    let client = ArweaveClient::new(config_for("http://127.0.0.1:9".to_string()))
+        .await
+        .unwrap();
+
+    let estimate = client.estimate_cost(1024).await.unwrap();
+    assert_eq!(estimate, 1024 * 1000);
+}
@@ -0,0 +1,124 @@
+//! Tests for API key authentication on the REST and WebSocket servers
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use futures_util::{SinkExt, StreamExt};
+use kova_core::api::auth::ApiKeyAuth;
+use kova_core::api::rest::RestApiServer;
+use kova_core::api::websocket::{Envelope, WebSocketMessage, WebSocketServer};
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::Message;
+use tower::ServiceExt;
+
+fn guarded_router() -> axum::Router {
+    let mut server = RestApiServer::new("127.0.0.1".to_string(), 0);
+    server.set_auth(ApiKeyAuth::new(["valid-key"]));
+    server.router()
+}
+
+#[tokio::test]
+async fn test_rest_rejects_missing_key() {
+    let response = guarded_router()
+        .oneshot(
+            Request::builder()
+                .uri("/health")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_rest_rejects_wrong_key() {
+    let response = guarded_router()
+        .oneshot(
+            Request::builder()
+                .uri("/health")
+                .header("x-api-key", "wrong-key")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_rest_accepts_bearer_and_header_keys() {
+    for request in [
+        Request::builder()
+            .uri("/health")
+            .header("authorization", "Bearer valid-key")
+            .body(Body::empty())
+            .unwrap(),
+        Request::builder()
+            .uri("/health")
+            .header("x-api-key", "valid-key")
+            .body(Body::empty())
+            .unwrap(),
+    ] {
+        let response = guarded_router().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn test_websocket_auth_handshake() {
+    let mut server = WebSocketServer::new("127.0.0.1".to_string(), 0);
+    server.set_auth(ApiKeyAuth::new(["valid-key"]));
+    server.start().await.unwrap();
+    let addr = server.local_addr().await.unwrap();
+
+    let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+    let auth = Envelope::new(WebSocketMessage::Auth {
+        api_key: "valid-key".to_string(),
+    })
+    .encode()
+    .unwrap();
+    client.send(Message::Text(auth)).await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(server.connection_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_websocket_rejects_bad_key() {
+    let mut server = WebSocketServer::new("127.0.0.1".to_string(), 0);
+    server.set_auth(ApiKeyAuth::new(["valid-key"]));
+    server.start().await.unwrap();
+    let addr = server.local_addr().await.unwrap();
+
+    let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+    let auth = Envelope::new(WebSocketMessage::Auth {
+        api_key: "wrong-key".to_string(),
+    })
+    .encode()
+    .unwrap();
+    client.send(Message::Text(auth)).await.unwrap();
+
+    let received = tokio::time::timeout(Duration::from_secs(5), client.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    match Envelope::decode(received.to_text().unwrap()).unwrap() {
+        WebSocketMessage::Error { code, .. } => assert_eq!(code, "unauthorized"),
+        other => panic!("Expected Error, got {:?}", other),
+    }
+    assert_eq!(server.connection_count().await, 0);
+}
+
+#[test]
+fn test_api_key_auth_checks_keys() {
+    let auth = ApiKeyAuth::new(["a", "b"]);
+    assert!(auth.is_authorized("a"));
+    assert!(auth.is_authorized("b"));
+    assert!(!auth.is_authorized("c"));
+    assert!(!auth.is_authorized(""));
+}
@@ -0,0 +1,475 @@
+//! Unit tests for blockchain manager batch submission
+
+use kova_core::blockchain::{BlockchainClient, BlockchainManager, Contribution, SerializationFormat};
+use kova_core::core::Error;
+use sha2::Digest;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Minimal in-memory client for exercising the manager without a network
+struct MemoryClient {
+    storage: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl MemoryClient {
+    fn new() -> Self {
+        Self {
+            storage: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockchainClient for MemoryClient {
+    fn name(&self) -> &str {
+        "Memory"
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    async fn store_data(&self, data: &[u8]) -> Result<String, Error> {
+        let hash = hex::encode(sha2::Sha256::digest(data));
+        self.storage
+            .lock()
+            .unwrap()
+            .insert(hash.clone(), data.to_vec());
+        Ok(hash)
+    }
+
+    async fn retrieve_data(&self, hash: &str) -> Result<Vec<u8>, Error> {
+        self.storage
+            .lock()
+            .unwrap()
+            .get(hash)
+            .cloned()
+            .ok_or_else(|| Error::blockchain(format!("Hash not found: {}", hash)))
+    }
+}
+
+/// Client that reports itself unavailable
+struct OfflineClient;
+
+#[async_trait::async_trait]
+impl BlockchainClient for OfflineClient {
+    fn name(&self) -> &str {
+        "Offline"
+    }
+
+    async fn is_available(&self) -> bool {
+        false
+    }
+
+    async fn store_data(&self, _data: &[u8]) -> Result<String, Error> {
+        Err(Error::blockchain("Offline"))
+    }
+
+    async fn retrieve_data(&self, _hash: &str) -> Result<Vec<u8>, Error> {
+        Err(Error::blockchain("Offline"))
+    }
+}
+
+fn sample_contribution(index: usize) -> Contribution {
+    Contribution {
+        sensor_data_hash: format!("hash_{}", index),
+        validator_signature: "signature".to_string(),
+        timestamp: chrono::Utc::now(),
+        quality_score: 0.9,
+        validator_id: "validator_1".to_string(),
+        sensor_id: "camera_front".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_batch_submission_returns_per_item_hashes() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+
+    let contributions: Vec<Contribution> = (0..3).map(sample_contribution).collect();
+    let hashes = manager.submit_contributions(&contributions).await.unwrap();
+
+    assert_eq!(hashes.len(), 3);
+    // Distinct contributions produce distinct hashes
+    assert_ne!(hashes[0], hashes[1]);
+    assert_ne!(hashes[1], hashes[2]);
+}
+
+#[tokio::test]
+async fn test_batch_pack_stores_single_blob() {
+    let mut manager = BlockchainManager::new();
+    manager.set_batch_pack(true);
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+
+    let contributions: Vec<Contribution> = (0..3).map(sample_contribution).collect();
+    let hashes = manager.submit_contributions(&contributions).await.unwrap();
+
+    assert_eq!(hashes.len(), 1);
+    let data = manager.retrieve_data(&hashes[0]).await.unwrap();
+    let unpacked: Vec<Contribution> = SerializationFormat::decode(&data).unwrap();
+    assert_eq!(unpacked.len(), 3);
+}
+
+#[tokio::test]
+async fn test_availability_reflects_each_client() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+    manager
+        .add_client("offline".to_string(), Box::new(OfflineClient))
+        .await;
+
+    let availability = manager.availability().await;
+
+    assert_eq!(availability.len(), 2);
+    assert_eq!(availability.get("memory"), Some(&true));
+    assert_eq!(availability.get("offline"), Some(&false));
+
+    let mut names = manager.client_names().await;
+    names.sort();
+    assert_eq!(names, vec!["memory", "offline"]);
+}
+
+#[test]
+fn test_cbor_encoding_is_smaller_and_round_trips() {
+    let contribution = sample_contribution(0);
+
+    let json = SerializationFormat::Json.encode(&contribution).unwrap();
+    let cbor = SerializationFormat::Cbor.encode(&contribution).unwrap();
+    let msgpack = SerializationFormat::MessagePack.encode(&contribution).unwrap();
+
+    assert!(cbor.len() < json.len());
+
+    for bytes in [json, cbor, msgpack] {
+        let decoded: Contribution = SerializationFormat::decode(&bytes).unwrap();
+        assert_eq!(decoded.sensor_data_hash, contribution.sensor_data_hash);
+        assert_eq!(decoded.validator_id, contribution.validator_id);
+    }
+}
+
+#[tokio::test]
+async fn test_contribution_round_trips_through_manager() {
+    let mut manager = BlockchainManager::new();
+    manager.set_serialization_format(SerializationFormat::Cbor);
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+
+    let contribution = sample_contribution(1);
+    let hash = manager.submit_contribution(&contribution).await.unwrap();
+    let decoded = manager.retrieve_contribution(&hash).await.unwrap();
+
+    assert_eq!(decoded.sensor_data_hash, contribution.sensor_data_hash);
+}
+
+#[tokio::test]
+async fn test_batch_submission_without_clients_errors() {
+    let manager = BlockchainManager::new();
+    let contributions = vec![sample_contribution(0)];
+
+    assert!(manager.submit_contributions(&contributions).await.is_err());
+}
+
+#[tokio::test]
+async fn test_store_compressed_round_trip_shrinks_blob() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+
+    // Highly compressible payload
+    let data = vec![42u8; 64 * 1024];
+    let hash = manager.store_compressed(&data).await.unwrap();
+
+    let stored = manager.retrieve_data(&hash).await.unwrap();
+    assert!(stored.len() < data.len());
+
+    let restored = manager.retrieve_decompressed(&hash).await.unwrap();
+    assert_eq!(restored, data);
+}
+
+#[tokio::test]
+async fn test_retrieve_decompressed_rejects_uncompressed_blob() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+
+    let hash = manager.store_data(b"raw bytes").await.unwrap();
+    assert!(manager.retrieve_decompressed(&hash).await.is_err());
+}
+
+#[tokio::test]
+async fn test_store_chunked_round_trip() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+
+    let data: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
+    let manifest = manager.store_chunked(&data, 256 * 1024).await.unwrap();
+
+    assert_eq!(manifest.chunk_hashes.len(), 4);
+    assert_eq!(manifest.total_length, data.len());
+
+    let restored = manager.retrieve_chunked(&manifest).await.unwrap();
+    assert_eq!(restored, data);
+}
+
+#[tokio::test]
+async fn test_store_chunked_rejects_zero_chunk_size() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+
+    assert!(manager.store_chunked(b"data", 0).await.is_err());
+}
+
+/// Client that returns different bytes than were stored
+struct TamperingClient;
+
+#[async_trait::async_trait]
+impl BlockchainClient for TamperingClient {
+    fn name(&self) -> &str {
+        "Tampering"
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    async fn store_data(&self, data: &[u8]) -> Result<String, Error> {
+        Ok(hex::encode(sha2::Sha256::digest(data)))
+    }
+
+    async fn retrieve_data(&self, _hash: &str) -> Result<Vec<u8>, Error> {
+        Ok(b"substituted bytes".to_vec())
+    }
+}
+
+#[tokio::test]
+async fn test_retrieve_verified_accepts_matching_bytes() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+
+    let data = b"important payload";
+    let hash = manager.store_data(data).await.unwrap();
+    let expected: [u8; 32] = sha2::Sha256::digest(data).into();
+
+    let retrieved = manager.retrieve_verified(&hash, &expected).await.unwrap();
+    assert_eq!(retrieved, data);
+}
+
+#[tokio::test]
+async fn test_retrieve_verified_detects_tampering() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("tampering".to_string(), Box::new(TamperingClient))
+        .await;
+
+    let data = b"important payload";
+    let hash = manager.store_data(data).await.unwrap();
+    let expected: [u8; 32] = sha2::Sha256::digest(data).into();
+
+    let error = manager.retrieve_verified(&hash, &expected).await.unwrap_err();
+    assert!(error.to_string().contains("Checksum mismatch"));
+}
+
+/// Client that records when it is tried and optionally fails
+struct PriorityProbe {
+    name: String,
+    fail: bool,
+    attempts: std::sync::Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait::async_trait]
+impl BlockchainClient for PriorityProbe {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    async fn store_data(&self, data: &[u8]) -> Result<String, Error> {
+        self.attempts.lock().unwrap().push(self.name.clone());
+        if self.fail {
+            Err(Error::network(format!("{} is down", self.name)))
+        } else {
+            Ok(hex::encode(sha2::Sha256::digest(data)))
+        }
+    }
+
+    async fn retrieve_data(&self, _hash: &str) -> Result<Vec<u8>, Error> {
+        Err(Error::blockchain("Not stored here"))
+    }
+}
+
+#[tokio::test]
+async fn test_store_data_fails_over_in_priority_order() {
+    let attempts = std::sync::Arc::new(Mutex::new(Vec::new()));
+    let manager = BlockchainManager::new();
+
+    // Registered out of priority order on purpose
+    for (name, priority, fail) in [
+        ("durable", 30u32, false),
+        ("fast", 10, true),
+        ("medium", 20, true),
+    ] {
+        manager
+            .add_client_with_priority(
+                name.to_string(),
+                Box::new(PriorityProbe {
+                    name: name.to_string(),
+                    fail,
+                    attempts: attempts.clone(),
+                }),
+                priority,
+            )
+            .await;
+    }
+
+    assert_eq!(
+        manager.client_names().await,
+        vec!["fast", "medium", "durable"]
+    );
+
+    manager.store_data(b"payload").await.unwrap();
+    assert_eq!(
+        *attempts.lock().unwrap(),
+        vec!["fast", "medium", "durable"]
+    );
+}
+
+#[tokio::test]
+async fn test_reregistering_a_client_replaces_it() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+    assert_eq!(manager.client_names().await.len(), 1);
+}
+
+/// Client whose failure behavior can be toggled at runtime
+struct ToggleClient {
+    fail: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    attempts: std::sync::Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait::async_trait]
+impl BlockchainClient for ToggleClient {
+    fn name(&self) -> &str {
+        "Toggle"
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    async fn store_data(&self, data: &[u8]) -> Result<String, Error> {
+        self.attempts.lock().unwrap().push("toggle".to_string());
+        if self.fail.load(std::sync::atomic::Ordering::SeqCst) {
+            Err(Error::network("Backend down"))
+        } else {
+            Ok(hex::encode(sha2::Sha256::digest(data)))
+        }
+    }
+
+    async fn retrieve_data(&self, _hash: &str) -> Result<Vec<u8>, Error> {
+        Err(Error::blockchain("Not stored here"))
+    }
+}
+
+#[tokio::test]
+async fn test_breaker_opens_skips_and_recovers() {
+    use kova_core::blockchain::BreakerState;
+
+    let fail = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
+    let attempts = std::sync::Arc::new(Mutex::new(Vec::new()));
+
+    let mut manager = BlockchainManager::new();
+    manager.set_breaker_policy(2, std::time::Duration::from_millis(100));
+    manager
+        .add_client(
+            "toggle".to_string(),
+            Box::new(ToggleClient {
+                fail: fail.clone(),
+                attempts: attempts.clone(),
+            }),
+        )
+        .await;
+
+    // Two consecutive failures open the breaker
+    assert!(manager.store_data(b"payload").await.is_err());
+    assert!(manager.store_data(b"payload").await.is_err());
+    assert_eq!(
+        manager.breaker_states().await["toggle"],
+        BreakerState::Open
+    );
+
+    // While open, the client is not even tried
+    let tried_before = attempts.lock().unwrap().len();
+    assert!(manager.store_data(b"payload").await.is_err());
+    assert_eq!(attempts.lock().unwrap().len(), tried_before);
+
+    // After the cooldown a trial request goes through and closes the breaker
+    tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+    assert_eq!(
+        manager.breaker_states().await["toggle"],
+        BreakerState::HalfOpen
+    );
+    fail.store(false, std::sync::atomic::Ordering::SeqCst);
+    manager.store_data(b"payload").await.unwrap();
+    assert_eq!(
+        manager.breaker_states().await["toggle"],
+        BreakerState::Closed
+    );
+}
+
+#[tokio::test]
+async fn test_failed_contribution_lands_in_dlq_and_retries() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut manager = BlockchainManager::new();
+    manager.set_dead_letter_queue(dir.path().to_path_buf());
+
+    let contribution = Contribution {
+        sensor_data_hash: "QmHash".to_string(),
+        validator_signature: "sig".to_string(),
+        timestamp: chrono::Utc::now(),
+        quality_score: 0.9,
+        validator_id: "validator_1".to_string(),
+        sensor_id: "camera_1".to_string(),
+    };
+
+    // No clients registered: submission fails and the contribution is queued
+    assert!(manager.submit_contribution(&contribution).await.is_err());
+    assert_eq!(manager.dead_letter_count().unwrap(), 1);
+
+    // Still failing: the retry keeps it queued
+    assert!(manager.retry_dead_letters().await.unwrap().is_empty());
+    assert_eq!(manager.dead_letter_count().unwrap(), 1);
+
+    // With a client restored the retry succeeds and clears the queue
+    manager
+        .add_client("memory".to_string(), Box::new(MemoryClient::new()))
+        .await;
+    let hashes = manager.retry_dead_letters().await.unwrap();
+    assert_eq!(hashes.len(), 1);
+    assert_eq!(manager.dead_letter_count().unwrap(), 0);
+
+    let restored = manager.retrieve_contribution(&hashes[0]).await.unwrap();
+    assert_eq!(restored.sensor_data_hash, contribution.sensor_data_hash);
+    assert_eq!(restored.validator_id, contribution.validator_id);
+}
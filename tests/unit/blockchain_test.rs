@@ -63,3 +63,30 @@ async fn test_data_retrieval() {
     // We expect this to fail in test environment since IPFS node is not running
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_contribution_from_validation_copies_fields() {
+    use kova_core::blockchain::Contribution;
+    use kova_core::core::validation::DataValidator;
+    use std::collections::HashMap;
+
+    let validator = DataValidator::new();
+    let result = validator
+        .validate(b"sensor payload", &HashMap::new())
+        .await
+        .unwrap();
+
+    let contribution = Contribution::from_validation(
+        "QmHash".to_string(),
+        "camera_1".to_string(),
+        "validator_1".to_string(),
+        &result,
+    );
+
+    assert_eq!(contribution.sensor_data_hash, "QmHash");
+    assert_eq!(contribution.sensor_id, "camera_1");
+    assert_eq!(contribution.validator_id, "validator_1");
+    assert_eq!(contribution.quality_score, result.quality_score);
+    assert_eq!(contribution.timestamp, result.timestamp);
+    assert!(contribution.verify(b"sensor payload"));
+}
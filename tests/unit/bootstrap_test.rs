@@ -0,0 +1,20 @@
+//! Unit tests for config-driven bootstrap
+
+use kova_core::{bootstrap, Config};
+
+#[tokio::test]
+async fn test_bootstrap_registers_blockchain_clients() {
+    let config = Config::default();
+    let (_sensor_manager, blockchain_manager, _validator) = bootstrap(&config).await.unwrap();
+
+    let mut clients = blockchain_manager.client_names().await;
+    clients.sort();
+    assert_eq!(clients, vec!["arweave", "ipfs", "solana"]);
+}
+
+#[tokio::test]
+async fn test_bootstrap_starts_with_no_sensors() {
+    let config = Config::default();
+    let (sensor_manager, _blockchain_manager, _validator) = bootstrap(&config).await.unwrap();
+    assert!(sensor_manager.list_sensors().await.is_empty());
+}
@@ -0,0 +1,48 @@
+//! Unit tests for sensor calibration persistence
+
+use kova_core::sensors::imu::{IMUConfig, IMU};
+use kova_core::sensors::thermal::{Thermal, ThermalConfig};
+
+#[tokio::test]
+async fn test_thermal_calibration_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("thermal_calibration.json");
+
+    let mut thermal = Thermal::new("thermal_1".to_string(), ThermalConfig::default()).unwrap();
+    thermal.initialize().await.unwrap();
+    let saved = thermal.config().calibration_data.clone().unwrap();
+    thermal.save_calibration(&path).unwrap();
+
+    let mut restored = Thermal::new("thermal_2".to_string(), ThermalConfig::default()).unwrap();
+    restored.load_calibration(&path).unwrap();
+    restored.initialize().await.unwrap();
+
+    assert_eq!(restored.config().calibration_data.as_ref(), Some(&saved));
+}
+
+#[tokio::test]
+async fn test_imu_calibration_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("imu_calibration.json");
+
+    let mut imu = IMU::new("imu_1".to_string(), IMUConfig::default()).unwrap();
+    imu.initialize().await.unwrap();
+    let saved = imu.calibration().cloned().unwrap();
+    imu.save_calibration(&path).unwrap();
+
+    let mut restored = IMU::new("imu_2".to_string(), IMUConfig::default()).unwrap();
+    restored.load_calibration(&path).unwrap();
+    restored.initialize().await.unwrap();
+
+    assert_eq!(restored.calibration(), Some(&saved));
+}
+
+#[test]
+fn test_save_without_calibration_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("never_written.json");
+
+    let imu = IMU::new("imu_1".to_string(), IMUConfig::default()).unwrap();
+    assert!(imu.save_calibration(&path).is_err());
+    assert!(!path.exists());
+}
@@ -0,0 +1,68 @@
+//! Unit tests for camera configuration validation
+
+use kova_core::sensors::camera::{Camera, CameraConfig};
+
+#[test]
+fn test_default_config_is_accepted() {
+    assert!(Camera::new("camera_1".to_string(), CameraConfig::default()).is_ok());
+}
+
+#[test]
+fn test_zero_resolution_is_rejected() {
+    let mut config = CameraConfig::default();
+    config.resolution = (0, 1080);
+    assert!(Camera::new("camera_1".to_string(), config).is_err());
+}
+
+#[test]
+fn test_zero_frame_rate_is_rejected() {
+    let mut config = CameraConfig::default();
+    config.frame_rate = 0;
+    assert!(Camera::new("camera_1".to_string(), config).is_err());
+}
+
+#[test]
+fn test_resolution_over_maximum_is_rejected() {
+    let mut config = CameraConfig::default();
+    config.max_resolution = (1920, 1080);
+    config.resolution = (3840, 2160);
+    assert!(Camera::new("camera_1".to_string(), config).is_err());
+}
+
+#[tokio::test]
+async fn test_roi_crops_captured_frame() {
+    let mut camera = Camera::new("camera_1".to_string(), CameraConfig::default()).unwrap();
+    camera.initialize().await.unwrap();
+
+    camera.set_roi(100, 50, 320, 240).unwrap();
+    let frame = camera.capture().await.unwrap();
+
+    // Default format is RGB: three bytes per pixel
+    assert_eq!(frame.len(), 320 * 240 * 3);
+
+    camera.clear_roi();
+    let frame = camera.capture().await.unwrap();
+    assert_eq!(frame.len(), 1920 * 1080 * 3);
+}
+
+#[test]
+fn test_roi_outside_frame_is_rejected() {
+    let mut camera = Camera::new("camera_1".to_string(), CameraConfig::default()).unwrap();
+    assert!(camera.set_roi(1800, 0, 320, 240).is_err());
+    assert!(camera.set_roi(0, 0, 0, 240).is_err());
+    assert!(camera.roi().is_none());
+}
+
+#[tokio::test]
+async fn test_roi_invalidated_by_config_update_errors() {
+    let mut camera = Camera::new("camera_1".to_string(), CameraConfig::default()).unwrap();
+    camera.initialize().await.unwrap();
+    camera.set_roi(0, 0, 1920, 1080).unwrap();
+
+    let mut config = CameraConfig::default();
+    config.resolution = (640, 480);
+    camera.update_config(config);
+
+    // The ROI no longer fits the frame; capture must error, not panic
+    assert!(camera.capture().await.is_err());
+}
@@ -0,0 +1,66 @@
+//! Unit tests for capture timeouts in the sensor manager
+
+use kova_core::core::Error;
+use kova_core::sensors::camera::{Camera, CameraConfig};
+use kova_core::sensors::{Sensor, SensorData, SensorManager, SensorType};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// A sensor whose captures never complete
+struct StuckSensor;
+
+#[async_trait::async_trait]
+impl Sensor for StuckSensor {
+    fn id(&self) -> &str {
+        "stuck"
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        SensorType::LiDAR
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        tokio::time::sleep(Duration::from_secs(3600)).await;
+        unreachable!("capture should have been timed out")
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &"stuck"
+    }
+}
+
+#[tokio::test]
+async fn test_stuck_sensor_does_not_hang_the_batch() {
+    let mut manager = SensorManager::new();
+    manager.set_capture_timeout(Duration::from_millis(100));
+
+    manager.add_sensor(Box::new(StuckSensor)).await.unwrap();
+
+    let mut camera = Camera::new("camera_1".to_string(), CameraConfig::default()).unwrap();
+    camera.initialize().await.unwrap();
+    manager.add_sensor(Box::new(camera)).await.unwrap();
+
+    let frames = tokio::time::timeout(Duration::from_secs(5), manager.capture_all())
+        .await
+        .expect("capture_all must not hang")
+        .unwrap();
+
+    assert_eq!(frames.len(), 1);
+    assert_eq!(frames[0].sensor_id, "camera_1");
+}
+
+#[tokio::test]
+async fn test_timed_out_capture_counts_as_failure() {
+    let mut manager = SensorManager::new();
+    manager.set_capture_timeout(Duration::from_millis(100));
+    manager.add_sensor(Box::new(StuckSensor)).await.unwrap();
+
+    let _ = manager.capture_all().await.unwrap();
+
+    let metrics = manager.metrics().await;
+    assert_eq!(metrics["stuck"].consecutive_failures, 1);
+}
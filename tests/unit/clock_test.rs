@@ -0,0 +1,69 @@
+//! Unit tests for the pluggable clock
+//!
+//! Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use chrono::{Duration, TimeZone, Utc};
+use kova_core::core::validation::DataValidator;
+use kova_core::core::MockClock;
+use kova_core::sensors::{SensorData, SensorType};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn frame_at(timestamp: chrono::DateTime<Utc>) -> SensorData {
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: "camera_1".to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp,
+        data: vec![128; 1024],
+        metadata: HashMap::new(),
+        checksum: None,
+    }
+}
+
+#[tokio::test]
+async fn test_mock_clock_drives_temporal_consistency() {
+    let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+    let clock = Arc::new(MockClock::new(start));
+
+    let mut validator = DataValidator::new();
+    validator.set_clock(clock.clone());
+
+    // A frame captured "now" passes the temporal window
+    let result = validator.validate_frame(&frame_at(start)).await.unwrap();
+    let fresh_valid = result.is_valid;
+
+    // Advancing the clock past the window invalidates the same frame
+    clock.advance(Duration::minutes(10));
+    let result = validator.validate_frame(&frame_at(start)).await.unwrap();
+    assert!(!result.is_valid);
+
+    // Bringing the clock back restores the original verdict
+    clock.set(start);
+    let result = validator.validate_frame(&frame_at(start)).await.unwrap();
+    assert_eq!(result.is_valid, fresh_valid);
+}
+
+#[tokio::test]
+async fn test_validation_timestamp_comes_from_clock() {
+    let start = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+    let clock = Arc::new(MockClock::new(start));
+
+    let mut validator = DataValidator::new();
+    validator.set_clock(clock.clone());
+
+    let result = validator
+        .validate(&[0u8; 64], &HashMap::new())
+        .await
+        .unwrap();
+    assert_eq!(result.timestamp, start);
+
+    clock.advance(Duration::seconds(42));
+    let result = validator
+        .validate(&[0u8; 64], &HashMap::new())
+        .await
+        .unwrap();
+    assert_eq!(result.timestamp, start + Duration::seconds(42));
+}
@@ -0,0 +1,67 @@
+//! Unit tests for the compression-ratio quality metric
+
+use kova_core::core::validation::{DataValidator, ValidationConfig};
+use std::collections::HashMap;
+
+fn validator(enable_compression_metric: bool) -> DataValidator {
+    DataValidator::with_config(ValidationConfig {
+        enable_compression_metric,
+        ..ValidationConfig::default()
+    })
+}
+
+/// Highly repetitive, very compressible payload
+fn structured() -> Vec<u8> {
+    b"kova sensor frame ".repeat(64)
+}
+
+/// Pseudo-random, incompressible payload of similar size
+fn random() -> Vec<u8> {
+    let mut state = 0x12345678u32;
+    (0..1152)
+        .map(|_| {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            (state >> 24) as u8
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_structured_data_compresses_better() {
+    let validator = validator(true);
+
+    let structured_result = validator
+        .validate(&structured(), &HashMap::new())
+        .await
+        .unwrap();
+    let random_result = validator.validate(&random(), &HashMap::new()).await.unwrap();
+
+    assert!(
+        structured_result.metrics.compression_ratio > random_result.metrics.compression_ratio
+    );
+    assert!(structured_result.metrics.compression_ratio > 0.5);
+    assert!(random_result.metrics.compression_ratio < 0.2);
+}
+
+#[tokio::test]
+async fn test_metric_contributes_to_the_score() {
+    let with_metric = validator(true);
+    let without_metric = validator(false);
+
+    // Incompressible, noise-like data is penalized when the metric is on
+    let scored = with_metric.validate(&random(), &HashMap::new()).await.unwrap();
+    let baseline = without_metric
+        .validate(&random(), &HashMap::new())
+        .await
+        .unwrap();
+    assert!(scored.quality_score < baseline.quality_score);
+}
+
+#[tokio::test]
+async fn test_metric_is_zero_when_disabled() {
+    let result = validator(false)
+        .validate(&structured(), &HashMap::new())
+        .await
+        .unwrap();
+    assert_eq!(result.metrics.compression_ratio, 0.0);
+}
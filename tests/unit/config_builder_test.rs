@@ -0,0 +1,82 @@
+//! Unit tests for the sensor config builders
+
+use kova_core::sensors::camera::{CameraConfig, ImageFormat};
+use kova_core::sensors::imu::IMUConfig;
+use kova_core::sensors::lidar::{LiDARConfig, PointCloudFormat};
+
+#[test]
+fn test_camera_builder_chain() {
+    let config = CameraConfig::builder()
+        .resolution(3840, 2160)
+        .frame_rate(60)
+        .format(ImageFormat::RGBA)
+        .exposure_compensation(1.5)
+        .iso_sensitivity(400)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.resolution, (3840, 2160));
+    assert_eq!(config.frame_rate, 60);
+    assert_eq!(config.format, ImageFormat::RGBA);
+    assert_eq!(config.iso_sensitivity, 400);
+    // Untouched fields keep their defaults
+    assert!(config.auto_exposure);
+}
+
+#[test]
+fn test_camera_builder_rejects_bad_values() {
+    assert!(CameraConfig::builder().resolution(0, 1080).build().is_err());
+    assert!(CameraConfig::builder().frame_rate(0).build().is_err());
+    assert!(CameraConfig::builder()
+        .exposure_compensation(3.0)
+        .build()
+        .is_err());
+    assert!(CameraConfig::builder()
+        .resolution(10_000, 10_000)
+        .build()
+        .is_err());
+}
+
+#[test]
+fn test_lidar_builder_chain() {
+    let config = LiDARConfig::builder()
+        .range(0.5, 200.0)
+        .scan_frequency(20.0)
+        .laser_count(64)
+        .point_cloud_format(PointCloudFormat::XYZIR)
+        .field_of_view(45.0, 360.0)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.range_min, 0.5);
+    assert_eq!(config.range_max, 200.0);
+    assert_eq!(config.laser_count, 64);
+    assert_eq!(config.point_cloud_format, PointCloudFormat::XYZIR);
+}
+
+#[test]
+fn test_lidar_builder_rejects_bad_values() {
+    assert!(LiDARConfig::builder().range(100.0, 1.0).build().is_err());
+    assert!(LiDARConfig::builder().scan_frequency(0.0).build().is_err());
+    assert!(LiDARConfig::builder().laser_count(0).build().is_err());
+}
+
+#[test]
+fn test_imu_builder_chain() {
+    let config = IMUConfig::builder()
+        .sample_rate(200.0)
+        .accelerometer_range(8.0)
+        .magnetometer_enabled(false)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.sample_rate, 200.0);
+    assert_eq!(config.accelerometer_range, 8.0);
+    assert!(!config.magnetometer_enabled);
+}
+
+#[test]
+fn test_imu_builder_rejects_bad_values() {
+    assert!(IMUConfig::builder().sample_rate(-1.0).build().is_err());
+    assert!(IMUConfig::builder().gyroscope_range(0.0).build().is_err());
+}
@@ -0,0 +1,66 @@
+//! Unit tests for configuration module
+
+use kova_core::core::config::Config;
+
+#[test]
+fn test_env_override_applied() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+
+    let config = Config::default();
+    config.to_file(&path).unwrap();
+
+    std::env::set_var("KOVA_SOLANA_RPC_URL", "http://localhost:8899");
+    std::env::set_var("KOVA_VALIDATION_MIN_QUALITY_SCORE", "0.9");
+
+    let loaded = Config::from_file_with_env(&path).unwrap();
+
+    assert_eq!(loaded.blockchain.solana.rpc_url, "http://localhost:8899");
+    assert!((loaded.validation.min_quality_score - 0.9).abs() < f64::EPSILON);
+    // Fields without overrides keep the file values
+    assert_eq!(loaded.network.max_connections, config.network.max_connections);
+
+    std::env::remove_var("KOVA_SOLANA_RPC_URL");
+    std::env::remove_var("KOVA_VALIDATION_MIN_QUALITY_SCORE");
+}
+
+#[test]
+fn test_layered_config_override_wins_field_by_field() {
+    let dir = tempfile::tempdir().unwrap();
+    let base_path = dir.path().join("base.toml");
+    let override_path = dir.path().join("override.toml");
+
+    let base = Config::default();
+    base.to_file(&base_path).unwrap();
+    std::fs::write(&override_path, "[network]\nmax_connections = 7\n").unwrap();
+
+    let layered = Config::from_layered(&[&base_path, &override_path]).unwrap();
+
+    assert_eq!(layered.network.max_connections, 7);
+    // Everything else is preserved from the base
+    assert_eq!(layered.network.pool_size, base.network.pool_size);
+    assert_eq!(
+        layered.blockchain.solana.rpc_url,
+        base.blockchain.solana.rpc_url
+    );
+    assert_eq!(layered.sensors.timeout_seconds, base.sensors.timeout_seconds);
+    assert!(
+        (layered.validation.min_quality_score - base.validation.min_quality_score).abs()
+            < f64::EPSILON
+    );
+}
+
+#[test]
+fn test_env_override_unparseable_value_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+
+    Config::default().to_file(&path).unwrap();
+
+    std::env::set_var("KOVA_NETWORK_MAX_CONNECTIONS", "not_a_number");
+
+    let result = Config::from_file_with_env(&path);
+    assert!(result.is_err());
+
+    std::env::remove_var("KOVA_NETWORK_MAX_CONNECTIONS");
+}
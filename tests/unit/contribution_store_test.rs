@@ -0,0 +1,99 @@
+//! Unit tests for the contribution store
+
+use chrono::{Duration, Utc};
+use kova_core::blockchain::{Contribution, ContributionFilter, ContributionStore};
+
+fn contribution(validator_id: &str, quality_score: f64, age_seconds: i64) -> Contribution {
+    Contribution {
+        sensor_data_hash: format!("hash_{}_{}", validator_id, age_seconds),
+        validator_signature: "signature".to_string(),
+        timestamp: Utc::now() - Duration::seconds(age_seconds),
+        quality_score,
+        validator_id: validator_id.to_string(),
+        sensor_id: "camera_front".to_string(),
+    }
+}
+
+async fn seeded_store() -> ContributionStore {
+    let store = ContributionStore::new();
+    store.add(contribution("validator_a", 0.9, 30)).await;
+    store.add(contribution("validator_a", 0.6, 20)).await;
+    store.add(contribution("validator_b", 0.8, 10)).await;
+    store.add(contribution("validator_b", 0.95, 0)).await;
+    store
+}
+
+#[tokio::test]
+async fn test_filter_by_validator() {
+    let store = seeded_store().await;
+
+    let results = store
+        .query(ContributionFilter {
+            validator_id: Some("validator_a".to_string()),
+            ..Default::default()
+        })
+        .await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|c| c.validator_id == "validator_a"));
+}
+
+#[tokio::test]
+async fn test_min_quality_cutoff() {
+    let store = seeded_store().await;
+
+    let results = store
+        .query(ContributionFilter {
+            min_quality_score: Some(0.85),
+            ..Default::default()
+        })
+        .await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|c| c.quality_score >= 0.85));
+}
+
+#[tokio::test]
+async fn test_results_ordered_newest_first() {
+    let store = seeded_store().await;
+
+    let results = store.query(ContributionFilter::default()).await;
+
+    assert_eq!(results.len(), 4);
+    for window in results.windows(2) {
+        assert!(window[0].timestamp >= window[1].timestamp);
+    }
+}
+
+#[tokio::test]
+async fn test_offset_and_limit_boundaries() {
+    let store = seeded_store().await;
+
+    let page = store
+        .query(ContributionFilter {
+            limit: Some(2),
+            offset: 1,
+            ..Default::default()
+        })
+        .await;
+    assert_eq!(page.len(), 2);
+
+    // Offset past the end yields an empty page
+    let empty = store
+        .query(ContributionFilter {
+            offset: 10,
+            ..Default::default()
+        })
+        .await;
+    assert!(empty.is_empty());
+
+    // Limit larger than the remainder is clamped
+    let tail = store
+        .query(ContributionFilter {
+            limit: Some(10),
+            offset: 3,
+            ..Default::default()
+        })
+        .await;
+    assert_eq!(tail.len(), 1);
+}
@@ -0,0 +1,53 @@
+//! Unit tests for contribution signature verification
+
+use kova_core::blockchain::Contribution;
+use kova_core::core::validation::DataValidator;
+use std::collections::HashMap;
+
+async fn signed_contribution(data: &[u8]) -> Contribution {
+    let validator = DataValidator::new();
+    let result = validator.validate(data, &HashMap::new()).await.unwrap();
+    Contribution::from_validation(
+        "QmHash".to_string(),
+        "camera_1".to_string(),
+        "validator_1".to_string(),
+        &result,
+    )
+}
+
+#[tokio::test]
+async fn test_genuine_contribution_verifies() {
+    let data = b"sensor payload";
+    let contribution = signed_contribution(data).await;
+    assert!(contribution.verify(data));
+}
+
+#[tokio::test]
+async fn test_wrong_data_fails() {
+    let contribution = signed_contribution(b"sensor payload").await;
+    assert!(!contribution.verify(b"different payload"));
+}
+
+#[tokio::test]
+async fn test_tampered_data_hash_fails() {
+    let data = b"sensor payload";
+    let mut contribution = signed_contribution(data).await;
+    contribution.sensor_data_hash = "QmForged".to_string();
+    assert!(!contribution.verify(data));
+}
+
+#[tokio::test]
+async fn test_altered_quality_score_fails() {
+    let data = b"sensor payload";
+    let mut contribution = signed_contribution(data).await;
+    contribution.quality_score = 1.0;
+    assert!(!contribution.verify(data));
+}
+
+#[tokio::test]
+async fn test_altered_timestamp_fails() {
+    let data = b"sensor payload";
+    let mut contribution = signed_contribution(data).await;
+    contribution.timestamp = contribution.timestamp + chrono::Duration::seconds(1);
+    assert!(!contribution.verify(data));
+}
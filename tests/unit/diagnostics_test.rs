@@ -0,0 +1,79 @@
+//! Unit tests for the system diagnostics report
+//!
+//! Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use kova_core::blockchain::{BlockchainManager, MockBlockchainClient};
+use kova_core::sensors::{MockSensor, SensorData, SensorManager, SensorType};
+use std::collections::HashMap;
+
+fn frame(sensor_id: &str) -> SensorData {
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: sensor_id.to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::Utc::now(),
+        data: vec![1, 2, 3],
+        metadata: HashMap::new(),
+        checksum: None,
+    }
+}
+
+#[tokio::test]
+async fn test_report_covers_sensors_and_clients() {
+    let sensors = SensorManager::new();
+    sensors
+        .add_sensor(Box::new(MockSensor::new(
+            "camera_1".to_string(),
+            SensorType::Camera,
+            vec![frame("camera_1")],
+        )))
+        .await
+        .unwrap();
+    sensors
+        .add_sensor(Box::new(MockSensor::new(
+            "imu_1".to_string(),
+            SensorType::IMU,
+            vec![frame("imu_1")],
+        )))
+        .await
+        .unwrap();
+
+    let blockchain = BlockchainManager::new();
+    blockchain
+        .add_client("up".to_string(), Box::new(MockBlockchainClient::new()))
+        .await;
+    blockchain
+        .add_client(
+            "down".to_string(),
+            Box::new(MockBlockchainClient::unavailable()),
+        )
+        .await;
+
+    let report = kova_core::diagnostics(&sensors, &blockchain).await;
+
+    assert_eq!(report.version, env!("CARGO_PKG_VERSION"));
+
+    let sensor_ids: Vec<&str> = report.sensors.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(sensor_ids, vec!["camera_1", "imu_1"]);
+    assert!(report.sensors.iter().all(|s| s.available));
+
+    let by_name: HashMap<&str, bool> = report
+        .blockchain
+        .iter()
+        .map(|c| (c.name.as_str(), c.available))
+        .collect();
+    assert!(by_name["up"]);
+    assert!(!by_name["down"]);
+}
+
+#[tokio::test]
+async fn test_report_serializes_to_json() {
+    let report = kova_core::diagnostics(&SensorManager::new(), &BlockchainManager::new()).await;
+
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json["version"].is_string());
+    assert!(json["sensors"].as_array().unwrap().is_empty());
+    assert!(json["blockchain"].as_array().unwrap().is_empty());
+}
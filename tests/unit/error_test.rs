@@ -0,0 +1,60 @@
+//! Unit tests for structured error variants
+
+use kova_core::blockchain::{BlockchainClient, IPFSClient};
+use kova_core::core::error::{BlockchainError, Error};
+
+#[tokio::test]
+async fn test_connection_failure_maps_to_unreachable() {
+    // Nothing listens on this port, so the connection is refused
+    let config = kova_core::blockchain::ipfs::IPFSConfig {
+        api_url: "http://127.0.0.1:1".to_string(),
+        timeout_seconds: 2,
+        ..Default::default()
+    };
+    let client = IPFSClient::new(config).await.unwrap();
+
+    let result = client.store_data(b"payload").await;
+
+    match result {
+        Err(Error::Blockchain(BlockchainError::Unreachable(_))) => {}
+        other => panic!("Expected Unreachable, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_not_found_status_maps_to_not_found() {
+    let error = BlockchainError::from_status("Failed to get data", reqwest::StatusCode::NOT_FOUND);
+    assert!(matches!(error, BlockchainError::NotFound(_)));
+}
+
+#[test]
+fn test_timeout_status_maps_to_timeout() {
+    let error =
+        BlockchainError::from_status("Failed to get data", reqwest::StatusCode::GATEWAY_TIMEOUT);
+    assert!(matches!(error, BlockchainError::Timeout(_)));
+}
+
+#[test]
+fn test_transient_errors_are_retryable() {
+    assert!(Error::from(BlockchainError::Unreachable("node down".to_string())).is_retryable());
+    assert!(Error::from(BlockchainError::Timeout("slow node".to_string())).is_retryable());
+    assert!(Error::network("connection reset").is_retryable());
+}
+
+#[test]
+fn test_permanent_errors_are_not_retryable() {
+    assert!(!Error::from(BlockchainError::MalformedResponse("bad json".to_string()))
+        .is_retryable());
+    assert!(!Error::from(BlockchainError::NotFound("missing tx".to_string())).is_retryable());
+    assert!(!Error::validation("quality below threshold").is_retryable());
+    assert!(!Error::config("missing field").is_retryable());
+}
+
+#[test]
+fn test_display_preserves_blockchain_prefix() {
+    let error = Error::blockchain("No available blockchain clients");
+    assert_eq!(
+        error.to_string(),
+        "Blockchain error: No available blockchain clients"
+    );
+}
@@ -0,0 +1,101 @@
+//! Unit tests for cross-sensor frame synchronization
+
+use chrono::{Duration, TimeZone, Utc};
+use kova_core::sensors::{FrameSynchronizer, SensorData, SensorType};
+use std::collections::HashMap;
+
+/// Build a frame for a sensor at a millisecond offset from a fixed base time
+fn frame(sensor_id: &str, sensor_type: SensorType, offset_ms: i64) -> SensorData {
+    let base = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: sensor_id.to_string(),
+        sensor_type,
+        timestamp: base + Duration::milliseconds(offset_ms),
+        data: vec![0u8; 8],
+        metadata: HashMap::new(),
+        checksum: None,
+    }
+}
+
+fn sensors() -> Vec<String> {
+    vec![
+        "camera_01".to_string(),
+        "lidar_01".to_string(),
+        "imu_01".to_string(),
+    ]
+}
+
+#[test]
+fn test_emits_nearest_matches_within_tolerance() {
+    let mut sync =
+        FrameSynchronizer::new("camera_01", &sensors(), Duration::milliseconds(50)).unwrap();
+
+    // Two candidates per sensor; the later ones are nearer to the reference at t=100
+    assert!(sync.push(frame("lidar_01", SensorType::LiDAR, 20)).is_none());
+    assert!(sync.push(frame("lidar_01", SensorType::LiDAR, 110)).is_none());
+    assert!(sync.push(frame("imu_01", SensorType::IMU, 40)).is_none());
+    assert!(sync.push(frame("imu_01", SensorType::IMU, 95)).is_none());
+
+    let set = sync
+        .push(frame("camera_01", SensorType::Camera, 100))
+        .expect("aligned set should complete");
+
+    assert_eq!(set.len(), 3);
+    assert_eq!(set[0].sensor_id, "camera_01");
+    assert_eq!(set[1].sensor_id, "lidar_01");
+    assert_eq!(set[1].timestamp.timestamp_subsec_millis(), 110);
+    assert_eq!(set[2].sensor_id, "imu_01");
+    assert_eq!(set[2].timestamp.timestamp_subsec_millis(), 95);
+}
+
+#[test]
+fn test_waits_until_all_sensors_have_frames() {
+    let mut sync =
+        FrameSynchronizer::new("camera_01", &sensors(), Duration::milliseconds(50)).unwrap();
+
+    assert!(sync
+        .push(frame("camera_01", SensorType::Camera, 100))
+        .is_none());
+    assert!(sync.push(frame("lidar_01", SensorType::LiDAR, 90)).is_none());
+
+    // The IMU frame completes the set
+    let set = sync.push(frame("imu_01", SensorType::IMU, 120)).unwrap();
+    assert_eq!(set.len(), 3);
+    assert_eq!(sync.dropped_sets(), 0);
+}
+
+#[test]
+fn test_drops_sets_outside_tolerance() {
+    let mut sync =
+        FrameSynchronizer::new("camera_01", &sensors(), Duration::milliseconds(50)).unwrap();
+
+    assert!(sync
+        .push(frame("camera_01", SensorType::Camera, 0))
+        .is_none());
+    assert!(sync.push(frame("imu_01", SensorType::IMU, 10)).is_none());
+    // LiDAR jumps past the window, so the reference frame at t=0 can never align
+    assert!(sync.push(frame("lidar_01", SensorType::LiDAR, 500)).is_none());
+
+    assert_eq!(sync.dropped_sets(), 1);
+
+    // A fresh reference near later frames still aligns
+    assert!(sync.push(frame("imu_01", SensorType::IMU, 480)).is_none());
+    let set = sync
+        .push(frame("camera_01", SensorType::Camera, 490))
+        .unwrap();
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn test_unknown_sensor_frames_are_ignored() {
+    let mut sync =
+        FrameSynchronizer::new("camera_01", &sensors(), Duration::milliseconds(50)).unwrap();
+    assert!(sync.push(frame("gps_01", SensorType::GPS, 0)).is_none());
+    assert_eq!(sync.dropped_sets(), 0);
+}
+
+#[test]
+fn test_reference_must_be_listed() {
+    assert!(FrameSynchronizer::new("radar_01", &sensors(), Duration::milliseconds(50)).is_err());
+}
@@ -0,0 +1,89 @@
+//! Unit tests for frame id correlation across capture, validate, and store
+//!
+//! Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use kova_core::blockchain::{BlockchainManager, MockBlockchainClient};
+use kova_core::core::validation::DataValidator;
+use kova_core::sensors::camera::{Camera, CameraConfig};
+use kova_core::sensors::SensorManager;
+use std::io;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Collects formatted log output into a shared buffer
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl LogBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+    }
+}
+
+impl io::Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for LogBuffer {
+    type Writer = LogBuffer;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[test]
+fn test_frame_id_appears_at_every_stage() {
+    let logs = LogBuffer::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(logs.clone())
+        .with_ansi(false)
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let frame_id = runtime.block_on(async {
+        let manager = SensorManager::new();
+        let mut camera = Camera::new("camera_1".to_string(), CameraConfig::default()).unwrap();
+        camera.initialize().await.unwrap();
+        manager.add_sensor(Box::new(camera)).await.unwrap();
+
+        let frames = manager.capture_all().await.unwrap();
+        let frame = &frames[0];
+
+        let validator = DataValidator::new();
+        validator.validate_frame(frame).await.unwrap();
+
+        let blockchain = BlockchainManager::new();
+        blockchain
+            .add_client("mock".to_string(), Box::new(MockBlockchainClient::new()))
+            .await;
+        blockchain.store_frame(frame).await.unwrap();
+
+        frame.frame_id
+    });
+
+    let output = logs.contents();
+    let id = frame_id.to_string();
+
+    for stage in ["Captured frame", "Validating frame", "Storing frame"] {
+        let line = output
+            .lines()
+            .find(|line| line.contains(stage))
+            .unwrap_or_else(|| panic!("no log record for stage: {}", stage));
+        assert!(line.contains(&id), "stage '{}' missing frame id", stage);
+    }
+}
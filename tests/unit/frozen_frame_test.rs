@@ -0,0 +1,109 @@
+//! Unit tests for frozen-frame detection
+
+use kova_core::core::validation::DataValidator;
+use kova_core::sensors::{SensorData, SensorType};
+use std::collections::HashMap;
+
+fn frame(sensor_id: &str, data: Vec<u8>) -> SensorData {
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: sensor_id.to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::Utc::now(),
+        data,
+        metadata: HashMap::new(),
+        checksum: None,
+    }
+}
+
+#[tokio::test]
+async fn test_frozen_flag_trips_after_threshold() {
+    let validator = DataValidator::new();
+    let payload = vec![0xABu8; 128];
+
+    // Default threshold is three identical repeats: the first capture plus
+    // three replays pass before the fourth replay trips the flag
+    for _ in 0..3 {
+        let result = validator
+            .validate_frame(&frame("camera_01", payload.clone()))
+            .await
+            .unwrap();
+        assert!(!result.frozen);
+    }
+
+    let result = validator
+        .validate_frame(&frame("camera_01", payload.clone()))
+        .await
+        .unwrap();
+    assert!(result.frozen);
+}
+
+#[tokio::test]
+async fn test_frozen_frames_lose_quality() {
+    let validator = DataValidator::new();
+    let payload = vec![0xABu8; 128];
+
+    let fresh = validator
+        .validate_frame(&frame("camera_01", payload.clone()))
+        .await
+        .unwrap();
+
+    let mut frozen = fresh.clone();
+    for _ in 0..4 {
+        frozen = validator
+            .validate_frame(&frame("camera_01", payload.clone()))
+            .await
+            .unwrap();
+    }
+
+    assert!(frozen.frozen);
+    assert!(frozen.quality_score < fresh.quality_score);
+}
+
+#[tokio::test]
+async fn test_changed_content_resets_the_counter() {
+    let mut validator = DataValidator::new();
+    validator.set_frozen_frame_threshold(1);
+
+    let result = validator
+        .validate_frame(&frame("camera_01", vec![1u8; 64]))
+        .await
+        .unwrap();
+    assert!(!result.frozen);
+
+    let result = validator
+        .validate_frame(&frame("camera_01", vec![1u8; 64]))
+        .await
+        .unwrap();
+    assert!(result.frozen);
+
+    // New content thaws the sensor
+    let result = validator
+        .validate_frame(&frame("camera_01", vec![2u8; 64]))
+        .await
+        .unwrap();
+    assert!(!result.frozen);
+}
+
+#[tokio::test]
+async fn test_tracking_is_per_sensor() {
+    let mut validator = DataValidator::new();
+    validator.set_frozen_frame_threshold(1);
+    let payload = vec![7u8; 64];
+
+    validator
+        .validate_frame(&frame("camera_01", payload.clone()))
+        .await
+        .unwrap();
+    validator
+        .validate_frame(&frame("camera_01", payload.clone()))
+        .await
+        .unwrap();
+
+    // The same bytes from a different sensor start a fresh counter
+    let result = validator
+        .validate_frame(&frame("camera_02", payload.clone()))
+        .await
+        .unwrap();
+    assert!(!result.frozen);
+}
@@ -0,0 +1,71 @@
+//! Unit tests for GPS + IMU dead reckoning
+
+use kova_core::sensors::fusion::DeadReckoner;
+use kova_core::sensors::gps::{FixQuality, GPSData};
+use kova_core::sensors::imu::IMUData;
+
+const METERS_PER_DEGREE: f64 = 111_320.0;
+
+fn gps_fix(
+    latitude: f64,
+    speed: f64,
+    timestamp: chrono::DateTime<chrono::Utc>,
+) -> GPSData {
+    GPSData {
+        latitude,
+        longitude: 0.0,
+        altitude: 10.0,
+        accuracy: 1.0,
+        speed,
+        heading: 0.0,
+        satellite_count: 9,
+        hdop: 1.1,
+        vdop: 1.5,
+        pdop: 1.9,
+        fix_quality: FixQuality::GPSFix,
+        timestamp,
+    }
+}
+
+fn coasting_imu_sample(timestamp: chrono::DateTime<chrono::Utc>) -> IMUData {
+    IMUData {
+        linear_acceleration: [0.0, 0.0, 0.0],
+        angular_velocity: [0.0, 0.0, 0.0],
+        magnetic_field: None,
+        temperature: None,
+        timestamp,
+    }
+}
+
+#[test]
+fn test_no_estimate_before_first_fix() {
+    let mut reckoner = DeadReckoner::new();
+    reckoner.update_imu(&coasting_imu_sample(chrono::Utc::now()));
+
+    assert!(reckoner.current_estimate().is_none());
+}
+
+#[test]
+fn test_straight_line_drive_through_gps_gap() {
+    let start = chrono::Utc::now();
+    let mut reckoner = DeadReckoner::new();
+
+    // Driving due north at 5 m/s when the GPS drops out
+    reckoner.update_gps(&gps_fix(0.0, 5.0, start));
+
+    for second in 1..=10 {
+        let timestamp = start + chrono::Duration::seconds(second);
+        reckoner.update_imu(&coasting_imu_sample(timestamp));
+    }
+
+    let estimate = reckoner.current_estimate().unwrap();
+    let expected_latitude = 50.0 / METERS_PER_DEGREE;
+    let error_meters = (estimate.latitude - expected_latitude).abs() * METERS_PER_DEGREE;
+    assert!(error_meters < 5.0, "position error {} m", error_meters);
+    assert!(estimate.longitude.abs() * METERS_PER_DEGREE < 1.0);
+
+    // A new fix snaps the estimate back to the measurement
+    let fix = gps_fix(expected_latitude, 5.0, start + chrono::Duration::seconds(11));
+    reckoner.update_gps(&fix);
+    assert_eq!(reckoner.current_estimate().unwrap().latitude, expected_latitude);
+}
@@ -0,0 +1,62 @@
+//! Unit tests for the GPS geofence check
+
+use kova_core::sensors::gps::{FixQuality, GPSConfig, GPSData, GPS};
+
+/// A fix at the given coordinates
+fn fix(latitude: f64, longitude: f64) -> GPSData {
+    GPSData {
+        latitude,
+        longitude,
+        altitude: 10.0,
+        accuracy: 1.0,
+        speed: 0.0,
+        heading: 0.0,
+        satellite_count: 9,
+        hdop: 1.1,
+        vdop: 1.5,
+        pdop: 1.9,
+        fix_quality: FixQuality::GPSFix,
+        timestamp: chrono::Utc::now(),
+    }
+}
+
+/// A rectangular fence around central San Francisco
+fn fence() -> Vec<(f64, f64)> {
+    vec![
+        (37.70, -122.52),
+        (37.70, -122.35),
+        (37.82, -122.35),
+        (37.82, -122.52),
+    ]
+}
+
+#[test]
+fn test_inside_and_outside_rectangular_fence() {
+    let mut gps = GPS::new("gps_01".to_string(), GPSConfig::default()).unwrap();
+    gps.set_geofence(fence()).unwrap();
+
+    assert!(gps.check_geofence(&fix(37.7749, -122.4194)));
+    assert!(!gps.check_geofence(&fix(37.90, -122.4194)));
+    assert!(!gps.check_geofence(&fix(37.7749, -122.20)));
+}
+
+#[test]
+fn test_no_fence_means_everywhere_allowed() {
+    let gps = GPS::new("gps_01".to_string(), GPSConfig::default()).unwrap();
+    assert!(gps.check_geofence(&fix(0.0, 0.0)));
+}
+
+#[test]
+fn test_fence_needs_three_vertices() {
+    let mut gps = GPS::new("gps_01".to_string(), GPSConfig::default()).unwrap();
+    assert!(gps.set_geofence(vec![(0.0, 0.0), (1.0, 1.0)]).is_err());
+}
+
+#[test]
+fn test_clear_geofence() {
+    let mut gps = GPS::new("gps_01".to_string(), GPSConfig::default()).unwrap();
+    gps.set_geofence(fence()).unwrap();
+    assert!(!gps.check_geofence(&fix(37.90, -122.4194)));
+    gps.clear_geofence();
+    assert!(gps.check_geofence(&fix(37.90, -122.4194)));
+}
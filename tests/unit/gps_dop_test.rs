@@ -0,0 +1,62 @@
+//! Unit tests for GPS dilution-of-precision fields
+
+use kova_core::sensors::gps::{FixQuality, GPSData};
+
+fn fix() -> GPSData {
+    GPSData {
+        latitude: 37.7749,
+        longitude: -122.4194,
+        altitude: 10.0,
+        accuracy: 2.0,
+        speed: 1.0,
+        heading: 90.0,
+        satellite_count: 8,
+        hdop: 0.0,
+        vdop: 0.0,
+        pdop: 0.0,
+        fix_quality: FixQuality::GPSFix,
+        timestamp: chrono::Utc::now(),
+    }
+}
+
+#[test]
+fn test_gsa_sentence_fills_dop_fields() {
+    let mut data = fix();
+    data.apply_gsa_sentence("$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39")
+        .unwrap();
+
+    assert!((data.pdop - 2.5).abs() < f64::EPSILON);
+    assert!((data.hdop - 1.3).abs() < f64::EPSILON);
+    assert!((data.vdop - 2.1).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_gngsa_variant_is_accepted() {
+    let mut data = fix();
+    data.apply_gsa_sentence("$GNGSA,A,3,01,02,03,,,,,,,,,,1.8,1.0,1.5*2C")
+        .unwrap();
+    assert!((data.hdop - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_non_gsa_sentence_is_rejected() {
+    let mut data = fix();
+    let err = data
+        .apply_gsa_sentence("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47")
+        .unwrap_err();
+    assert!(err.to_string().contains("GSA"));
+}
+
+#[test]
+fn test_dop_fields_survive_serde_round_trip() {
+    let mut data = fix();
+    data.apply_gsa_sentence("$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39")
+        .unwrap();
+
+    let json = serde_json::to_string(&data).unwrap();
+    let parsed: GPSData = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed.pdop, data.pdop);
+    assert_eq!(parsed.hdop, data.hdop);
+    assert_eq!(parsed.vdop, data.vdop);
+}
@@ -0,0 +1,82 @@
+//! Unit tests for the GraphQL server
+
+use kova_core::api::graphql::{GraphQLQuery, GraphQLServer, QueryContext, SensorData};
+
+fn seeded_context() -> QueryContext {
+    let context = QueryContext::default();
+    {
+        let store = context.sensor_store.clone();
+        let mut entries = store.try_write().unwrap();
+        for i in 0..3 {
+            entries.push(SensorData {
+                id: format!("frame_{}", i),
+                sensor_type: "camera".to_string(),
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                data: "AAAA".to_string(),
+                metadata: Vec::new(),
+            });
+        }
+    }
+    context
+}
+
+#[tokio::test]
+async fn test_sensor_data_list_with_limit() {
+    let server = GraphQLServer::with_context("127.0.0.1".to_string(), 0, seeded_context());
+
+    let query = GraphQLQuery {
+        query: "{ sensorDataList(limit: 2) { id sensorType } }".to_string(),
+        variables: None,
+        operation_name: None,
+    };
+
+    let response = server
+        .execute_query::<serde_json::Value>(query)
+        .await
+        .unwrap();
+
+    assert!(response.errors.is_none());
+    let data = response.data.unwrap();
+    let nodes = data["sensorDataList"].as_array().unwrap();
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[0]["id"], "frame_0");
+    assert_eq!(nodes[1]["id"], "frame_1");
+}
+
+#[tokio::test]
+async fn test_sensor_data_lookup_by_id() {
+    let server = GraphQLServer::with_context("127.0.0.1".to_string(), 0, seeded_context());
+
+    let query = GraphQLQuery {
+        query: r#"{ sensorData(id: "frame_1") { id } }"#.to_string(),
+        variables: None,
+        operation_name: None,
+    };
+
+    let response = server
+        .execute_query::<serde_json::Value>(query)
+        .await
+        .unwrap();
+
+    let data = response.data.unwrap();
+    assert_eq!(data["sensorData"]["id"], "frame_1");
+}
+
+#[tokio::test]
+async fn test_invalid_query_reports_errors() {
+    let server = GraphQLServer::new("127.0.0.1".to_string(), 0);
+
+    let query = GraphQLQuery {
+        query: "{ notAField }".to_string(),
+        variables: None,
+        operation_name: None,
+    };
+
+    let response = server
+        .execute_query::<serde_json::Value>(query)
+        .await
+        .unwrap();
+
+    assert!(response.data.is_none());
+    assert!(response.errors.is_some());
+}
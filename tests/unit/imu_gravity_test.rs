@@ -0,0 +1,75 @@
+//! Unit tests for IMU gravity removal
+
+use kova_core::sensors::imu::{IMUConfig, IMUData, IMU};
+
+/// A static sample with gravity on the given axes
+fn static_sample(acceleration: [f32; 3]) -> IMUData {
+    IMUData {
+        linear_acceleration: acceleration,
+        angular_velocity: [0.0, 0.0, 0.0],
+        magnetic_field: None,
+        temperature: None,
+        timestamp: chrono::Utc::now(),
+    }
+}
+
+#[test]
+fn test_static_sensor_yields_near_zero_motion() {
+    let mut imu = IMU::new("imu_01".to_string(), IMUConfig::default()).unwrap();
+
+    // Level and static: gravity is ~9.81 straight down the Z axis
+    let mut motion = [0.0f32; 3];
+    for _ in 0..20 {
+        motion = imu.remove_gravity(&static_sample([0.0, 0.0, 9.81]));
+    }
+
+    for axis in motion {
+        assert!(axis.abs() < 0.05, "residual motion too large: {}", axis);
+    }
+}
+
+#[test]
+fn test_tilted_static_sensor_also_settles() {
+    let mut imu = IMU::new("imu_01".to_string(), IMUConfig::default()).unwrap();
+
+    // Gravity split across axes, as on a tilted mount
+    let tilted = [4.0, 3.0, 8.4];
+    let mut motion = [0.0f32; 3];
+    for _ in 0..20 {
+        motion = imu.remove_gravity(&static_sample(tilted));
+    }
+
+    for axis in motion {
+        assert!(axis.abs() < 0.05);
+    }
+}
+
+#[test]
+fn test_sudden_motion_shows_through() {
+    let mut imu = IMU::new("imu_01".to_string(), IMUConfig::default()).unwrap();
+
+    for _ in 0..20 {
+        imu.remove_gravity(&static_sample([0.0, 0.0, 9.81]));
+    }
+
+    // A 2 m/s² jolt on X should dominate the settled estimate
+    let motion = imu.remove_gravity(&static_sample([2.0, 0.0, 9.81]));
+    assert!(motion[0] > 1.5);
+    assert!(motion[2].abs() < 0.05);
+}
+
+#[tokio::test]
+async fn test_linear_motion_from_capture() {
+    let mut imu = IMU::new("imu_01".to_string(), IMUConfig::default()).unwrap();
+    imu.initialize().await.unwrap();
+
+    // Generated data is gravity plus small wobble; after settling, the
+    // gravity-removed output stays well below the 9.81 raw Z reading
+    let mut motion = [0.0f32; 3];
+    for _ in 0..10 {
+        motion = imu.linear_motion().await.unwrap();
+    }
+    for axis in motion {
+        assert!(axis.abs() < 1.0);
+    }
+}
@@ -0,0 +1,62 @@
+//! Unit tests for IMU vibration monitoring
+
+use kova_core::sensors::imu::{IMUConfig, IMUData, VibrationLevel, VibrationThresholds, IMU};
+
+fn sample(z_acceleration: f32) -> IMUData {
+    IMUData {
+        linear_acceleration: [0.0, 0.0, z_acceleration],
+        angular_velocity: [0.0, 0.0, 0.0],
+        magnetic_field: None,
+        temperature: None,
+        timestamp: chrono::Utc::now(),
+    }
+}
+
+/// Feed `count` samples oscillating around 1 g with the given amplitude
+fn feed(imu: &mut IMU, count: usize, amplitude: f32) {
+    for i in 0..count {
+        let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+        imu.record_vibration_sample(&sample(9.81 + sign * amplitude));
+    }
+}
+
+#[test]
+fn test_classification_follows_amplitude() {
+    let mut imu = IMU::new("imu_01".to_string(), IMUConfig::default()).unwrap();
+
+    feed(&mut imu, 64, 0.1);
+    assert_eq!(imu.vibration_level(), VibrationLevel::Low);
+
+    // A rough-terrain burst pushes the RMS past the high threshold
+    feed(&mut imu, 64, 5.0);
+    assert_eq!(imu.vibration_level(), VibrationLevel::High);
+}
+
+#[test]
+fn test_rms_ignores_the_static_gravity_component() {
+    let mut imu = IMU::new("imu_01".to_string(), IMUConfig::default()).unwrap();
+
+    // Perfectly steady at 1 g: magnitude is constant, so AC RMS is zero
+    for _ in 0..32 {
+        imu.record_vibration_sample(&sample(9.81));
+    }
+    assert!(imu.vibration_rms(32) < 1e-3);
+}
+
+#[test]
+fn test_rms_of_empty_history_is_zero() {
+    let imu = IMU::new("imu_01".to_string(), IMUConfig::default()).unwrap();
+    assert_eq!(imu.vibration_rms(64), 0.0);
+}
+
+#[test]
+fn test_custom_thresholds() {
+    let mut imu = IMU::new("imu_01".to_string(), IMUConfig::default()).unwrap();
+    imu.set_vibration_thresholds(VibrationThresholds {
+        medium: 0.05,
+        high: 10.0,
+    });
+
+    feed(&mut imu, 64, 0.1);
+    assert_eq!(imu.vibration_level(), VibrationLevel::Medium);
+}
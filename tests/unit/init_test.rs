@@ -0,0 +1,11 @@
+//! Unit tests for library initialization
+
+use kova_core::{init_with, Config};
+
+#[test]
+fn test_init_with_tolerates_double_init() {
+    let config = Config::default();
+    init_with(&config, tracing::Level::DEBUG).unwrap();
+    // A second call must not panic even though the global subscriber is set
+    init_with(&config, tracing::Level::INFO).unwrap();
+}
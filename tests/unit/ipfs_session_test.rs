@@ -0,0 +1,56 @@
+//! Unit tests for the resumable IPFS upload session
+
+use kova_core::blockchain::ipfs::IPFSUploadSession;
+use kova_core::core::storage::StorageManager;
+
+#[tokio::test]
+async fn test_session_chunk_accounting() {
+    // 1000 bytes in 256-byte chunks -> 4 chunks
+    let session = IPFSUploadSession::new("session_1".to_string(), 1000, 256).unwrap();
+
+    assert_eq!(session.pending_chunks(), vec![0, 1, 2, 3]);
+    assert!(!session.is_complete());
+}
+
+#[tokio::test]
+async fn test_session_resumes_with_only_remaining_chunks() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage = StorageManager::new(dir.path().to_string_lossy().to_string());
+
+    let mut session = IPFSUploadSession::new("session_2".to_string(), 1000, 256).unwrap();
+
+    // Simulate an interruption after the first two chunks succeeded
+    session
+        .mark_chunk_complete(0, "QmChunk0".to_string())
+        .unwrap();
+    session
+        .mark_chunk_complete(1, "QmChunk1".to_string())
+        .unwrap();
+    session.persist(&storage).await.unwrap();
+
+    let resumed = IPFSUploadSession::resume(&storage, "session_2").await.unwrap();
+
+    assert_eq!(resumed.session_id(), "session_2");
+    assert_eq!(resumed.pending_chunks(), vec![2, 3]);
+    assert!(!resumed.is_complete());
+}
+
+#[tokio::test]
+async fn test_session_complete_after_all_chunks() {
+    let mut session = IPFSUploadSession::new("session_3".to_string(), 100, 50).unwrap();
+
+    session
+        .mark_chunk_complete(0, "QmChunk0".to_string())
+        .unwrap();
+    session
+        .mark_chunk_complete(1, "QmChunk1".to_string())
+        .unwrap();
+
+    assert!(session.is_complete());
+    assert!(session.pending_chunks().is_empty());
+}
+
+#[test]
+fn test_session_rejects_zero_chunk_size() {
+    assert!(IPFSUploadSession::new("session_4".to_string(), 100, 0).is_err());
+}
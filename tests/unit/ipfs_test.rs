@@ -0,0 +1,148 @@
+//! Unit tests for the IPFS client against a scripted mock API
+
+use kova_core::blockchain::ipfs::{IPFSClient, IPFSConfig};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Serve one scripted response body per incoming request
+async fn mock_api(responses: Vec<String>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        for body in responses {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                break;
+            };
+            let mut buffer = vec![0u8; 65536];
+            let _ = stream.read(&mut buffer).await;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    url
+}
+
+/// Serve one scripted raw HTTP response (status line included) per request
+async fn mock_raw(responses: Vec<String>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        for response in responses {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                break;
+            };
+            let mut buffer = vec![0u8; 65536];
+            let _ = stream.read(&mut buffer).await;
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    url
+}
+
+fn config_for(url: String) -> IPFSConfig {
+    IPFSConfig {
+        api_url: url,
+        gateway_url: "http://localhost:8080".to_string(),
+        timeout_seconds: 2,
+        retry_attempts: 1,
+        pin_on_add: false,
+        verify_after_add: false,
+    }
+}
+
+#[tokio::test]
+async fn test_add_directory_parses_root_and_entries() {
+    let body = concat!(
+        r#"{"Name":"frame_0.bin","Hash":"QmFileOne","Size":"16"}"#,
+        "\n",
+        r#"{"Name":"frame_1.bin","Hash":"QmFileTwo","Size":"16"}"#,
+        "\n",
+        r#"{"Name":"","Hash":"QmWrappingDir","Size":"64"}"#,
+    );
+    let url = mock_api(vec![body.to_string()]).await;
+    let client = IPFSClient::new(config_for(url)).await.unwrap();
+
+    let files = vec![
+        ("frame_0.bin".to_string(), vec![0u8; 16]),
+        ("frame_1.bin".to_string(), vec![1u8; 16]),
+    ];
+    let result = client.add_directory(&files).await.unwrap();
+
+    assert_eq!(result.root, "QmWrappingDir");
+    assert_eq!(result.entries.len(), 2);
+    assert_eq!(result.entries[0].name, "frame_0.bin");
+    assert_eq!(result.entries[0].hash, "QmFileOne");
+    assert_eq!(result.entries[1].hash, "QmFileTwo");
+}
+
+#[tokio::test]
+async fn test_verify_after_add_catches_altered_content() {
+    let add = r#"{"Name":"file","Hash":"QmSomething","Size":"4"}"#.to_string();
+    let altered = "not the same bytes".to_string();
+    let url = mock_api(vec![add, altered]).await;
+
+    let mut config = config_for(url);
+    config.verify_after_add = true;
+    let client = IPFSClient::new(config).await.unwrap();
+
+    let result = client.add_data(b"abcd").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_verify_after_add_passes_on_matching_content() {
+    let add = r#"{"Name":"file","Hash":"QmSomething","Size":"4"}"#.to_string();
+    let url = mock_api(vec![add, "abcd".to_string()]).await;
+
+    let mut config = config_for(url);
+    config.verify_after_add = true;
+    let client = IPFSClient::new(config).await.unwrap();
+
+    let hash = client.add_data(b"abcd").await.unwrap();
+    assert_eq!(hash, "QmSomething");
+}
+
+#[tokio::test]
+async fn test_get_data_falls_back_to_gateway_on_missing_block() {
+    let not_found =
+        "HTTP/1.1 404 Not Found
+content-length: 0
+
+".to_string();
+    let api_url = mock_raw(vec![not_found]).await;
+
+    let payload = "frame bytes";
+    let served = format!(
+        "HTTP/1.1 200 OK
+content-length: {}
+
+{}",
+        payload.len(),
+        payload
+    );
+    let gateway_url = mock_raw(vec![served]).await;
+
+    let mut config = config_for(api_url);
+    config.gateway_url = gateway_url;
+    let client = IPFSClient::new(config).await.unwrap();
+
+    let data = client.get_data("QmSomething").await.unwrap();
+    assert_eq!(data, payload.as_bytes());
+}
+
+#[tokio::test]
+async fn test_add_directory_rejects_empty_batch() {
+    let client = IPFSClient::new(config_for("http://127.0.0.1:9".to_string()))
+        .await
+        .unwrap();
+
+    assert!(client.add_directory(&[]).await.is_err());
+}
@@ -0,0 +1,75 @@
+//! Unit tests for LiDAR ground segmentation
+
+use kova_core::sensors::lidar::{LiDARConfig, LiDAR, Point};
+
+fn point(x: f32, y: f32, z: f32) -> Point {
+    Point {
+        x,
+        y,
+        z,
+        intensity: None,
+        ring: None,
+    }
+}
+
+fn lidar() -> LiDAR {
+    LiDAR::new("lidar_01".to_string(), LiDARConfig::default()).unwrap()
+}
+
+/// A flat ground patch around the origin
+fn flat_ground() -> Vec<Point> {
+    let mut points = Vec::new();
+    for i in 0..10 {
+        for j in 0..10 {
+            points.push(point(i as f32 * 0.4, j as f32 * 0.4, 0.0));
+        }
+    }
+    points
+}
+
+#[test]
+fn test_elevated_cluster_is_non_ground() {
+    let mut cloud = flat_ground();
+    let ground_count = cloud.len();
+
+    // A box-shaped obstacle 1.5 m up, off to the side
+    for i in 0..5 {
+        cloud.push(point(8.0 + i as f32 * 0.1, 8.0, 1.5));
+    }
+
+    let (ground, non_ground) = lidar().segment_ground(&cloud, 0.05, 0.2);
+
+    assert_eq!(ground.len(), ground_count);
+    assert_eq!(non_ground.len(), 5);
+    assert!(non_ground.iter().all(|p| p.z > 1.0));
+}
+
+#[test]
+fn test_gentle_slope_stays_ground() {
+    // Ground rising 2 cm per meter of distance
+    let cloud: Vec<Point> = (0..20)
+        .map(|i| point(i as f32, 0.0, i as f32 * 0.02))
+        .collect();
+
+    let (ground, non_ground) = lidar().segment_ground(&cloud, 0.05, 0.2);
+    assert_eq!(ground.len(), cloud.len());
+    assert!(non_ground.is_empty());
+}
+
+#[test]
+fn test_points_above_threshold_within_cell_are_non_ground() {
+    let mut cloud = flat_ground();
+    // A return half a meter above the ground inside a ground cell
+    cloud.push(point(1.0, 1.0, 0.5));
+
+    let (_, non_ground) = lidar().segment_ground(&cloud, 0.05, 0.2);
+    assert_eq!(non_ground.len(), 1);
+    assert!((non_ground[0].z - 0.5).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_empty_cloud() {
+    let (ground, non_ground) = lidar().segment_ground(&[], 0.05, 0.2);
+    assert!(ground.is_empty());
+    assert!(non_ground.is_empty());
+}
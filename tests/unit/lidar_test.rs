@@ -0,0 +1,97 @@
+//! Unit tests for LiDAR point-cloud operations
+
+use kova_core::sensors::lidar::{LiDAR, LiDARConfig, Point};
+
+fn point(x: f32, y: f32, z: f32) -> Point {
+    Point {
+        x,
+        y,
+        z,
+        intensity: Some(0.5),
+        ring: None,
+    }
+}
+
+#[test]
+fn test_crop_keeps_inside_and_boundary_points() {
+    let lidar = LiDAR::new("lidar_1".to_string(), LiDARConfig::default()).unwrap();
+
+    let points = vec![
+        point(0.5, 0.5, 0.5),  // inside
+        point(1.0, 1.0, 1.0),  // on the max boundary
+        point(0.0, 0.0, 0.0),  // on the min boundary
+        point(1.5, 0.5, 0.5),  // outside in x
+        point(0.5, -0.1, 0.5), // outside in y
+    ];
+
+    let cropped = lidar
+        .crop(&points, [0.0, 0.0, 0.0], [1.0, 1.0, 1.0])
+        .unwrap();
+
+    assert_eq!(cropped.len(), 3);
+    assert!(cropped
+        .iter()
+        .all(|p| (0.0..=1.0).contains(&p.x) && (0.0..=1.0).contains(&p.y)));
+}
+
+#[test]
+fn test_intensity_stats_histogram_and_mean() {
+    let lidar = LiDAR::new("lidar_1".to_string(), LiDARConfig::default()).unwrap();
+
+    let mut points: Vec<Point> = [0.0, 0.25, 0.5, 1.0]
+        .iter()
+        .map(|&i| Point {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            intensity: Some(i),
+            ring: None,
+        })
+        .collect();
+    // A point without intensity must be skipped
+    points.push(Point {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        intensity: None,
+        ring: None,
+    });
+
+    let stats = lidar.intensity_stats(&points);
+
+    assert_eq!(stats.count, 4);
+    assert!((stats.mean - 0.4375).abs() < 1e-6);
+    assert_eq!(stats.min, 0.0);
+    assert_eq!(stats.max, 1.0);
+    assert_eq!(stats.histogram.len(), 16);
+    assert_eq!(stats.histogram[0], 1); // 0.0
+    assert_eq!(stats.histogram[4], 1); // 0.25
+    assert_eq!(stats.histogram[8], 1); // 0.5
+    assert_eq!(stats.histogram[15], 1); // 1.0 lands in the last bin
+    assert_eq!(stats.histogram.iter().sum::<u32>(), 4);
+}
+
+#[test]
+fn test_intensity_stats_without_intensities() {
+    let lidar = LiDAR::new("lidar_1".to_string(), LiDARConfig::default()).unwrap();
+
+    let points = vec![Point {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        intensity: None,
+        ring: None,
+    }];
+
+    let stats = lidar.intensity_stats(&points);
+    assert_eq!(stats.count, 0);
+    assert!(stats.histogram.is_empty());
+}
+
+#[test]
+fn test_crop_rejects_inverted_bounds() {
+    let lidar = LiDAR::new("lidar_1".to_string(), LiDARConfig::default()).unwrap();
+    let points = vec![point(0.0, 0.0, 0.0)];
+
+    assert!(lidar.crop(&points, [1.0, 0.0, 0.0], [0.0, 1.0, 1.0]).is_err());
+}
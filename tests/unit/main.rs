@@ -0,0 +1,77 @@
+//! Unit test harness
+//!
+//! Cargo only builds top-level files in `tests/`, so every module under
+//! `tests/unit/` is declared here to form one integration test binary.
+
+mod anomaly_test;
+mod arduino_test;
+mod arweave_test;
+mod auth_test;
+mod blockchain_manager_test;
+mod blockchain_test;
+mod bootstrap_test;
+mod calibration_test;
+mod camera_test;
+mod capture_timeout_test;
+mod clock_test;
+mod compression_metric_test;
+mod config_builder_test;
+mod config_test;
+mod contribution_store_test;
+mod contribution_verify_test;
+mod diagnostics_test;
+mod error_test;
+mod frame_sync_test;
+mod frame_tracing_test;
+mod frozen_frame_test;
+mod fusion_test;
+mod geofence_test;
+mod gps_dop_test;
+mod graphql_test;
+mod imu_gravity_test;
+mod imu_vibration_test;
+mod init_test;
+mod ipfs_session_test;
+mod ipfs_test;
+mod lidar_ground_test;
+mod lidar_test;
+mod manager_calibration_test;
+mod merkle_test;
+mod metadata_schema_test;
+mod mock_client_test;
+mod mock_sensor_test;
+mod occupancy_grid_test;
+mod openapi_test;
+mod per_type_threshold_test;
+mod pipeline_test;
+mod proto_test;
+mod quality_smoothing_test;
+mod raspberry_pi_test;
+mod rate_limited_test;
+mod recorder_test;
+mod redaction_test;
+mod registry_test;
+mod replay_test;
+mod rest_test;
+mod ros2_test;
+mod scheduler_test;
+mod sdk_rust_test;
+mod seeded_generation_test;
+mod sensor_data_test;
+mod sensor_group_test;
+mod sensor_init_test;
+mod sensor_metrics_test;
+mod sensor_shutdown_test;
+mod sensor_spec_test;
+mod sensor_state_test;
+mod sensor_type_test;
+mod sensors_test;
+mod signing_test;
+mod solana_test;
+mod storage_prune_test;
+mod thermal_png_test;
+mod thermal_test;
+mod units_test;
+mod usage_report_test;
+mod validation_test;
+mod websocket_test;
@@ -0,0 +1,50 @@
+//! Unit tests for manager-level calibration policy
+//!
+//! Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use kova_core::core::config::SensorConfig;
+use kova_core::sensors::{MockSensor, SensorManager, SensorType};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+async fn manager_with_flagged_sensor(manager: SensorManager) -> (SensorManager, Arc<AtomicBool>) {
+    let flag = Arc::new(AtomicBool::new(false));
+    let sensor = MockSensor::new("camera_1".to_string(), SensorType::Camera, vec![])
+        .with_calibration_flag(flag.clone());
+    manager.add_sensor(Box::new(sensor)).await.unwrap();
+    (manager, flag)
+}
+
+#[tokio::test]
+async fn test_enabled_calibration_invokes_the_hook() {
+    let config = SensorConfig {
+        enable_calibration: true,
+        ..SensorConfig::default()
+    };
+    let (manager, flag) = manager_with_flagged_sensor(SensorManager::new_with_config(config)).await;
+
+    manager.initialize_all().await.unwrap();
+    assert!(flag.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn test_disabled_calibration_skips_the_hook() {
+    let config = SensorConfig {
+        enable_calibration: false,
+        ..SensorConfig::default()
+    };
+    let (manager, flag) = manager_with_flagged_sensor(SensorManager::new_with_config(config)).await;
+
+    manager.initialize_all().await.unwrap();
+    assert!(!flag.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn test_plain_manager_leaves_calibration_to_sensors() {
+    let (manager, flag) = manager_with_flagged_sensor(SensorManager::new()).await;
+
+    manager.initialize_all().await.unwrap();
+    assert!(!flag.load(Ordering::SeqCst));
+}
@@ -0,0 +1,71 @@
+//! Unit tests for the Merkle tree builder
+
+use kova_core::core::merkle::{verify, MerkleTree};
+use sha2::{Digest, Sha256};
+
+fn leaves(count: usize) -> Vec<Vec<u8>> {
+    (0..count).map(|i| format!("frame_{}", i).into_bytes()).collect()
+}
+
+#[test]
+fn test_empty_batch_has_no_tree() {
+    assert!(MerkleTree::from_leaves(&[]).is_none());
+}
+
+#[test]
+fn test_known_two_leaf_root() {
+    let batch = leaves(2);
+    let tree = MerkleTree::from_leaves(&batch).unwrap();
+
+    let left: [u8; 32] = Sha256::digest(&batch[0]).into();
+    let right: [u8; 32] = Sha256::digest(&batch[1]).into();
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    let expected: [u8; 32] = hasher.finalize().into();
+
+    assert_eq!(tree.root(), expected);
+}
+
+#[test]
+fn test_inclusion_proofs_round_trip() {
+    let batch = leaves(8);
+    let tree = MerkleTree::from_leaves(&batch).unwrap();
+    let root = tree.root();
+
+    for (index, leaf) in batch.iter().enumerate() {
+        let proof = tree.proof(index).unwrap();
+        assert!(verify(root, leaf, &proof, index));
+    }
+}
+
+#[test]
+fn test_odd_leaf_count_proofs_verify() {
+    let batch = leaves(5);
+    let tree = MerkleTree::from_leaves(&batch).unwrap();
+    let root = tree.root();
+
+    for (index, leaf) in batch.iter().enumerate() {
+        let proof = tree.proof(index).unwrap();
+        assert!(verify(root, leaf, &proof, index));
+    }
+}
+
+#[test]
+fn test_tampered_leaf_fails_verification() {
+    let batch = leaves(4);
+    let tree = MerkleTree::from_leaves(&batch).unwrap();
+    let proof = tree.proof(1).unwrap();
+
+    assert!(!verify(tree.root(), b"tampered", &proof, 1));
+    // Right leaf, wrong position
+    assert!(!verify(tree.root(), &batch[1], &proof, 2));
+}
+
+#[test]
+fn test_out_of_range_proof_is_none() {
+    let batch = leaves(3);
+    let tree = MerkleTree::from_leaves(&batch).unwrap();
+
+    assert!(tree.proof(3).is_none());
+}
@@ -0,0 +1,52 @@
+//! Unit tests for per-sensor metadata schema validation
+
+use kova_core::sensors::{metadata, SensorType};
+use std::collections::HashMap;
+
+/// Metadata matching what a camera populates at capture
+fn camera_metadata() -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    map.insert("resolution".to_string(), "1920x1080".to_string());
+    map.insert("format".to_string(), "RGB8".to_string());
+    map.insert("frame_rate".to_string(), "30".to_string());
+    map
+}
+
+#[test]
+fn test_well_formed_camera_metadata_passes() {
+    assert!(metadata::validate(&SensorType::Camera, &camera_metadata()).is_ok());
+}
+
+#[test]
+fn test_extra_keys_are_allowed() {
+    let mut map = camera_metadata();
+    map.insert("roi".to_string(), "0,0,640x480".to_string());
+    assert!(metadata::validate(&SensorType::Camera, &map).is_ok());
+}
+
+#[test]
+fn test_missing_resolution_fails() {
+    let mut map = camera_metadata();
+    map.remove("resolution");
+    let err = metadata::validate(&SensorType::Camera, &map).unwrap_err();
+    assert!(err.to_string().contains("resolution"));
+}
+
+#[test]
+fn test_malformed_resolution_fails() {
+    let mut map = camera_metadata();
+    map.insert("resolution".to_string(), "1920 by 1080".to_string());
+    assert!(metadata::validate(&SensorType::Camera, &map).is_err());
+}
+
+#[test]
+fn test_gps_coordinates_are_range_checked() {
+    let mut map = HashMap::new();
+    map.insert("latitude".to_string(), "95.0".to_string());
+    map.insert("longitude".to_string(), "13.4".to_string());
+    map.insert("satellite_count".to_string(), "8".to_string());
+    assert!(metadata::validate(&SensorType::GPS, &map).is_err());
+
+    map.insert("latitude".to_string(), "52.5".to_string());
+    assert!(metadata::validate(&SensorType::GPS, &map).is_ok());
+}
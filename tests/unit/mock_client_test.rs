@@ -0,0 +1,45 @@
+//! Unit tests for the in-memory mock blockchain client
+//!
+//! Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use kova_core::blockchain::{BlockchainClient, BlockchainManager, MockBlockchainClient};
+
+#[tokio::test]
+async fn test_manager_round_trip_through_mock() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("mock".to_string(), Box::new(MockBlockchainClient::new()))
+        .await;
+
+    let data = b"point cloud frame";
+    let hash = manager.store_data(data).await.unwrap();
+    let restored = manager.retrieve_data(&hash).await.unwrap();
+
+    assert_eq!(restored, data);
+}
+
+#[tokio::test]
+async fn test_identical_content_maps_to_identical_hash() {
+    let client = MockBlockchainClient::new();
+
+    let first = client.store_data(b"same bytes").await.unwrap();
+    let second = client.store_data(b"same bytes").await.unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(client.len(), 1);
+}
+
+#[tokio::test]
+async fn test_unavailable_mock_is_skipped_by_manager() {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client(
+            "offline".to_string(),
+            Box::new(MockBlockchainClient::unavailable()),
+        )
+        .await;
+
+    assert!(manager.store_data(b"data").await.is_err());
+}
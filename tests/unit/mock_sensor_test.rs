@@ -0,0 +1,58 @@
+//! Unit tests for the scripted mock sensor
+//!
+//! Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use kova_core::sensors::{MockSensor, Sensor, SensorData, SensorManager, SensorType};
+use std::collections::HashMap;
+
+fn frame(payload: &[u8]) -> SensorData {
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: "mock_camera".to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::Utc::now(),
+        data: payload.to_vec(),
+        metadata: HashMap::new(),
+        checksum: None,
+    }
+}
+
+#[tokio::test]
+async fn test_mock_sensor_serves_frames_in_order() {
+    let mut sensor = MockSensor::new(
+        "mock_camera".to_string(),
+        SensorType::Camera,
+        vec![frame(b"first"), frame(b"second")],
+    );
+
+    assert_eq!(sensor.capture().await.unwrap().data, b"first");
+    assert_eq!(sensor.capture().await.unwrap().data, b"second");
+    assert!(sensor.capture().await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_sensor_with_manager_success_and_failure() {
+    let manager = SensorManager::new();
+    manager
+        .add_sensor(Box::new(
+            MockSensor::new(
+                "mock_camera".to_string(),
+                SensorType::Camera,
+                vec![frame(b"one"), frame(b"two")],
+            )
+            .fail_on_capture(1),
+        ))
+        .await
+        .unwrap();
+
+    // First pass succeeds
+    let frames = manager.capture_all().await.unwrap();
+    assert_eq!(frames.len(), 1);
+    assert_eq!(frames[0].data, b"one");
+
+    // Second pass hits the configured failure and yields nothing
+    let frames = manager.capture_all().await.unwrap();
+    assert!(frames.is_empty());
+}
@@ -0,0 +1,65 @@
+//! Unit tests for LiDAR occupancy grid generation
+
+use kova_core::sensors::lidar::{LiDARConfig, LiDAR, Point};
+
+fn point(x: f32, y: f32, z: f32) -> Point {
+    Point {
+        x,
+        y,
+        z,
+        intensity: None,
+        ring: None,
+    }
+}
+
+fn lidar() -> LiDAR {
+    LiDAR::new("lidar_01".to_string(), LiDARConfig::default()).unwrap()
+}
+
+#[test]
+fn test_points_mark_exactly_their_cells() {
+    // 10x10 m area at 1 m resolution
+    let bounds = ((0.0, 0.0), (10.0, 10.0));
+    let cloud = vec![
+        point(2.5, 3.5, 1.0), // cell (2, 3)
+        point(7.1, 7.9, 0.5), // cell (7, 7)
+    ];
+
+    let grid = lidar().to_occupancy_grid(&cloud, 1.0, bounds);
+
+    assert_eq!(grid.width, 10);
+    assert_eq!(grid.height, 10);
+    assert_eq!(grid.cells.len(), 100);
+    assert!(grid.is_occupied(2, 3));
+    assert!(grid.is_occupied(7, 7));
+    assert_eq!(grid.occupied_count(), 2);
+}
+
+#[test]
+fn test_ground_returns_do_not_occupy() {
+    let bounds = ((0.0, 0.0), (10.0, 10.0));
+    let cloud = vec![point(5.0, 5.0, 0.0), point(5.0, 5.0, 0.05)];
+
+    let grid = lidar().to_occupancy_grid(&cloud, 1.0, bounds);
+    assert_eq!(grid.occupied_count(), 0);
+}
+
+#[test]
+fn test_points_outside_bounds_are_ignored() {
+    let bounds = ((0.0, 0.0), (5.0, 5.0));
+    let cloud = vec![point(-1.0, 2.0, 1.0), point(6.0, 2.0, 1.0)];
+
+    let grid = lidar().to_occupancy_grid(&cloud, 0.5, bounds);
+    assert_eq!(grid.width, 10);
+    assert_eq!(grid.height, 10);
+    assert_eq!(grid.occupied_count(), 0);
+}
+
+#[test]
+fn test_dimensions_round_up_to_cover_bounds() {
+    let bounds = ((0.0, 0.0), (3.3, 2.1));
+    let grid = lidar().to_occupancy_grid(&[], 1.0, bounds);
+    assert_eq!(grid.width, 4);
+    assert_eq!(grid.height, 3);
+    assert_eq!(grid.origin, (0.0, 0.0));
+}
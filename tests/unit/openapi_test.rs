@@ -0,0 +1,60 @@
+//! Unit tests for the generated OpenAPI document
+
+use kova_core::api::rest::openapi_spec;
+
+#[test]
+fn test_spec_deserializes_and_lists_all_routes() {
+    let raw = serde_json::to_string(&openapi_spec()).unwrap();
+    let spec: serde_json::Value = serde_json::from_str(&raw).unwrap();
+
+    assert_eq!(spec["openapi"], "3.0.3");
+
+    let paths = spec["paths"].as_object().unwrap();
+    assert!(paths.contains_key("/health"));
+    assert!(paths.contains_key("/sensor-data"));
+    assert!(paths.contains_key("/contributions/{id}"));
+
+    assert!(paths["/health"]["get"].is_object());
+    assert!(paths["/sensor-data"]["post"].is_object());
+    assert!(paths["/contributions/{id}"]["get"].is_object());
+}
+
+#[test]
+fn test_health_response_uses_api_response_envelope() {
+    let spec = openapi_spec();
+
+    let schema = &spec["paths"]["/health"]["get"]["responses"]["200"]["content"]
+        ["application/json"]["schema"];
+    assert_eq!(schema["type"], "object");
+
+    let properties = schema["properties"].as_object().unwrap();
+    for field in ["success", "data", "error", "timestamp"] {
+        assert!(
+            properties.contains_key(field),
+            "missing envelope field: {}",
+            field
+        );
+    }
+    assert_eq!(properties["data"]["type"], "string");
+}
+
+#[test]
+fn test_component_schemas_match_response_types() {
+    let spec = openapi_spec();
+    let schemas = spec["components"]["schemas"].as_object().unwrap();
+
+    assert!(schemas.contains_key("ValidationResult"));
+    assert!(schemas.contains_key("QualityMetrics"));
+    assert!(schemas.contains_key("Contribution"));
+
+    let metrics = schemas["QualityMetrics"]["properties"].as_object().unwrap();
+    for field in [
+        "noise_level",
+        "completeness",
+        "consistency",
+        "accuracy",
+        "anomaly_score",
+    ] {
+        assert!(metrics.contains_key(field), "missing metric field: {}", field);
+    }
+}
@@ -0,0 +1,81 @@
+//! Unit tests for per-sensor-type quality thresholds
+
+use kova_core::core::validation::{DataValidator, ValidationConfig};
+use kova_core::sensors::SensorType;
+use std::collections::HashMap;
+
+fn payload() -> Vec<u8> {
+    vec![0xAA; 256]
+}
+
+#[tokio::test]
+async fn test_stricter_per_type_bar_overrides_global() {
+    let mut per_type_thresholds = HashMap::new();
+    // No real frame can reach this bar
+    per_type_thresholds.insert(SensorType::LiDAR, 1.01);
+    let config = ValidationConfig {
+        min_quality_score: 0.0,
+        per_type_thresholds,
+        ..ValidationConfig::default()
+    };
+    let validator = DataValidator::with_config(config);
+
+    let lidar = validator
+        .validate_typed(&SensorType::LiDAR, &payload(), &HashMap::new())
+        .await
+        .unwrap();
+    assert!(!lidar.is_valid);
+
+    // Types without an override keep the permissive global bar
+    let gps = validator
+        .validate_typed(&SensorType::GPS, &payload(), &HashMap::new())
+        .await
+        .unwrap();
+    assert!(gps.is_valid);
+}
+
+#[tokio::test]
+async fn test_looser_per_type_bar_overrides_global() {
+    let mut per_type_thresholds = HashMap::new();
+    per_type_thresholds.insert(SensorType::GPS, 0.0);
+    let config = ValidationConfig {
+        min_quality_score: 1.01,
+        per_type_thresholds,
+        ..ValidationConfig::default()
+    };
+    let validator = DataValidator::with_config(config);
+
+    let gps = validator
+        .validate_typed(&SensorType::GPS, &payload(), &HashMap::new())
+        .await
+        .unwrap();
+    assert!(gps.is_valid);
+
+    let camera = validator
+        .validate_typed(&SensorType::Camera, &payload(), &HashMap::new())
+        .await
+        .unwrap();
+    assert!(!camera.is_valid);
+}
+
+#[tokio::test]
+async fn test_quality_score_is_unaffected_by_the_override() {
+    let mut per_type_thresholds = HashMap::new();
+    per_type_thresholds.insert(SensorType::Camera, 1.01);
+    let strict = DataValidator::with_config(ValidationConfig {
+        per_type_thresholds,
+        ..ValidationConfig::default()
+    });
+    let default = DataValidator::new();
+
+    let strict_result = strict
+        .validate_typed(&SensorType::Camera, &payload(), &HashMap::new())
+        .await
+        .unwrap();
+    let default_result = default
+        .validate_typed(&SensorType::Camera, &payload(), &HashMap::new())
+        .await
+        .unwrap();
+
+    assert_eq!(strict_result.quality_score, default_result.quality_score);
+}
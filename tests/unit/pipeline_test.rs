@@ -0,0 +1,151 @@
+//! Unit tests for the capture-validate-store pipeline
+//!
+//! Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use chrono::{Duration, Utc};
+use kova_core::blockchain::{BlockchainManager, MockBlockchainClient};
+use kova_core::core::validation::{DataValidator, ValidationConfig};
+use kova_core::core::{FailAction, FrameOutcome, Pipeline, ValidationPolicy};
+use kova_core::sensors::{MockSensor, SensorData, SensorManager, SensorType};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn frame(sensor_id: &str, timestamp: chrono::DateTime<Utc>) -> SensorData {
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: sensor_id.to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp,
+        data: vec![128; 1024],
+        metadata: HashMap::new(),
+        checksum: None,
+    }
+}
+
+fn permissive_validator() -> Arc<DataValidator> {
+    let config = ValidationConfig {
+        min_quality_score: 0.0,
+        ..ValidationConfig::default()
+    };
+    Arc::new(DataValidator::with_config(config))
+}
+
+async fn mock_blockchain() -> Arc<BlockchainManager> {
+    let blockchain = BlockchainManager::new();
+    blockchain
+        .add_client("mock".to_string(), Box::new(MockBlockchainClient::new()))
+        .await;
+    Arc::new(blockchain)
+}
+
+#[tokio::test]
+async fn test_valid_frames_produce_contributions() {
+    let manager = SensorManager::new();
+    manager
+        .add_sensor(Box::new(MockSensor::new(
+            "camera_1".to_string(),
+            SensorType::Camera,
+            vec![frame("camera_1", Utc::now())],
+        )))
+        .await
+        .unwrap();
+
+    let pipeline = Pipeline::new(permissive_validator(), mock_blockchain().await, "validator_1");
+    let results = pipeline.run(&manager).await.unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].sensor_id, "camera_1");
+    match &results[0].outcome {
+        FrameOutcome::Stored {
+            data_hash,
+            contribution_tx,
+            quality_score,
+        } => {
+            assert!(!data_hash.is_empty());
+            assert!(!contribution_tx.is_empty());
+            assert!((0.0..=1.0).contains(quality_score));
+        }
+        other => panic!("Expected Stored, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_stale_frames_are_rejected_not_stored() {
+    let manager = SensorManager::new();
+    manager
+        .add_sensor(Box::new(MockSensor::new(
+            "camera_1".to_string(),
+            SensorType::Camera,
+            // Far outside the temporal-consistency window
+            vec![frame("camera_1", Utc::now() - Duration::hours(2))],
+        )))
+        .await
+        .unwrap();
+
+    let blockchain = mock_blockchain().await;
+    let pipeline = Pipeline::new(permissive_validator(), blockchain.clone(), "validator_1");
+    let results = pipeline.run(&manager).await.unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(
+        results[0].outcome,
+        FrameOutcome::Rejected { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_store_anyway_policy_stores_failing_frames() {
+    let stale = frame("camera_1", Utc::now() - Duration::hours(2));
+    let blockchain = mock_blockchain().await;
+    let mut pipeline = Pipeline::new(permissive_validator(), blockchain, "validator_1");
+    pipeline.set_policy(ValidationPolicy {
+        on_fail: FailAction::StoreAnyway,
+    });
+
+    let result = pipeline.process_frame(&stale).await;
+    assert!(matches!(result.outcome, FrameOutcome::Stored { .. }));
+}
+
+#[tokio::test]
+async fn test_quarantine_policy_writes_rejected_frames() {
+    let dir = tempfile::tempdir().unwrap();
+    let stale = frame("camera_1", Utc::now() - Duration::hours(2));
+    let blockchain = mock_blockchain().await;
+    let mut pipeline = Pipeline::new(permissive_validator(), blockchain, "validator_1");
+    pipeline.set_policy(ValidationPolicy {
+        on_fail: FailAction::Quarantine(dir.path().to_path_buf()),
+    });
+
+    let result = pipeline.process_frame(&stale).await;
+    assert!(matches!(result.outcome, FrameOutcome::Rejected { .. }));
+
+    let quarantined = dir.path().join(format!("{}.json", stale.frame_id));
+    let written: kova_core::sensors::SensorData =
+        serde_json::from_slice(&std::fs::read(quarantined).unwrap()).unwrap();
+    assert_eq!(written.frame_id, stale.frame_id);
+    assert_eq!(written.data, stale.data);
+}
+
+#[tokio::test]
+async fn test_storage_failure_is_reported_per_frame() {
+    let manager = SensorManager::new();
+    manager
+        .add_sensor(Box::new(MockSensor::new(
+            "camera_1".to_string(),
+            SensorType::Camera,
+            vec![frame("camera_1", Utc::now())],
+        )))
+        .await
+        .unwrap();
+
+    // No clients registered: storing must fail, but the pipeline still
+    // returns a result for the frame.
+    let blockchain = Arc::new(BlockchainManager::new());
+    let pipeline = Pipeline::new(permissive_validator(), blockchain, "validator_1");
+    let results = pipeline.run(&manager).await.unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(results[0].outcome, FrameOutcome::Failed { .. }));
+}
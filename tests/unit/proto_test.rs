@@ -0,0 +1,61 @@
+//! Unit tests for the protobuf wire format
+
+use kova_core::sensors::{SensorData, SensorType};
+use std::collections::HashMap;
+
+fn sample_frame() -> SensorData {
+    let mut metadata = HashMap::new();
+    metadata.insert("resolution".to_string(), "640x480".to_string());
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: "camera_front".to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::DateTime::from_timestamp_millis(1_700_000_000_000).unwrap(),
+        data: vec![1, 2, 3, 4],
+        metadata,
+        checksum: None,
+    }
+    .with_checksum()
+}
+
+#[test]
+fn test_protobuf_round_trip() {
+    let frame = sample_frame();
+    let bytes = frame.to_protobuf();
+    let decoded = SensorData::from_protobuf(&bytes).unwrap();
+
+    assert_eq!(decoded.sensor_id, frame.sensor_id);
+    assert_eq!(decoded.sensor_type, frame.sensor_type);
+    assert_eq!(decoded.timestamp, frame.timestamp);
+    assert_eq!(decoded.data, frame.data);
+    assert_eq!(decoded.metadata, frame.metadata);
+    assert_eq!(decoded.checksum, frame.checksum);
+}
+
+#[test]
+fn test_decodes_known_byte_buffer() {
+    // field 1 "imu_base", field 2 "imu", field 3 = 1000, field 4 = [9, 9]
+    let bytes: Vec<u8> = vec![
+        0x0a, 0x08, b'i', b'm', b'u', b'_', b'b', b'a', b's', b'e', // sensor_id
+        0x12, 0x03, b'i', b'm', b'u', // sensor_type
+        0x18, 0xe8, 0x07, // timestamp_ms = 1000
+        0x22, 0x02, 0x09, 0x09, // data
+    ];
+
+    let decoded = SensorData::from_protobuf(&bytes).unwrap();
+    assert_eq!(decoded.sensor_id, "imu_base");
+    assert_eq!(decoded.sensor_type, SensorType::IMU);
+    assert_eq!(decoded.timestamp.timestamp_millis(), 1000);
+    assert_eq!(decoded.data, vec![9, 9]);
+    assert!(decoded.checksum.is_none());
+}
+
+#[test]
+fn test_unknown_sensor_type_errors() {
+    let bytes: Vec<u8> = vec![
+        0x0a, 0x01, b'x', // sensor_id
+        0x12, 0x05, b's', b'o', b'n', b'a', b'r', // sensor_type
+    ];
+
+    assert!(SensorData::from_protobuf(&bytes).is_err());
+}
@@ -0,0 +1,134 @@
+//! Unit tests for EMA quality score smoothing
+
+use kova_core::core::validation::{DataValidator, ValidationConfig};
+use kova_core::sensors::{SensorData, SensorType};
+use std::collections::HashMap;
+
+/// Build a camera frame around the given payload
+fn frame(sensor_id: &str, data: Vec<u8>) -> SensorData {
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: sensor_id.to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::Utc::now(),
+        data,
+        metadata: HashMap::new(),
+        checksum: None,
+    }
+}
+
+/// A clean, repetitive payload that scores well
+fn clean_payload() -> Vec<u8> {
+    vec![0xAA; 256]
+}
+
+/// A noisy payload that scores worse
+fn noisy_payload(seed: u8) -> Vec<u8> {
+    (0..256u16)
+        .map(|i| (i as u8).wrapping_mul(31).wrapping_add(seed))
+        .collect()
+}
+
+fn variance(series: &[f64]) -> f64 {
+    let mean = series.iter().sum::<f64>() / series.len() as f64;
+    series.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / series.len() as f64
+}
+
+#[tokio::test]
+async fn test_smoothed_series_has_lower_variance() {
+    let mut validator = DataValidator::new();
+    validator.enable_score_smoothing(0.3).unwrap();
+
+    let mut instantaneous = Vec::new();
+    let mut smoothed = Vec::new();
+    for i in 0..20u8 {
+        // Alternate clean and noisy payloads to produce a jittery score series
+        let data = if i % 2 == 0 {
+            clean_payload()
+        } else {
+            noisy_payload(i)
+        };
+        let result = validator
+            .validate_frame_smoothed(&frame("camera_01", data))
+            .await
+            .unwrap();
+        instantaneous.push(result.instantaneous_score);
+        smoothed.push(result.smoothed_score);
+    }
+
+    assert!(variance(&instantaneous) > 0.0, "input series should be noisy");
+    assert!(variance(&smoothed) < variance(&instantaneous));
+
+    // The smoothed series stays inside the range of observed scores
+    let min = instantaneous.iter().cloned().fold(f64::MAX, f64::min);
+    let max = instantaneous.iter().cloned().fold(f64::MIN, f64::max);
+    assert!(smoothed.iter().all(|s| *s >= min && *s <= max));
+}
+
+#[tokio::test]
+async fn test_smoothed_score_tracks_trend() {
+    let mut validator = DataValidator::new();
+    validator.enable_score_smoothing(0.5).unwrap();
+
+    // Start noisy, then sustain clean frames: the EMA should converge upward
+    let mut last = 0.0;
+    for i in 0..3u8 {
+        last = validator
+            .validate_frame_smoothed(&frame("camera_01", noisy_payload(i)))
+            .await
+            .unwrap()
+            .smoothed_score;
+    }
+    let after_noisy = last;
+
+    for i in 0..10u8 {
+        // Vary one byte per frame so frozen-frame detection doesn't kick in
+        let mut data = clean_payload();
+        data[0] = i;
+        last = validator
+            .validate_frame_smoothed(&frame("camera_01", data))
+            .await
+            .unwrap()
+            .smoothed_score;
+    }
+
+    assert!(last > after_noisy);
+}
+
+#[tokio::test]
+async fn test_smoothing_is_keyed_per_sensor() {
+    let mut validator = DataValidator::new();
+    validator.enable_score_smoothing(0.5).unwrap();
+
+    for i in 0..5u8 {
+        validator
+            .validate_frame_smoothed(&frame("camera_01", noisy_payload(i)))
+            .await
+            .unwrap();
+    }
+
+    // A fresh sensor starts from its own first score, unaffected by camera_01
+    let result = validator
+        .validate_frame_smoothed(&frame("camera_02", clean_payload()))
+        .await
+        .unwrap();
+    assert_eq!(result.smoothed_score, result.instantaneous_score);
+}
+
+#[tokio::test]
+async fn test_disabled_smoothing_reports_instantaneous_score() {
+    let validator = DataValidator::with_config(ValidationConfig::default());
+    let result = validator
+        .validate_frame_smoothed(&frame("camera_01", clean_payload()))
+        .await
+        .unwrap();
+    assert_eq!(result.smoothed_score, result.instantaneous_score);
+}
+
+#[test]
+fn test_smoothing_factor_is_validated() {
+    let mut validator = DataValidator::new();
+    assert!(validator.enable_score_smoothing(0.0).is_err());
+    assert!(validator.enable_score_smoothing(1.5).is_err());
+    assert!(validator.enable_score_smoothing(1.0).is_ok());
+}
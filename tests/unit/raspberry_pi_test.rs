@@ -0,0 +1,69 @@
+//! Unit tests for the Raspberry Pi robot integration
+
+use kova_core::robots::raspberry_pi::{
+    PinConfig, PinMode, PullUpDown, RaspberryPiConfig, RaspberryPiRobot,
+};
+
+fn config_with_pins() -> RaspberryPiConfig {
+    let mut config = RaspberryPiConfig::default();
+    config.gpio_pins.insert(
+        17,
+        PinConfig {
+            mode: PinMode::Output,
+            pull_up_down: None,
+            initial_value: Some(false),
+        },
+    );
+    config.gpio_pins.insert(
+        27,
+        PinConfig {
+            mode: PinMode::Input,
+            pull_up_down: Some(PullUpDown::PullUp),
+            initial_value: None,
+        },
+    );
+    config
+}
+
+#[test]
+fn test_output_pin_write_read_round_trip() {
+    let mut robot = RaspberryPiRobot::new("pi_001".to_string(), config_with_pins()).unwrap();
+
+    assert!(!robot.read_pin(17).unwrap());
+    robot.write_pin(17, true).unwrap();
+    assert!(robot.read_pin(17).unwrap());
+}
+
+#[test]
+fn test_writing_input_pin_errors() {
+    let mut robot = RaspberryPiRobot::new("pi_001".to_string(), config_with_pins()).unwrap();
+
+    assert!(robot.write_pin(27, true).is_err());
+}
+
+#[test]
+fn test_input_pin_defaults_to_pull_state() {
+    let robot = RaspberryPiRobot::new("pi_001".to_string(), config_with_pins()).unwrap();
+
+    // Pull-up inputs read high until driven otherwise
+    assert!(robot.read_pin(27).unwrap());
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_system_info_values_are_plausible() {
+    let mut robot = RaspberryPiRobot::new("pi_001".to_string(), config_with_pins()).unwrap();
+    robot.update_system_info().await.unwrap();
+
+    let info = robot.get_system_info();
+    assert!((0.0..=100.0).contains(&info.cpu_usage));
+    assert!((0.0..=100.0).contains(&info.memory_usage));
+    assert_eq!(info.is_simulated, cfg!(not(feature = "raspberry-pi")));
+}
+
+#[test]
+fn test_unconfigured_pin_errors() {
+    let robot = RaspberryPiRobot::new("pi_001".to_string(), config_with_pins()).unwrap();
+
+    assert!(robot.read_pin(5).is_err());
+}
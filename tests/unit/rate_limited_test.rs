@@ -0,0 +1,75 @@
+//! Unit tests for the rate-limiting sensor wrapper
+
+use kova_core::core::Error;
+use kova_core::sensors::{RateLimited, Sensor, SensorData, SensorType};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Mock sensor that counts how often it is actually polled
+struct CountingSensor {
+    captures: usize,
+}
+
+impl CountingSensor {
+    fn new() -> Self {
+        Self { captures: 0 }
+    }
+}
+
+#[async_trait::async_trait]
+impl Sensor for CountingSensor {
+    fn id(&self) -> &str {
+        "counting"
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        SensorType::IMU
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        self.captures += 1;
+        Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
+            sensor_id: "counting".to_string(),
+            sensor_type: SensorType::IMU,
+            timestamp: chrono::Utc::now(),
+            data: vec![self.captures as u8],
+            metadata: HashMap::new(),
+            checksum: None,
+        })
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &"counting"
+    }
+}
+
+#[tokio::test]
+async fn test_rapid_captures_poll_inner_at_limited_rate() {
+    let mut sensor = RateLimited::new(CountingSensor::new(), Duration::from_millis(50));
+
+    for _ in 0..10 {
+        sensor.capture().await.unwrap();
+    }
+
+    // Only the first capture hits the inner sensor within the interval
+    assert_eq!(sensor.inner().captures, 1);
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    sensor.capture().await.unwrap();
+    assert_eq!(sensor.inner().captures, 2);
+}
+
+#[tokio::test]
+async fn test_limited_captures_return_cached_frame() {
+    let mut sensor = RateLimited::new(CountingSensor::new(), Duration::from_secs(10));
+
+    let first = sensor.capture().await.unwrap();
+    let second = sensor.capture().await.unwrap();
+
+    assert_eq!(first.data, second.data);
+}
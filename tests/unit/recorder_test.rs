@@ -0,0 +1,78 @@
+//! Unit tests for the session recorder
+
+use kova_core::sensors::camera::{Camera, CameraConfig};
+use kova_core::sensors::{
+    RecorderConfig, ReplayConfig, ReplaySensor, SensorManager, SessionRecorder,
+};
+
+#[tokio::test]
+async fn test_recorded_session_replays_identically() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.log");
+
+    let manager = SensorManager::new();
+    let mut camera = Camera::new("camera_1".to_string(), CameraConfig::default()).unwrap();
+    camera.initialize().await.unwrap();
+    manager.add_sensor(Box::new(camera)).await.unwrap();
+
+    let mut recorder = SessionRecorder::new(RecorderConfig::new(path.clone()));
+    recorder.start().unwrap();
+
+    let mut recorded = Vec::new();
+    for _ in 0..3 {
+        recorded.extend(recorder.record_capture(&manager).await.unwrap());
+    }
+    recorder.stop().unwrap();
+
+    let mut replay =
+        ReplaySensor::from_file("replay_1".to_string(), &path, ReplayConfig::default()).unwrap();
+    assert_eq!(replay.frame_count(), 3);
+
+    use kova_core::sensors::Sensor;
+    for original in &recorded {
+        let frame = replay.capture().await.unwrap();
+        assert_eq!(frame.sensor_id, original.sensor_id);
+        assert_eq!(frame.data, original.data);
+        assert_eq!(
+            frame.timestamp.timestamp_millis(),
+            original.timestamp.timestamp_millis()
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_recorder_rotates_at_size_threshold() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.log");
+
+    let manager = SensorManager::new();
+    let mut config = CameraConfig::default();
+    config.resolution = (64, 64);
+    let mut camera = Camera::new("camera_1".to_string(), config).unwrap();
+    camera.initialize().await.unwrap();
+    manager.add_sensor(Box::new(camera)).await.unwrap();
+
+    // Each 64x64 RGB frame is ~12 KB, so a 16 KB cap forces rotation
+    let mut recorder = SessionRecorder::new(RecorderConfig {
+        path: path.clone(),
+        max_file_size: 16 * 1024,
+    });
+    recorder.start().unwrap();
+    for _ in 0..3 {
+        recorder.record_capture(&manager).await.unwrap();
+    }
+    recorder.stop().unwrap();
+
+    assert!(recorder.files().len() >= 2);
+    for file in recorder.files() {
+        assert!(file.exists());
+    }
+}
+
+#[test]
+fn test_record_before_start_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut recorder =
+        SessionRecorder::new(RecorderConfig::new(dir.path().join("session.log")));
+    assert!(!recorder.is_recording());
+}
@@ -0,0 +1,126 @@
+//! Unit tests for camera frame redaction
+
+use kova_core::sensors::{BoundingBox, RedactionPolicy, SensorData, SensorType};
+use std::collections::HashMap;
+
+const WIDTH: u32 = 32;
+const HEIGHT: u32 = 24;
+
+/// An RGB frame with a distinct value per pixel position
+fn rgb_frame() -> Vec<u8> {
+    (0..WIDTH * HEIGHT * 3).map(|i| (i % 251) as u8).collect()
+}
+
+fn in_box(x: u32, y: u32, bbox: &BoundingBox) -> bool {
+    x >= bbox.x && x < bbox.x + bbox.width && y >= bbox.y && y < bbox.y + bbox.height
+}
+
+#[test]
+fn test_blur_changes_inside_and_preserves_outside() {
+    let original = rgb_frame();
+    let mut frame = original.clone();
+    let bbox = BoundingBox {
+        x: 8,
+        y: 6,
+        width: 12,
+        height: 10,
+    };
+
+    let policy = RedactionPolicy::new(2.0).unwrap();
+    policy.blur_region(&mut frame, WIDTH, HEIGHT, &bbox).unwrap();
+
+    let mut changed_inside = 0;
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let offset = ((y * WIDTH + x) * 3) as usize;
+            let pixel = &frame[offset..offset + 3];
+            let before = &original[offset..offset + 3];
+            if in_box(x, y, &bbox) {
+                if pixel != before {
+                    changed_inside += 1;
+                }
+            } else {
+                assert_eq!(pixel, before, "pixel ({}, {}) outside the box changed", x, y);
+            }
+        }
+    }
+    // The gradient pattern means blurring must alter most box pixels
+    assert!(changed_inside > (bbox.width * bbox.height / 2) as usize);
+}
+
+#[test]
+fn test_box_is_clipped_to_the_image() {
+    let mut frame = rgb_frame();
+    let bbox = BoundingBox {
+        x: 28,
+        y: 20,
+        width: 100,
+        height: 100,
+    };
+    let policy = RedactionPolicy::default();
+    assert!(policy.blur_region(&mut frame, WIDTH, HEIGHT, &bbox).is_ok());
+}
+
+#[test]
+fn test_buffer_size_is_validated() {
+    let mut frame = vec![0u8; 10];
+    let bbox = BoundingBox {
+        x: 0,
+        y: 0,
+        width: 4,
+        height: 4,
+    };
+    let policy = RedactionPolicy::default();
+    assert!(policy.blur_region(&mut frame, WIDTH, HEIGHT, &bbox).is_err());
+}
+
+#[test]
+fn test_apply_uses_resolution_metadata() {
+    let mut metadata = HashMap::new();
+    metadata.insert("resolution".to_string(), format!("{}x{}", WIDTH, HEIGHT));
+    let mut frame = SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: "camera_01".to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::Utc::now(),
+        data: rgb_frame(),
+        metadata,
+        checksum: None,
+    };
+    let original = frame.data.clone();
+
+    let policy = RedactionPolicy::default();
+    policy
+        .apply(
+            &mut frame,
+            &[BoundingBox {
+                x: 0,
+                y: 0,
+                width: 8,
+                height: 8,
+            }],
+        )
+        .unwrap();
+    assert_ne!(frame.data, original);
+}
+
+#[test]
+fn test_non_camera_frames_are_rejected() {
+    let mut frame = SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: "imu_01".to_string(),
+        sensor_type: SensorType::IMU,
+        timestamp: chrono::Utc::now(),
+        data: vec![0; 12],
+        metadata: HashMap::new(),
+        checksum: None,
+    };
+    let policy = RedactionPolicy::default();
+    assert!(policy.apply(&mut frame, &[]).is_err());
+}
+
+#[test]
+fn test_sigma_must_be_positive() {
+    assert!(RedactionPolicy::new(0.0).is_err());
+    assert!(RedactionPolicy::new(-1.0).is_err());
+}
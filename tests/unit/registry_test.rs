@@ -0,0 +1,112 @@
+//! Unit tests for the sensor factory registry
+
+use kova_core::core::Error;
+use kova_core::sensors::{Sensor, SensorData, SensorRegistry, SensorState, SensorType};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// A third-party radar sensor, as an integrator would define one
+#[derive(Debug, Deserialize)]
+struct RadarConfig {
+    range_max: f32,
+}
+
+#[derive(Debug)]
+struct Radar {
+    id: String,
+    config: RadarConfig,
+}
+
+#[async_trait::async_trait]
+impl Sensor for Radar {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        SensorType::LiDAR
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
+            sensor_id: self.id.clone(),
+            sensor_type: self.sensor_type(),
+            timestamp: chrono::Utc::now(),
+            data: self.config.range_max.to_le_bytes().to_vec(),
+            metadata: HashMap::new(),
+            checksum: None,
+        })
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn state(&self) -> SensorState {
+        SensorState::Ready
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &self.config
+    }
+}
+
+fn radar_registry() -> SensorRegistry {
+    let mut registry = SensorRegistry::new();
+    registry.register("radar", |id, config| {
+        let config: RadarConfig = serde_json::from_value(config.clone())
+            .map_err(|e| Error::sensor(format!("Invalid radar config: {}", e)))?;
+        Ok(Box::new(Radar { id, config }) as Box<dyn Sensor>)
+    });
+    registry
+}
+
+#[tokio::test]
+async fn test_custom_factory_builds_a_working_sensor() {
+    let registry = radar_registry();
+    let config = serde_json::json!({ "range_max": 120.0 });
+
+    let mut sensor = registry
+        .create("radar", "radar_01".to_string(), &config)
+        .unwrap();
+
+    assert_eq!(sensor.id(), "radar_01");
+    let frame = sensor.capture().await.unwrap();
+    assert_eq!(frame.sensor_id, "radar_01");
+    assert_eq!(frame.data, 120.0f32.to_le_bytes().to_vec());
+}
+
+#[test]
+fn test_unknown_type_is_rejected() {
+    let registry = radar_registry();
+    let err = registry
+        .create("sonar", "sonar_01".to_string(), &serde_json::json!({}))
+        .err()
+        .expect("unknown sensor type should be rejected");
+    assert!(err.to_string().contains("sonar"));
+}
+
+#[test]
+fn test_malformed_config_is_rejected() {
+    let registry = radar_registry();
+    let config = serde_json::json!({ "range_max": "not a number" });
+    assert!(registry
+        .create("radar", "radar_01".to_string(), &config)
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_builtin_types_are_available() {
+    let registry = SensorRegistry::with_builtins();
+    assert_eq!(
+        registry.type_names(),
+        vec!["camera", "gps", "imu", "lidar", "thermal"]
+    );
+
+    let config = serde_json::to_value(kova_core::sensors::gps::GPSConfig::default()).unwrap();
+    let sensor = registry
+        .create("gps", "gps_01".to_string(), &config)
+        .unwrap();
+    assert_eq!(sensor.sensor_type(), SensorType::GPS);
+}
@@ -0,0 +1,71 @@
+//! Integration tests for the REST API server
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use kova_core::api::rest::RestApiServer;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn test_health_endpoint() {
+    let server = RestApiServer::new("127.0.0.1".to_string(), 0);
+    let router = server.router();
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/health")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["success"], true);
+    assert_eq!(json["data"], "ok");
+}
+
+#[tokio::test]
+async fn test_sensor_data_round_trip() {
+    let server = RestApiServer::new("127.0.0.1".to_string(), 0);
+    let router = server.router();
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/sensor-data")
+                .body(Body::from("test sensor payload".as_bytes().to_vec()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["success"], true);
+    let score = json["data"]["quality_score"].as_f64().unwrap();
+    assert!((0.0..=1.0).contains(&score));
+    assert!(!json["data"]["signature"].as_str().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_unknown_contribution_is_not_found() {
+    let server = RestApiServer::new("127.0.0.1".to_string(), 0);
+    let router = server.router();
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/contributions/QmDoesNotExist")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
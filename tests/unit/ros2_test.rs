@@ -0,0 +1,123 @@
+//! Unit tests for the ROS2 bridge
+
+use kova_core::robots::ros2::bridge::{ROS2Bridge, ROS2Config};
+use kova_core::sensors::{SensorData, SensorType};
+use std::collections::HashMap;
+
+fn bridge_config() -> ROS2Config {
+    ROS2Config {
+        node_name: "kova_bridge".to_string(),
+        namespace: "/kova".to_string(),
+        qos_profile: "default".to_string(),
+    }
+}
+
+fn sample_frame() -> SensorData {
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: "camera_front".to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::Utc::now(),
+        data: vec![1, 2, 3, 4],
+        metadata: HashMap::new(),
+        checksum: None,
+    }
+}
+
+#[tokio::test]
+async fn test_publish_subscribe_round_trip() {
+    let mut bridge = ROS2Bridge::new(bridge_config()).await.unwrap();
+    bridge.connect().await.unwrap();
+
+    let mut receiver = bridge.subscribe("camera_front").await.unwrap();
+    bridge.publish("camera_front", &sample_frame()).await.unwrap();
+
+    let received = receiver.recv().await.unwrap();
+    assert_eq!(received.sensor_id, "camera_front");
+    assert_eq!(received.data, vec![1, 2, 3, 4]);
+}
+
+#[tokio::test]
+async fn test_publish_requires_connection() {
+    let bridge = ROS2Bridge::new(bridge_config()).await.unwrap();
+
+    assert!(bridge.publish("camera_front", &sample_frame()).await.is_err());
+}
+
+#[test]
+fn test_known_qos_profiles_parse() {
+    use kova_core::robots::ros2::config::QosProfile;
+
+    assert_eq!(QosProfile::parse("sensor_data").unwrap(), QosProfile::SensorData);
+    assert_eq!(QosProfile::parse("reliable").unwrap(), QosProfile::Reliable);
+    assert_eq!(QosProfile::parse("default").unwrap(), QosProfile::Reliable);
+    assert_eq!(QosProfile::parse("best_effort").unwrap(), QosProfile::BestEffort);
+    assert_eq!(QosProfile::parse("parameters").unwrap(), QosProfile::Parameters);
+}
+
+#[test]
+fn test_unknown_qos_profile_errors() {
+    use kova_core::robots::ros2::config::QosProfile;
+
+    assert!(QosProfile::parse("ultra_reliable").is_err());
+}
+
+#[tokio::test]
+async fn test_bridge_rejects_unknown_qos_profile() {
+    let config = ROS2Config {
+        node_name: "kova_bridge".to_string(),
+        namespace: "/kova".to_string(),
+        qos_profile: "nonsense".to_string(),
+    };
+
+    assert!(ROS2Bridge::new(config).await.is_err());
+}
+
+#[tokio::test]
+async fn test_manager_publishes_to_every_bridge() {
+    use kova_core::robots::ros2::ROS2Manager;
+
+    let mut manager = ROS2Manager::new();
+    manager
+        .add_bridge("left".to_string(), bridge_config())
+        .await
+        .unwrap();
+    manager
+        .add_bridge("right".to_string(), bridge_config())
+        .await
+        .unwrap();
+    manager.connect_all().await.unwrap();
+
+    let mut left_rx = manager
+        .get_bridge("left")
+        .unwrap()
+        .subscribe("camera_front")
+        .await
+        .unwrap();
+    let mut right_rx = manager
+        .get_bridge("right")
+        .unwrap()
+        .subscribe("camera_front")
+        .await
+        .unwrap();
+
+    manager.publish_all("camera_front", &sample_frame()).await.unwrap();
+
+    assert_eq!(left_rx.recv().await.unwrap().sensor_id, "camera_front");
+    assert_eq!(right_rx.recv().await.unwrap().sensor_id, "camera_front");
+
+    manager.disconnect_all().await.unwrap();
+    assert!(manager.publish_all("camera_front", &sample_frame()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_topics_resolve_within_namespace() {
+    let mut bridge = ROS2Bridge::new(bridge_config()).await.unwrap();
+    bridge.connect().await.unwrap();
+
+    // Relative and absolute forms of the same topic share a channel
+    let mut receiver = bridge.subscribe("/kova/imu_base").await.unwrap();
+    bridge.publish("imu_base", &sample_frame()).await.unwrap();
+
+    assert!(receiver.recv().await.is_ok());
+}
@@ -0,0 +1,87 @@
+//! Unit tests for the per-sensor sampling scheduler
+
+use kova_core::sensors::camera::{Camera, CameraConfig};
+use kova_core::sensors::{OverflowPolicy, SensorManager};
+use std::sync::Arc;
+use std::time::Duration;
+
+async fn small_camera(id: &str) -> Camera {
+    let mut config = CameraConfig::default();
+    config.resolution = (8, 8);
+    let mut camera = Camera::new(id.to_string(), config).unwrap();
+    camera.initialize().await.unwrap();
+    camera
+}
+
+#[tokio::test]
+async fn test_sensors_sample_at_their_own_rates() {
+    let manager = Arc::new(SensorManager::new());
+    manager
+        .add_sensor(Box::new(small_camera("fast_camera").await))
+        .await
+        .unwrap();
+    manager
+        .add_sensor(Box::new(small_camera("slow_camera").await))
+        .await
+        .unwrap();
+
+    manager.set_rate("fast_camera", 40.0).await.unwrap();
+    manager.set_rate("slow_camera", 10.0).await.unwrap();
+
+    let mut frames = manager.run_scheduler(256, OverflowPolicy::Block);
+
+    let mut fast = 0usize;
+    let mut slow = 0usize;
+    let deadline = tokio::time::Instant::now() + Duration::from_millis(500);
+    while let Ok(Some(frame)) = tokio::time::timeout_at(deadline, frames.recv()).await {
+        match frame.sensor_id.as_str() {
+            "fast_camera" => fast += 1,
+            "slow_camera" => slow += 1,
+            other => panic!("unexpected sensor id: {}", other),
+        }
+    }
+
+    assert!(fast > 0 && slow > 0);
+    // 40Hz vs 10Hz should be roughly 4x; allow generous scheduling slack
+    assert!(
+        fast >= slow * 2,
+        "expected the fast sensor to dominate: fast={} slow={}",
+        fast,
+        slow
+    );
+}
+
+#[tokio::test]
+async fn test_set_rate_validates_input() {
+    let manager = Arc::new(SensorManager::new());
+    manager
+        .add_sensor(Box::new(small_camera("camera_1").await))
+        .await
+        .unwrap();
+
+    assert!(manager.set_rate("camera_1", 0.0).await.is_err());
+    assert!(manager.set_rate("camera_1", -5.0).await.is_err());
+    assert!(manager.set_rate("missing", 10.0).await.is_err());
+    assert!(manager.set_rate("camera_1", 10.0).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_drop_oldest_counts_dropped_frames() {
+    let manager = Arc::new(SensorManager::new());
+    manager
+        .add_sensor(Box::new(small_camera("camera_1").await))
+        .await
+        .unwrap();
+    manager.set_rate("camera_1", 100.0).await.unwrap();
+
+    // Tiny buffer and a consumer that never reads
+    let frames = manager.run_scheduler(2, OverflowPolicy::DropOldest);
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    assert!(
+        manager.dropped_frames() > 0,
+        "expected drops with an idle consumer"
+    );
+    drop(frames);
+}
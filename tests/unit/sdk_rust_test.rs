@@ -0,0 +1,208 @@
+//! Integration tests for the Rust SDK HTTP client
+//!
+//! Runs a small in-process HTTP server that records what the SDK sends.
+
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use kova_core::sdk::rust::{Contribution, RustSDK, RustSDKConfig};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+/// What the mock server saw for one request
+#[derive(Debug, Clone)]
+struct Recorded {
+    path: String,
+    api_key: Option<String>,
+    body: Vec<u8>,
+}
+
+type Log = Arc<Mutex<Vec<Recorded>>>;
+
+fn record(log: &Log, path: &str, headers: &HeaderMap, body: &[u8]) {
+    log.lock().unwrap().push(Recorded {
+        path: path.to_string(),
+        api_key: headers
+            .get("x-api-key")
+            .and_then(|v| v.to_str().ok())
+            .map(String::from),
+        body: body.to_vec(),
+    });
+}
+
+fn envelope(data: serde_json::Value) -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "success": true,
+        "data": data,
+        "error": null,
+        "timestamp": chrono::Utc::now(),
+    }))
+}
+
+async fn start_mock_server(log: Log) -> SocketAddr {
+    let router = Router::new()
+        .route(
+            "/sensor-data",
+            post(
+                |State(log): State<Log>, headers: HeaderMap, body: axum::body::Bytes| async move {
+                    record(&log, "/sensor-data", &headers, &body);
+                    envelope(serde_json::json!("sensor_data_received"))
+                },
+            ),
+        )
+        .route(
+            "/validation/:id",
+            get(
+                |State(log): State<Log>, Path(id): Path<String>, headers: HeaderMap| async move {
+                    record(&log, &format!("/validation/{}", id), &headers, &[]);
+                    envelope(serde_json::json!({
+                        "id": id,
+                        "quality_score": 0.9,
+                        "is_valid": true,
+                        "timestamp": chrono::Utc::now(),
+                    }))
+                },
+            ),
+        )
+        .route(
+            "/contributions",
+            post(|State(log): State<Log>, headers: HeaderMap, body: axum::body::Bytes| async move {
+                record(&log, "/contributions", &headers, &body);
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    Json(serde_json::json!({
+                        "success": false,
+                        "data": null,
+                        "error": "contribution rejected",
+                        "timestamp": chrono::Utc::now(),
+                    })),
+                )
+            }),
+        )
+        .with_state(log);
+
+    let server = axum::Server::bind(&"127.0.0.1:0".parse().unwrap())
+        .serve(router.into_make_service());
+    let addr = server.local_addr();
+    tokio::spawn(server);
+    addr
+}
+
+fn sdk_for(addr: SocketAddr) -> RustSDK {
+    let config = RustSDKConfig {
+        api_endpoint: format!("http://{}", addr),
+        api_key: Some("test-key".to_string()),
+        ..RustSDKConfig::default()
+    };
+    RustSDK::new(config).unwrap()
+}
+
+#[tokio::test]
+async fn test_send_sensor_data_posts_raw_bytes() {
+    let log: Log = Arc::default();
+    let addr = start_mock_server(log.clone()).await;
+
+    let response = sdk_for(addr).send_sensor_data(b"payload").await.unwrap();
+    assert!(response.success);
+    assert_eq!(response.data.as_deref(), Some("sensor_data_received"));
+
+    let recorded = log.lock().unwrap();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].path, "/sensor-data");
+    assert_eq!(recorded[0].api_key.as_deref(), Some("test-key"));
+    assert_eq!(recorded[0].body, b"payload");
+}
+
+#[tokio::test]
+async fn test_get_validation_result_parses_envelope() {
+    let log: Log = Arc::default();
+    let addr = start_mock_server(log.clone()).await;
+
+    let response = sdk_for(addr).get_validation_result("abc123").await.unwrap();
+    let result = response.data.unwrap();
+    assert_eq!(result.id, "abc123");
+    assert!(result.is_valid);
+
+    assert_eq!(log.lock().unwrap()[0].path, "/validation/abc123");
+}
+
+#[tokio::test]
+async fn test_server_error_surfaces_as_error() {
+    let log: Log = Arc::default();
+    let addr = start_mock_server(log.clone()).await;
+
+    let contribution = Contribution {
+        sensor_data_hash: "QmHash".to_string(),
+        validator_signature: "sig".to_string(),
+        quality_score: 0.5,
+        timestamp: chrono::Utc::now(),
+    };
+    let error = sdk_for(addr)
+        .submit_contribution(&contribution)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("contribution rejected"));
+
+    // The request body was the JSON-encoded contribution
+    let recorded = log.lock().unwrap();
+    let sent: serde_json::Value = serde_json::from_slice(&recorded[0].body).unwrap();
+    assert_eq!(sent["sensor_data_hash"], "QmHash");
+}
+
+#[tokio::test]
+async fn test_subscribe_receives_published_messages() {
+    use futures_util::StreamExt;
+    use kova_core::api::websocket::{WebSocketMessage, WebSocketServer};
+
+    let server = WebSocketServer::new("127.0.0.1".to_string(), 0);
+    server.start().await.unwrap();
+    let ws_addr = server.local_addr().await.unwrap();
+
+    let config = RustSDKConfig {
+        api_endpoint: format!("http://{}", ws_addr),
+        ..RustSDKConfig::default()
+    };
+    let sdk = RustSDK::new(config).unwrap();
+    let mut stream = Box::pin(sdk.subscribe("camera_front").await.unwrap());
+
+    // Give the server a moment to register the subscription
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    server
+        .broadcast_to_topic(
+            "camera_front",
+            WebSocketMessage::SensorData {
+                sensor_id: "camera_front".to_string(),
+                sensor_type: "camera".to_string(),
+                data: vec![1, 2, 3],
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                metadata: std::collections::HashMap::new(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let received = tokio::time::timeout(std::time::Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap();
+    match received {
+        WebSocketMessage::SensorData { sensor_id, data, .. } => {
+            assert_eq!(sensor_id, "camera_front");
+            assert_eq!(data, vec![1, 2, 3]);
+        }
+        other => panic!("Expected SensorData, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_unreachable_endpoint_is_a_network_error() {
+    let config = RustSDKConfig {
+        api_endpoint: "http://127.0.0.1:1".to_string(),
+        timeout_seconds: 1,
+        ..RustSDKConfig::default()
+    };
+    let sdk = RustSDK::new(config).unwrap();
+    assert!(sdk.send_sensor_data(b"payload").await.is_err());
+}
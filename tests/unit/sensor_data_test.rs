@@ -0,0 +1,37 @@
+//! Unit tests for SensorData integrity checks
+
+use kova_core::sensors::{SensorData, SensorType};
+use std::collections::HashMap;
+
+fn sample_frame() -> SensorData {
+    SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: "camera_front".to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::Utc::now(),
+        data: vec![10, 20, 30, 40],
+        metadata: HashMap::new(),
+        checksum: None,
+    }
+}
+
+#[test]
+fn test_valid_frame_passes_verification() {
+    let frame = sample_frame().with_checksum();
+
+    assert!(frame.checksum.is_some());
+    assert!(frame.verify_checksum());
+}
+
+#[test]
+fn test_corrupted_frame_fails_verification() {
+    let mut frame = sample_frame().with_checksum();
+    frame.data[2] ^= 0xFF;
+
+    assert!(!frame.verify_checksum());
+}
+
+#[test]
+fn test_frame_without_checksum_verifies_trivially() {
+    assert!(sample_frame().verify_checksum());
+}
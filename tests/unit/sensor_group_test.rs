@@ -0,0 +1,116 @@
+//! Unit tests for the multiplexing sensor group
+
+use kova_core::core::Error;
+use kova_core::sensors::{Sensor, SensorData, SensorGroup, SensorType};
+use std::collections::HashMap;
+
+/// Mock camera returning a fixed payload
+struct MockCamera {
+    id: String,
+    payload: Vec<u8>,
+}
+
+#[async_trait::async_trait]
+impl Sensor for MockCamera {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        SensorType::Camera
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        let mut metadata = HashMap::new();
+        metadata.insert("exposure".to_string(), "auto".to_string());
+        Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
+            sensor_id: self.id.clone(),
+            sensor_type: SensorType::Camera,
+            timestamp: chrono::Utc::now(),
+            data: self.payload.clone(),
+            metadata,
+            checksum: None,
+        })
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &"mock_camera"
+    }
+}
+
+fn mock_camera(id: &str, payload: Vec<u8>) -> Box<MockCamera> {
+    Box::new(MockCamera {
+        id: id.to_string(),
+        payload,
+    })
+}
+
+#[tokio::test]
+async fn test_merged_capture_contains_both_sub_frames() {
+    let mut group = SensorGroup::new("stereo".to_string(), SensorType::Camera);
+    group.add_member(mock_camera("cam_left", vec![1, 1, 1])).unwrap();
+    group.add_member(mock_camera("cam_right", vec![2, 2])).unwrap();
+
+    let frame = group.capture().await.unwrap();
+
+    assert_eq!(frame.sensor_id, "stereo");
+
+    // First sub-frame: id header then payload
+    let id_len = u16::from_le_bytes([frame.data[0], frame.data[1]]) as usize;
+    assert_eq!(&frame.data[2..2 + id_len], b"cam_left");
+    let offset = 2 + id_len;
+    let payload_len = u32::from_le_bytes(frame.data[offset..offset + 4].try_into().unwrap());
+    assert_eq!(payload_len, 3);
+    assert_eq!(&frame.data[offset + 4..offset + 7], &[1, 1, 1]);
+
+    // Second sub-frame follows the first
+    let second = offset + 7;
+    let id_len = u16::from_le_bytes([frame.data[second], frame.data[second + 1]]) as usize;
+    assert_eq!(&frame.data[second + 2..second + 2 + id_len], b"cam_right");
+
+    // Metadata is merged under source-prefixed keys
+    assert!(frame.metadata.contains_key("cam_left.exposure"));
+    assert!(frame.metadata.contains_key("cam_right.exposure"));
+}
+
+#[tokio::test]
+async fn test_group_rejects_mismatched_sensor_type() {
+    struct MockImu;
+
+    #[async_trait::async_trait]
+    impl Sensor for MockImu {
+        fn id(&self) -> &str {
+            "imu"
+        }
+
+        fn sensor_type(&self) -> SensorType {
+            SensorType::IMU
+        }
+
+        async fn capture(&mut self) -> Result<SensorData, Error> {
+            Err(Error::sensor("unused"))
+        }
+
+        async fn is_available(&self) -> bool {
+            true
+        }
+
+        fn config(&self) -> &dyn std::fmt::Debug {
+            &"mock_imu"
+        }
+    }
+
+    let mut group = SensorGroup::new("stereo".to_string(), SensorType::Camera);
+    assert!(group.add_member(Box::new(MockImu)).is_err());
+}
+
+#[tokio::test]
+async fn test_empty_group_is_unavailable() {
+    let group = SensorGroup::new("stereo".to_string(), SensorType::Camera);
+    assert!(!group.is_available().await);
+}
@@ -0,0 +1,30 @@
+//! Unit tests for manager-driven sensor initialization
+
+use kova_core::sensors::camera::{Camera, CameraConfig};
+use kova_core::sensors::imu::{IMUConfig, IMU};
+use kova_core::sensors::SensorManager;
+
+#[tokio::test]
+async fn test_initialize_all_enables_captures() {
+    let manager = SensorManager::new();
+    manager
+        .add_sensor(Box::new(
+            Camera::new("camera_front".to_string(), CameraConfig::default()).unwrap(),
+        ))
+        .await
+        .unwrap();
+    manager
+        .add_sensor(Box::new(
+            IMU::new("imu_base".to_string(), IMUConfig::default()).unwrap(),
+        ))
+        .await
+        .unwrap();
+
+    // Uninitialized sensors are unavailable and produce nothing
+    assert!(manager.capture_all().await.unwrap().is_empty());
+
+    manager.initialize_all().await.unwrap();
+
+    let frames = manager.capture_all().await.unwrap();
+    assert_eq!(frames.len(), 2);
+}
@@ -0,0 +1,109 @@
+//! Unit tests for sensor reliability metrics
+
+use kova_core::core::Error;
+use kova_core::sensors::{Sensor, SensorData, SensorManager, SensorType};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Mock sensor that always captures successfully
+struct SteadySensor;
+
+#[async_trait::async_trait]
+impl Sensor for SteadySensor {
+    fn id(&self) -> &str {
+        "steady"
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        SensorType::IMU
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        Ok(SensorData {
+            frame_id: uuid::Uuid::new_v4(),
+            sensor_id: "steady".to_string(),
+            sensor_type: SensorType::IMU,
+            timestamp: chrono::Utc::now(),
+            data: vec![0],
+            metadata: HashMap::new(),
+            checksum: None,
+        })
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &"steady"
+    }
+}
+
+/// Mock sensor that always fails to capture
+struct BrokenSensor;
+
+#[async_trait::async_trait]
+impl Sensor for BrokenSensor {
+    fn id(&self) -> &str {
+        "broken"
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        SensorType::IMU
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        Err(Error::sensor("hardware fault"))
+    }
+
+    async fn is_available(&self) -> bool {
+        true
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &"broken"
+    }
+}
+
+#[tokio::test]
+async fn test_capture_rate_is_in_expected_range() {
+    let manager = SensorManager::new();
+    manager.add_sensor(Box::new(SteadySensor)).await.unwrap();
+
+    // ~10 Hz for two seconds
+    for _ in 0..20 {
+        manager.capture_all().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    let metrics = manager.metrics().await;
+    let steady = metrics.get("steady").unwrap();
+
+    assert!(
+        (5.0..=20.0).contains(&steady.captures_per_second),
+        "unexpected rate: {}",
+        steady.captures_per_second
+    );
+    assert!(steady.last_capture.is_some());
+    assert_eq!(steady.consecutive_failures, 0);
+    assert!(!steady.stale);
+}
+
+#[tokio::test]
+async fn test_failures_accumulate_and_sensor_goes_stale() {
+    let mut manager = SensorManager::new();
+    manager.set_stale_timeout(Duration::from_millis(50));
+    manager.add_sensor(Box::new(BrokenSensor)).await.unwrap();
+
+    for _ in 0..3 {
+        manager.capture_all().await.unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(60)).await;
+
+    let metrics = manager.metrics().await;
+    let broken = metrics.get("broken").unwrap();
+
+    assert_eq!(broken.consecutive_failures, 3);
+    assert!(broken.last_capture.is_none());
+    assert!(broken.stale);
+}
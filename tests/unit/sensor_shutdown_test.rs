@@ -0,0 +1,69 @@
+//! Unit tests for graceful sensor shutdown
+
+use kova_core::core::Error;
+use kova_core::sensors::{Sensor, SensorData, SensorManager, SensorType};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Mock sensor that records whether shutdown was invoked
+struct TrackedSensor {
+    id: String,
+    shut_down: Arc<AtomicBool>,
+    fail_shutdown: bool,
+}
+
+#[async_trait::async_trait]
+impl Sensor for TrackedSensor {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        SensorType::Camera
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        Err(Error::sensor("unused"))
+    }
+
+    async fn is_available(&self) -> bool {
+        false
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &"tracked"
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Error> {
+        self.shut_down.store(true, Ordering::SeqCst);
+        if self.fail_shutdown {
+            Err(Error::sensor("device busy"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_shutdown_all_reaches_every_sensor() {
+    let manager = SensorManager::new();
+    let flags: Vec<Arc<AtomicBool>> = (0..3).map(|_| Arc::new(AtomicBool::new(false))).collect();
+
+    for (i, flag) in flags.iter().enumerate() {
+        manager
+            .add_sensor(Box::new(TrackedSensor {
+                id: format!("sensor_{}", i),
+                shut_down: flag.clone(),
+                // A failing sensor must not stop the rest of the batch
+                fail_shutdown: i == 1,
+            }))
+            .await
+            .unwrap();
+    }
+
+    manager.shutdown_all().await.unwrap();
+
+    for flag in &flags {
+        assert!(flag.load(Ordering::SeqCst));
+    }
+}
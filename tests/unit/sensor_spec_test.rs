@@ -0,0 +1,77 @@
+//! Unit tests for the unified sensor spec and factory
+
+use kova_core::sensors::{create, SensorSpec, SensorType};
+
+#[test]
+fn test_specs_deserialize_from_toml_and_create_sensors() {
+    let specs: std::collections::HashMap<String, SensorSpec> = toml::from_str(
+        r#"
+        [camera_front]
+        kind = "camera"
+        resolution = [1920, 1080]
+        frame_rate = 30
+        format = "RGB"
+        auto_exposure = true
+        auto_white_balance = true
+        exposure_compensation = 0.0
+        iso_sensitivity = 100
+        focus_mode = "Auto"
+        white_balance_mode = "Auto"
+
+        [imu_main]
+        kind = "imu"
+        sample_rate = 100.0
+        accelerometer_range = 16.0
+        gyroscope_range = 2000.0
+        magnetometer_enabled = true
+        temperature_compensation = true
+        noise_filtering = true
+        calibration_enabled = true
+        "#,
+    )
+    .unwrap();
+
+    for (id, spec) in specs {
+        let expected = spec.sensor_type();
+        let sensor = create(id.clone(), spec).unwrap();
+        assert_eq!(sensor.id(), id);
+        assert_eq!(sensor.sensor_type(), expected);
+    }
+}
+
+#[test]
+fn test_create_covers_every_variant() {
+    use kova_core::sensors::camera::CameraConfig;
+    use kova_core::sensors::gps::GPSConfig;
+    use kova_core::sensors::imu::IMUConfig;
+    use kova_core::sensors::lidar::LiDARConfig;
+    use kova_core::sensors::thermal::ThermalConfig;
+
+    let cases = vec![
+        (
+            SensorSpec::Camera(CameraConfig::default()),
+            SensorType::Camera,
+        ),
+        (SensorSpec::LiDAR(LiDARConfig::default()), SensorType::LiDAR),
+        (SensorSpec::IMU(IMUConfig::default()), SensorType::IMU),
+        (SensorSpec::GPS(GPSConfig::default()), SensorType::GPS),
+        (
+            SensorSpec::Thermal(ThermalConfig::default()),
+            SensorType::Thermal,
+        ),
+    ];
+
+    for (spec, expected) in cases {
+        let sensor = create("sensor_1".to_string(), spec).unwrap();
+        assert_eq!(sensor.sensor_type(), expected);
+    }
+}
+
+#[test]
+fn test_create_propagates_config_validation() {
+    use kova_core::sensors::camera::CameraConfig;
+
+    let mut config = CameraConfig::default();
+    config.resolution = (0, 0);
+    assert!(create("camera_1".to_string(), SensorSpec::Camera(config)).is_err());
+}
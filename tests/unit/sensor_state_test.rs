@@ -0,0 +1,70 @@
+//! Unit tests for the sensor lifecycle state machine
+
+use kova_core::core::Error;
+use kova_core::sensors::camera::{Camera, CameraConfig};
+use kova_core::sensors::{Sensor, SensorData, SensorState, SensorType};
+
+/// A sensor whose captures always fail
+struct FaultySensor {
+    state: SensorState,
+}
+
+#[async_trait::async_trait]
+impl Sensor for FaultySensor {
+    fn id(&self) -> &str {
+        "faulty"
+    }
+
+    fn sensor_type(&self) -> SensorType {
+        SensorType::Camera
+    }
+
+    async fn capture(&mut self) -> Result<SensorData, Error> {
+        let error = Error::sensor("Hardware fault");
+        self.state = SensorState::Error(error.to_string());
+        Err(error)
+    }
+
+    async fn is_available(&self) -> bool {
+        matches!(self.state, SensorState::Ready | SensorState::Capturing)
+    }
+
+    fn state(&self) -> SensorState {
+        self.state.clone()
+    }
+
+    fn config(&self) -> &dyn std::fmt::Debug {
+        &"faulty"
+    }
+}
+
+#[tokio::test]
+async fn test_failed_capture_moves_sensor_to_error_state() {
+    let mut sensor = FaultySensor {
+        state: SensorState::Ready,
+    };
+    assert!(sensor.is_available().await);
+
+    assert!(sensor.capture().await.is_err());
+
+    assert!(matches!(sensor.state(), SensorState::Error(_)));
+    assert!(!sensor.is_available().await);
+}
+
+#[tokio::test]
+async fn test_camera_state_transitions() {
+    let mut camera = Camera::new("camera_1".to_string(), CameraConfig::default()).unwrap();
+    assert_eq!(camera.state(), SensorState::Uninitialized);
+
+    // Capturing before init fails and records the fault
+    assert!(Sensor::capture(&mut camera).await.is_err());
+    assert!(matches!(camera.state(), SensorState::Error(_)));
+    assert!(!Sensor::is_available(&camera).await);
+
+    Sensor::initialize(&mut camera).await.unwrap();
+    assert_eq!(camera.state(), SensorState::Ready);
+
+    Sensor::capture(&mut camera).await.unwrap();
+    assert_eq!(camera.state(), SensorState::Ready);
+    assert!(Sensor::is_available(&camera).await);
+}
@@ -0,0 +1,108 @@
+//! Unit tests for the SensorType string mapping
+
+use kova_core::sensors::SensorType;
+
+#[test]
+fn test_every_variant_round_trips_through_strings() {
+    let variants = [
+        SensorType::Camera,
+        SensorType::LiDAR,
+        SensorType::IMU,
+        SensorType::GPS,
+        SensorType::Thermal,
+    ];
+
+    for variant in variants {
+        let parsed: SensorType = variant.as_str().parse().unwrap();
+        assert_eq!(parsed, variant);
+    }
+}
+
+#[test]
+fn test_string_forms_are_lowercase_and_stable() {
+    assert_eq!(SensorType::Camera.as_str(), "camera");
+    assert_eq!(SensorType::LiDAR.as_str(), "lidar");
+    assert_eq!(SensorType::IMU.as_str(), "imu");
+    assert_eq!(SensorType::GPS.as_str(), "gps");
+    assert_eq!(SensorType::Thermal.as_str(), "thermal");
+}
+
+#[test]
+fn test_display_matches_as_str() {
+    assert_eq!(SensorType::LiDAR.to_string(), "lidar");
+    assert_eq!(format!("{}", SensorType::Camera), SensorType::Camera.as_str());
+}
+
+#[test]
+fn test_serde_uses_lowercase_strings() {
+    assert_eq!(
+        serde_json::to_string(&SensorType::Thermal).unwrap(),
+        "\"thermal\""
+    );
+    let parsed: SensorType = serde_json::from_str("\"gps\"").unwrap();
+    assert_eq!(parsed, SensorType::GPS);
+}
+
+#[test]
+fn test_sensor_data_serializes_sensor_type_as_string() {
+    use kova_core::sensors::SensorData;
+    use std::collections::HashMap;
+
+    let frame = SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: "camera_1".to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::Utc::now(),
+        data: vec![1, 2, 3],
+        metadata: HashMap::new(),
+        checksum: None,
+    };
+
+    let json: serde_json::Value =
+        serde_json::from_str(&serde_json::to_string(&frame).unwrap()).unwrap();
+    assert_eq!(json["sensor_type"], "camera");
+}
+
+#[test]
+fn test_sensor_data_round_trips_through_json() {
+    use kova_core::sensors::SensorData;
+    use std::collections::HashMap;
+
+    let mut metadata = HashMap::new();
+    metadata.insert("resolution".to_string(), "1920x1080".to_string());
+    metadata.insert("format".to_string(), "RGB".to_string());
+
+    let frame = SensorData {
+        frame_id: uuid::Uuid::new_v4(),
+        sensor_id: "camera_1".to_string(),
+        sensor_type: SensorType::Camera,
+        timestamp: chrono::Utc::now(),
+        data: (0..=255).collect(),
+        metadata,
+        checksum: Some(42),
+    }
+    .with_checksum();
+
+    let json = serde_json::to_string(&frame).unwrap();
+
+    // The payload travels as base64, not a JSON number array
+    let raw: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!(raw["data"].is_string());
+
+    let decoded: SensorData = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.frame_id, frame.frame_id);
+    assert_eq!(decoded.sensor_id, frame.sensor_id);
+    assert_eq!(decoded.sensor_type, frame.sensor_type);
+    assert_eq!(decoded.timestamp, frame.timestamp);
+    assert_eq!(decoded.data, frame.data);
+    assert_eq!(decoded.metadata, frame.metadata);
+    assert_eq!(decoded.checksum, frame.checksum);
+    assert!(decoded.verify_checksum());
+}
+
+#[test]
+fn test_unknown_string_errors() {
+    assert!("sonar".parse::<SensorType>().is_err());
+    assert!("Camera".parse::<SensorType>().is_err());
+    assert!("".parse::<SensorType>().is_err());
+}
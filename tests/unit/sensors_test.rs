@@ -1,7 +1,7 @@
 //! Unit tests for sensor modules
 
 use kova_core::{
-    sensors::{Camera, LiDAR, IMU, GPS, Thermal, SensorType, SensorData},
+    sensors::{Camera, LiDAR, IMU, GPS, Thermal, Sensor, SensorType, SensorData},
     core::Error,
 };
 
@@ -15,7 +15,8 @@ async fn test_camera_sensor() {
     assert_eq!(camera.sensor_type(), SensorType::Camera);
     
     // Test data capture
-    let sensor_data = camera.capture().await.unwrap();
+    Sensor::initialize(&mut camera).await.unwrap();
+    let sensor_data = Sensor::capture(&mut camera).await.unwrap();
     assert_eq!(sensor_data.sensor_id, "test-camera");
     assert_eq!(sensor_data.sensor_type, SensorType::Camera);
     assert!(!sensor_data.data.is_empty());
@@ -31,7 +32,8 @@ async fn test_lidar_sensor() {
     assert_eq!(lidar.sensor_type(), SensorType::LiDAR);
     
     // Test data capture
-    let sensor_data = lidar.capture().await.unwrap();
+    Sensor::initialize(&mut lidar).await.unwrap();
+    let sensor_data = Sensor::capture(&mut lidar).await.unwrap();
     assert_eq!(sensor_data.sensor_id, "test-lidar");
     assert_eq!(sensor_data.sensor_type, SensorType::LiDAR);
     assert!(!sensor_data.data.is_empty());
@@ -47,7 +49,8 @@ async fn test_imu_sensor() {
     assert_eq!(imu.sensor_type(), SensorType::IMU);
     
     // Test data capture
-    let sensor_data = imu.capture().await.unwrap();
+    Sensor::initialize(&mut imu).await.unwrap();
+    let sensor_data = Sensor::capture(&mut imu).await.unwrap();
     assert_eq!(sensor_data.sensor_id, "test-imu");
     assert_eq!(sensor_data.sensor_type, SensorType::IMU);
     assert!(!sensor_data.data.is_empty());
@@ -63,7 +66,8 @@ async fn test_gps_sensor() {
     assert_eq!(gps.sensor_type(), SensorType::GPS);
     
     // Test data capture
-    let sensor_data = gps.capture().await.unwrap();
+    Sensor::initialize(&mut gps).await.unwrap();
+    let sensor_data = Sensor::capture(&mut gps).await.unwrap();
     assert_eq!(sensor_data.sensor_id, "test-gps");
     assert_eq!(sensor_data.sensor_type, SensorType::GPS);
     assert!(!sensor_data.data.is_empty());
@@ -79,7 +83,8 @@ async fn test_thermal_sensor() {
     assert_eq!(thermal.sensor_type(), SensorType::Thermal);
     
     // Test data capture
-    let sensor_data = thermal.capture().await.unwrap();
+    Sensor::initialize(&mut thermal).await.unwrap();
+    let sensor_data = Sensor::capture(&mut thermal).await.unwrap();
     assert_eq!(sensor_data.sensor_id, "test-thermal");
     assert_eq!(sensor_data.sensor_type, SensorType::Thermal);
     assert!(!sensor_data.data.is_empty());
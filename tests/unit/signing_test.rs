@@ -0,0 +1,61 @@
+//! Unit tests for Ed25519 validation signatures
+
+use kova_core::core::validation::{verify_signature, SigningValidator, ValidationConfig};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_signature_verifies_with_right_key() {
+    let validator = SigningValidator::new(ValidationConfig::default());
+    let data = vec![42u8; 256];
+
+    let result = validator.validate(&data, &HashMap::new()).await.unwrap();
+
+    assert!(verify_signature(
+        &validator.public_key(),
+        &data,
+        &result.timestamp,
+        &result.signature,
+    ));
+}
+
+#[tokio::test]
+async fn test_signature_fails_with_wrong_key() {
+    let validator = SigningValidator::new(ValidationConfig::default());
+    let other = SigningValidator::new(ValidationConfig::default());
+    let data = vec![42u8; 256];
+
+    let result = validator.validate(&data, &HashMap::new()).await.unwrap();
+
+    assert!(!verify_signature(
+        &other.public_key(),
+        &data,
+        &result.timestamp,
+        &result.signature,
+    ));
+}
+
+#[tokio::test]
+async fn test_signature_fails_on_tampered_data() {
+    let validator = SigningValidator::new(ValidationConfig::default());
+    let data = vec![42u8; 256];
+
+    let result = validator.validate(&data, &HashMap::new()).await.unwrap();
+
+    let mut tampered = data.clone();
+    tampered[0] ^= 0xFF;
+    assert!(!verify_signature(
+        &validator.public_key(),
+        &tampered,
+        &result.timestamp,
+        &result.signature,
+    ));
+}
+
+#[tokio::test]
+async fn test_same_secret_key_reproduces_public_key() {
+    let secret = [7u8; 32];
+    let a = SigningValidator::from_secret_key(ValidationConfig::default(), &secret);
+    let b = SigningValidator::from_secret_key(ValidationConfig::default(), &secret);
+
+    assert_eq!(a.public_key(), b.public_key());
+}
@@ -0,0 +1,103 @@
+//! Unit tests for the Solana RPC client against a scripted mock endpoint
+
+use kova_core::blockchain::solana::{SolanaClient, SolanaConfig};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Serve one scripted JSON-RPC response body per incoming request
+async fn mock_rpc(responses: Vec<String>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        for body in responses {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                break;
+            };
+            let mut buffer = vec![0u8; 4096];
+            let _ = stream.read(&mut buffer).await;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    url
+}
+
+fn config_for(url: String) -> SolanaConfig {
+    SolanaConfig {
+        rpc_url: url,
+        commitment: "confirmed".to_string(),
+        timeout_seconds: 5,
+        retry_attempts: 3,
+        private_key: None,
+    }
+}
+
+#[tokio::test]
+async fn test_get_balance_retries_past_transient_errors() {
+    let retryable =
+        r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32002,"message":"Blockhash not found"}}"#
+            .to_string();
+    let success = r#"{"jsonrpc":"2.0","id":1,"result":{"value":2500000000}}"#.to_string();
+
+    let url = mock_rpc(vec![retryable.clone(), retryable, success]).await;
+    let client = SolanaClient::new(config_for(url)).await.unwrap();
+
+    let balance = client.get_balance("somepubkey").await.unwrap();
+    assert!((balance - 2.5).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_confirm_transaction_polls_until_confirmed() {
+    let processed =
+        r#"{"jsonrpc":"2.0","id":1,"result":{"value":[{"confirmationStatus":"processed"}]}}"#
+            .to_string();
+    let confirmed =
+        r#"{"jsonrpc":"2.0","id":1,"result":{"value":[{"confirmationStatus":"confirmed"}]}}"#
+            .to_string();
+
+    let url = mock_rpc(vec![processed, confirmed]).await;
+    let client = SolanaClient::new(config_for(url)).await.unwrap();
+
+    let confirmed = client
+        .confirm_transaction("somesignature", std::time::Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert!(confirmed);
+}
+
+#[tokio::test]
+async fn test_confirm_transaction_times_out_unconfirmed() {
+    let processed =
+        r#"{"jsonrpc":"2.0","id":1,"result":{"value":[{"confirmationStatus":"processed"}]}}"#
+            .to_string();
+
+    let url = mock_rpc(vec![processed; 10]).await;
+    let client = SolanaClient::new(config_for(url)).await.unwrap();
+
+    let confirmed = client
+        .confirm_transaction("somesignature", std::time::Duration::from_millis(300))
+        .await
+        .unwrap();
+    assert!(!confirmed);
+}
+
+#[tokio::test]
+async fn test_malformed_request_error_does_not_retry() {
+    let invalid =
+        r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"Invalid params"}}"#
+            .to_string();
+    // Only one response scripted: a retry would hang, so failing fast
+    // also proves no second request was made
+    let url = mock_rpc(vec![invalid]).await;
+    let client = SolanaClient::new(config_for(url)).await.unwrap();
+
+    let result = client.get_balance("somepubkey").await;
+    assert!(result.is_err());
+    assert!(!result.unwrap_err().is_retryable());
+}
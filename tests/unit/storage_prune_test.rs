@@ -0,0 +1,60 @@
+//! Unit tests for storage retention pruning
+
+use kova_core::core::storage::StorageManager;
+use std::time::{Duration, SystemTime};
+
+/// Backdate a stored file's modification time
+fn backdate(path: &std::path::Path, age: Duration) {
+    let file = std::fs::File::options().write(true).open(path).unwrap();
+    file.set_modified(SystemTime::now() - age).unwrap();
+}
+
+#[tokio::test]
+async fn test_prune_removes_only_stale_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = StorageManager::new(dir.path().to_string_lossy().into_owned());
+
+    manager.store("fresh.bin", b"fresh").await.unwrap();
+    manager.store("stale.bin", b"stale").await.unwrap();
+    manager.store("nested/old.bin", b"old").await.unwrap();
+
+    backdate(&dir.path().join("stale.bin"), Duration::from_secs(7200));
+    backdate(&dir.path().join("nested/old.bin"), Duration::from_secs(7200));
+
+    let removed = manager
+        .prune_older_than(Duration::from_secs(3600))
+        .await
+        .unwrap();
+
+    assert_eq!(removed, 2);
+    assert!(manager.retrieve("fresh.bin").await.is_ok());
+    assert!(manager.retrieve("stale.bin").await.is_err());
+    assert!(manager.retrieve("nested/old.bin").await.is_err());
+}
+
+#[tokio::test]
+async fn test_prune_of_missing_directory_is_empty() {
+    let manager = StorageManager::new("/nonexistent/kova-storage".to_string());
+    let removed = manager
+        .prune_older_than(Duration::from_secs(60))
+        .await
+        .unwrap();
+    assert_eq!(removed, 0);
+}
+
+#[tokio::test]
+async fn test_background_prune_task_runs() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = std::sync::Arc::new(StorageManager::new(
+        dir.path().to_string_lossy().into_owned(),
+    ));
+
+    manager.store("stale.bin", b"stale").await.unwrap();
+    backdate(&dir.path().join("stale.bin"), Duration::from_secs(7200));
+
+    let handle = manager.spawn_prune_task(Duration::from_secs(3600), Duration::from_millis(50));
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    handle.abort();
+
+    assert!(manager.retrieve("stale.bin").await.is_err());
+}
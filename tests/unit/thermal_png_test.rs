@@ -0,0 +1,66 @@
+//! Unit tests for thermal PNG export
+
+use kova_core::sensors::thermal::{ColorMap, Thermal, ThermalConfig, ThermalData};
+
+/// A 4x3 frame with one hot pixel at (2, 1)
+fn thermal_data() -> ThermalData {
+    let mut temperature_map = vec![vec![20.0f32; 4]; 3];
+    temperature_map[1][2] = 80.0;
+    ThermalData {
+        temperature_map,
+        min_temperature: 20.0,
+        max_temperature: 80.0,
+        avg_temperature: 25.0,
+        hot_spots: vec![(2, 1, 80.0)],
+        cold_spots: vec![],
+        timestamp: chrono::Utc::now(),
+    }
+}
+
+fn thermal() -> Thermal {
+    Thermal::new("thermal_01".to_string(), ThermalConfig::default()).unwrap()
+}
+
+#[test]
+fn test_png_decodes_with_matching_dimensions() {
+    let png = thermal()
+        .to_png(&thermal_data(), ColorMap::Inferno)
+        .unwrap();
+
+    let decoded = image::load_from_memory(&png).unwrap();
+    assert_eq!(decoded.width(), 4);
+    assert_eq!(decoded.height(), 3);
+}
+
+#[test]
+fn test_hottest_pixel_gets_the_peak_color() {
+    for colormap in [ColorMap::Inferno, ColorMap::Jet, ColorMap::Grayscale] {
+        let png = thermal().to_png(&thermal_data(), colormap).unwrap();
+        let decoded = image::load_from_memory(&png).unwrap().to_rgb8();
+        assert_eq!(decoded.get_pixel(2, 1).0, colormap.peak());
+    }
+}
+
+#[test]
+fn test_grayscale_extremes() {
+    assert_eq!(ColorMap::Grayscale.color(0.0), [0, 0, 0]);
+    assert_eq!(ColorMap::Grayscale.peak(), [255, 255, 255]);
+}
+
+#[test]
+fn test_uniform_frame_renders_without_dividing_by_zero() {
+    let mut data = thermal_data();
+    data.temperature_map = vec![vec![20.0; 4]; 3];
+    data.max_temperature = 20.0;
+
+    let png = thermal().to_png(&data, ColorMap::Grayscale).unwrap();
+    let decoded = image::load_from_memory(&png).unwrap().to_rgb8();
+    assert_eq!(decoded.get_pixel(0, 0).0, [0, 0, 0]);
+}
+
+#[test]
+fn test_empty_map_is_rejected() {
+    let mut data = thermal_data();
+    data.temperature_map.clear();
+    assert!(thermal().to_png(&data, ColorMap::Jet).is_err());
+}
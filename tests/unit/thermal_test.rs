@@ -0,0 +1,83 @@
+//! Unit tests for thermal data serialization round-trips
+
+use kova_core::sensors::thermal::{Thermal, ThermalConfig};
+use kova_core::sensors::{Sensor, SensorType};
+
+#[tokio::test]
+async fn test_thermal_data_round_trip() {
+    let mut thermal = Thermal::new("thermal_1".to_string(), ThermalConfig::default()).unwrap();
+    thermal.initialize().await.unwrap();
+
+    let original = thermal.capture().await.unwrap();
+    let bytes = thermal.serialize_thermal_data(&original).unwrap();
+    let decoded = Thermal::deserialize_thermal_data(&bytes).unwrap();
+
+    assert_eq!(decoded.temperature_map, original.temperature_map);
+    assert_eq!(decoded.min_temperature, original.min_temperature);
+    assert_eq!(decoded.max_temperature, original.max_temperature);
+    assert_eq!(decoded.avg_temperature, original.avg_temperature);
+    assert_eq!(decoded.hot_spots, original.hot_spots);
+    assert_eq!(decoded.cold_spots, original.cold_spots);
+    assert_eq!(
+        decoded.timestamp.timestamp_millis(),
+        original.timestamp.timestamp_millis()
+    );
+}
+
+#[tokio::test]
+async fn test_as_thermal_decodes_captured_frame() {
+    let mut thermal = Thermal::new("thermal_1".to_string(), ThermalConfig::default()).unwrap();
+    thermal.initialize().await.unwrap();
+
+    let frame = Sensor::capture(&mut thermal).await.unwrap();
+    assert_eq!(frame.sensor_type, SensorType::Thermal);
+
+    let decoded = frame.as_thermal().unwrap();
+    let (width, height) = ThermalConfig::default().resolution;
+    assert_eq!(decoded.temperature_map.len(), height as usize);
+    assert_eq!(decoded.temperature_map[0].len(), width as usize);
+}
+
+#[tokio::test]
+async fn test_as_thermal_rejects_other_sensor_types() {
+    let mut thermal = Thermal::new("thermal_1".to_string(), ThermalConfig::default()).unwrap();
+    thermal.initialize().await.unwrap();
+
+    let mut frame = Sensor::capture(&mut thermal).await.unwrap();
+    frame.sensor_type = SensorType::Camera;
+
+    assert!(frame.as_thermal().is_err());
+}
+
+#[test]
+fn test_deserialize_rejects_truncated_data() {
+    assert!(Thermal::deserialize_thermal_data(&[0u8; 4]).is_err());
+}
+
+#[test]
+fn test_lower_emissivity_raises_corrected_temperature() {
+    let mut config = ThermalConfig::default();
+    config.atmospheric_temp = 20.0;
+
+    config.emissivity = 0.95;
+    let high = Thermal::new("thermal_1".to_string(), config.clone()).unwrap();
+
+    config.emissivity = 0.60;
+    let low = Thermal::new("thermal_2".to_string(), config).unwrap();
+
+    // A target warmer than the background reads hotter once the weaker
+    // emission is compensated for
+    let raw = 60.0;
+    assert!(low.apply_radiometric_correction(raw) > high.apply_radiometric_correction(raw));
+}
+
+#[test]
+fn test_perfect_emitter_at_zero_distance_is_unchanged() {
+    let mut config = ThermalConfig::default();
+    config.emissivity = 1.0;
+    config.distance = 0.0;
+    let thermal = Thermal::new("thermal_1".to_string(), config).unwrap();
+
+    let corrected = thermal.apply_radiometric_correction(35.0);
+    assert!((corrected - 35.0).abs() < 0.01);
+}
@@ -0,0 +1,74 @@
+//! Unit tests for sensor unit normalization
+
+use kova_core::sensors::gps::{FixQuality, GPSData};
+use kova_core::sensors::thermal::ThermalData;
+use kova_core::sensors::units::{normalize_gps_data, normalize_thermal_data, UnitSystem};
+use std::collections::HashMap;
+
+#[test]
+fn test_thermal_map_to_fahrenheit() {
+    let mut thermal_data = ThermalData {
+        temperature_map: vec![vec![0.0, 100.0], vec![25.0, -40.0]],
+        min_temperature: -40.0,
+        max_temperature: 100.0,
+        avg_temperature: 21.25,
+        hot_spots: vec![(1, 0, 100.0)],
+        cold_spots: vec![(1, 1, -40.0)],
+        timestamp: chrono::Utc::now(),
+    };
+    let mut metadata = HashMap::new();
+
+    normalize_thermal_data(UnitSystem::Imperial, &mut thermal_data, &mut metadata);
+
+    assert!((thermal_data.temperature_map[0][0] - 32.0).abs() < 1e-3);
+    assert!((thermal_data.temperature_map[0][1] - 212.0).abs() < 1e-3);
+    assert!((thermal_data.temperature_map[1][0] - 77.0).abs() < 1e-3);
+    assert!((thermal_data.temperature_map[1][1] + 40.0).abs() < 1e-3);
+    assert!((thermal_data.min_temperature + 40.0).abs() < 1e-3);
+    assert!((thermal_data.max_temperature - 212.0).abs() < 1e-3);
+    assert_eq!(metadata.get("temperature_unit").unwrap(), "fahrenheit");
+}
+
+#[test]
+fn test_gps_speed_to_mph() {
+    let mut gps_data = GPSData {
+        latitude: 37.7749,
+        longitude: -122.4194,
+        altitude: 10.0,
+        accuracy: 2.0,
+        speed: 10.0,
+        heading: 90.0,
+        satellite_count: 10,
+        hdop: 1.1,
+        vdop: 1.5,
+        pdop: 1.9,
+        fix_quality: FixQuality::GPSFix,
+        timestamp: chrono::Utc::now(),
+    };
+    let mut metadata = HashMap::new();
+
+    normalize_gps_data(UnitSystem::Imperial, &mut gps_data, &mut metadata);
+
+    assert!((gps_data.speed - 22.369).abs() < 1e-2);
+    assert_eq!(metadata.get("speed_unit").unwrap(), "mph");
+    assert_eq!(metadata.get("altitude_unit").unwrap(), "ft");
+}
+
+#[test]
+fn test_si_normalization_is_identity() {
+    let mut metadata = HashMap::new();
+    let mut thermal_data = ThermalData {
+        temperature_map: vec![vec![25.0]],
+        min_temperature: 25.0,
+        max_temperature: 25.0,
+        avg_temperature: 25.0,
+        hot_spots: Vec::new(),
+        cold_spots: Vec::new(),
+        timestamp: chrono::Utc::now(),
+    };
+
+    normalize_thermal_data(UnitSystem::SI, &mut thermal_data, &mut metadata);
+
+    assert!((thermal_data.temperature_map[0][0] - 25.0).abs() < f32::EPSILON);
+    assert_eq!(metadata.get("temperature_unit").unwrap(), "celsius");
+}
@@ -0,0 +1,65 @@
+//! Unit tests for blockchain usage accounting
+//!
+//! Requires the `test-utils` feature.
+
+#![cfg(feature = "test-utils")]
+
+use kova_core::blockchain::{BlockchainManager, MockBlockchainClient};
+
+async fn manager() -> BlockchainManager {
+    let manager = BlockchainManager::new();
+    manager
+        .add_client("mock".to_string(), Box::new(MockBlockchainClient::new()))
+        .await;
+    manager
+}
+
+#[tokio::test]
+async fn test_store_requests_and_bytes_are_counted() {
+    let manager = manager().await;
+
+    manager.store_data(&[0u8; 100]).await.unwrap();
+    manager.store_data(&[0u8; 250]).await.unwrap();
+    manager.store_data(&[0u8; 50]).await.unwrap();
+
+    let report = manager.usage_report().await;
+    let stats = &report["mock"];
+    assert_eq!(stats.requests, 3);
+    assert_eq!(stats.bytes_stored, 400);
+    assert!(stats.estimated_cost > 0.0);
+}
+
+#[tokio::test]
+async fn test_retrieves_count_requests_but_not_bytes() {
+    let manager = manager().await;
+    let hash = manager.store_data(b"payload").await.unwrap();
+    manager.retrieve_data(&hash).await.unwrap();
+
+    let report = manager.usage_report().await;
+    let stats = &report["mock"];
+    assert_eq!(stats.requests, 2);
+    assert_eq!(stats.bytes_stored, 7);
+}
+
+#[tokio::test]
+async fn test_reset_clears_the_accounting() {
+    let manager = manager().await;
+    manager.store_data(b"payload").await.unwrap();
+    manager.reset_usage().await;
+
+    assert!(manager.usage_report().await.is_empty());
+}
+
+#[tokio::test]
+async fn test_cost_scales_with_the_configured_rate() {
+    let mut manager = BlockchainManager::new();
+    manager.set_cost_per_gb(1e9); // one unit per byte, for easy math
+    manager
+        .add_client("mock".to_string(), Box::new(MockBlockchainClient::new()))
+        .await;
+
+    manager.store_data(&[0u8; 128]).await.unwrap();
+
+    let report = manager.usage_report().await;
+    assert!((report["mock"].estimated_cost - 128.0).abs() < 1e-6);
+}
@@ -19,6 +19,9 @@ async fn test_data_validator_with_config() {
         min_quality_score: 0.8,
         enable_anomaly_detection: true,
         enable_temporal_consistency: true,
+        enable_metadata_validation: false,
+        enable_compression_metric: false,
+        per_type_thresholds: Default::default(),
         max_noise_threshold: 0.05,
     };
     
@@ -36,7 +39,6 @@ async fn test_data_validation() {
     let result = validator.validate(test_data, &metadata).await.unwrap();
     
     assert!(result.quality_score >= 0.0 && result.quality_score <= 1.0);
-    assert!(result.is_valid || !result.is_valid); // Either true or false
     assert!(!result.signature.is_empty());
 }
 
@@ -46,6 +48,9 @@ async fn test_validation_with_high_quality_data() {
         min_quality_score: 0.5,
         enable_anomaly_detection: true,
         enable_temporal_consistency: true,
+        enable_metadata_validation: false,
+        enable_compression_metric: false,
+        per_type_thresholds: Default::default(),
         max_noise_threshold: 0.1,
     };
     
@@ -67,6 +72,9 @@ async fn test_validation_with_low_quality_data() {
         min_quality_score: 0.8,
         enable_anomaly_detection: true,
         enable_temporal_consistency: true,
+        enable_metadata_validation: false,
+        enable_compression_metric: false,
+        per_type_thresholds: Default::default(),
         max_noise_threshold: 0.05,
     };
     
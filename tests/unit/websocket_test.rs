@@ -0,0 +1,294 @@
+//! Integration tests for the WebSocket server
+
+use futures_util::{SinkExt, StreamExt};
+use kova_core::api::websocket::{
+    ConnectionLimits, Envelope, WebSocketMessage, WebSocketServer, PROTOCOL_VERSION,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::Message;
+
+#[tokio::test]
+async fn test_server_accepts_connection_and_delivers_broadcast() {
+    let server = WebSocketServer::new("127.0.0.1".to_string(), 0);
+    server.start().await.unwrap();
+    let addr = server.local_addr().await.unwrap();
+
+    let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+
+    // Subscribe to a sensor topic
+    let subscribe = serde_json::to_string(&WebSocketMessage::Subscribe {
+        topic: "camera_front".to_string(),
+    })
+    .unwrap();
+    client.send(Message::Text(subscribe)).await.unwrap();
+
+    // Give the server a moment to register the subscription
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(server.connection_count().await, 1);
+    assert_eq!(
+        server
+            .get_connections_by_subscription("camera_front")
+            .await
+            .len(),
+        1
+    );
+
+    server
+        .broadcast(WebSocketMessage::SensorData {
+            sensor_id: "camera_front".to_string(),
+            sensor_type: "camera".to_string(),
+            data: vec![1, 2, 3],
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            metadata: HashMap::new(),
+        })
+        .await
+        .unwrap();
+
+    let received = tokio::time::timeout(Duration::from_secs(5), client.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+
+    // Sensor data arrives as a binary frame
+    match WebSocketMessage::decode_binary(&received.into_data()).unwrap() {
+        WebSocketMessage::SensorData { sensor_id, data, .. } => {
+            assert_eq!(sensor_id, "camera_front");
+            assert_eq!(data, vec![1, 2, 3]);
+        }
+        other => panic!("Expected SensorData, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_broadcast_to_topic_only_reaches_subscribers() {
+    let server = WebSocketServer::new("127.0.0.1".to_string(), 0);
+    server.start().await.unwrap();
+    let addr = server.local_addr().await.unwrap();
+
+    let (mut camera_client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+    let (mut lidar_client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+
+    let subscribe_camera = serde_json::to_string(&WebSocketMessage::Subscribe {
+        topic: "camera_front".to_string(),
+    })
+    .unwrap();
+    camera_client
+        .send(Message::Text(subscribe_camera))
+        .await
+        .unwrap();
+
+    let subscribe_lidar = serde_json::to_string(&WebSocketMessage::Subscribe {
+        topic: "lidar_top".to_string(),
+    })
+    .unwrap();
+    lidar_client
+        .send(Message::Text(subscribe_lidar))
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let message = WebSocketMessage::SensorData {
+        sensor_id: "camera_front".to_string(),
+        sensor_type: "camera".to_string(),
+        data: vec![9, 9, 9],
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        metadata: HashMap::new(),
+    };
+    assert_eq!(message.topic(), Some("camera_front"));
+    server
+        .broadcast_to_topic("camera_front", message)
+        .await
+        .unwrap();
+
+    // The subscribed client receives the message
+    let received = tokio::time::timeout(Duration::from_secs(5), camera_client.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    let message = WebSocketMessage::decode_binary(&received.into_data()).unwrap();
+    assert!(matches!(message, WebSocketMessage::SensorData { .. }));
+
+    // The other client receives nothing
+    let nothing = tokio::time::timeout(Duration::from_millis(300), lidar_client.next()).await;
+    assert!(nothing.is_err());
+}
+
+#[tokio::test]
+async fn test_connection_drop_removes_registration() {
+    let server = WebSocketServer::new("127.0.0.1".to_string(), 0);
+    server.start().await.unwrap();
+    let addr = server.local_addr().await.unwrap();
+
+    let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(server.connection_count().await, 1);
+
+    client.close(None).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(server.connection_count().await, 0);
+}
+
+#[test]
+fn test_versioned_envelope_round_trip() {
+    let encoded = Envelope::new(WebSocketMessage::Subscribe {
+        topic: "camera_front".to_string(),
+    })
+    .encode()
+    .unwrap();
+
+    // The version rides alongside the tagged message
+    let raw: serde_json::Value = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(raw["v"], PROTOCOL_VERSION);
+    assert_eq!(raw["type"], "Subscribe");
+
+    match Envelope::decode(&encoded).unwrap() {
+        WebSocketMessage::Subscribe { topic } => assert_eq!(topic, "camera_front"),
+        other => panic!("Expected Subscribe, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_binary_encoding_avoids_json_bloat() {
+    let payload = vec![0xABu8; 1024 * 1024];
+    let message = WebSocketMessage::SensorData {
+        sensor_id: "camera_front".to_string(),
+        sensor_type: "camera".to_string(),
+        data: payload.clone(),
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        metadata: HashMap::new(),
+    };
+
+    let binary = message.encode_binary().unwrap();
+    // A small header plus the raw bytes, not a JSON number array
+    assert!(binary.len() < payload.len() + 1024);
+    assert!(serde_json::to_string(&message).unwrap().len() > 2 * payload.len());
+
+    match WebSocketMessage::decode_binary(&binary).unwrap() {
+        WebSocketMessage::SensorData { sensor_id, data, .. } => {
+            assert_eq!(sensor_id, "camera_front");
+            assert_eq!(data, payload);
+        }
+        other => panic!("Expected SensorData, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_binary_encoding_only_covers_sensor_data() {
+    assert!(WebSocketMessage::Ping.encode_binary().is_err());
+    assert!(WebSocketMessage::decode_binary(&[1, 0]).is_err());
+}
+
+#[test]
+fn test_decode_rejects_unsupported_version() {
+    let stale = format!(r#"{{"v":{},"type":"Ping"}}"#, PROTOCOL_VERSION + 1);
+    assert!(Envelope::decode(&stale).is_err());
+}
+
+#[tokio::test]
+async fn test_connection_beyond_cap_is_rejected() {
+    let limits = ConnectionLimits {
+        max_connections: 1,
+        ..ConnectionLimits::default()
+    };
+    let server = WebSocketServer::with_limits("127.0.0.1".to_string(), 0, limits);
+    server.start().await.unwrap();
+    let addr = server.local_addr().await.unwrap();
+
+    let (_first, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(server.connection_count().await, 1);
+
+    let (mut second, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+    let received = tokio::time::timeout(Duration::from_secs(5), second.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    match Envelope::decode(received.to_text().unwrap()).unwrap() {
+        WebSocketMessage::Error { code, .. } => assert_eq!(code, "connection_limit"),
+        other => panic!("Expected Error, got {:?}", other),
+    }
+    assert_eq!(server.connection_count().await, 1);
+}
+
+#[tokio::test]
+async fn test_flooding_connection_is_closed() {
+    let limits = ConnectionLimits {
+        max_messages_per_second: 5,
+        ..ConnectionLimits::default()
+    };
+    let server = WebSocketServer::with_limits("127.0.0.1".to_string(), 0, limits);
+    server.start().await.unwrap();
+    let addr = server.local_addr().await.unwrap();
+
+    let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+
+    let ping = Envelope::new(WebSocketMessage::Ping).encode().unwrap();
+    for _ in 0..20 {
+        if client.send(Message::Text(ping.clone())).await.is_err() {
+            break;
+        }
+    }
+
+    // Drain responses until the rate-limit error shows up
+    let mut saw_rate_limit = false;
+    while let Ok(Some(Ok(received))) =
+        tokio::time::timeout(Duration::from_secs(5), client.next()).await
+    {
+        if let Ok(text) = received.to_text() {
+            if let Ok(WebSocketMessage::Error { code, .. }) = Envelope::decode(text) {
+                assert_eq!(code, "rate_limited");
+                saw_rate_limit = true;
+                break;
+            }
+        }
+    }
+    assert!(saw_rate_limit, "expected a rate_limited error");
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(server.connection_count().await, 0);
+}
+
+#[tokio::test]
+async fn test_server_rejects_old_protocol_version() {
+    let server = WebSocketServer::new("127.0.0.1".to_string(), 0);
+    server.start().await.unwrap();
+    let addr = server.local_addr().await.unwrap();
+
+    let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{}", addr))
+        .await
+        .unwrap();
+
+    client
+        .send(Message::Text(r#"{"v":0,"type":"Ping"}"#.to_string()))
+        .await
+        .unwrap();
+
+    let received = tokio::time::timeout(Duration::from_secs(5), client.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    match Envelope::decode(received.to_text().unwrap()).unwrap() {
+        WebSocketMessage::Error { code, .. } => assert_eq!(code, "version_mismatch"),
+        other => panic!("Expected Error, got {:?}", other),
+    }
+}